@@ -1,10 +1,17 @@
 use crate::{
     commands,
     helpers::get_guild,
-    translation::{apply_translations, read_ftl},
+    translation::{apply_translations, get_with_locale, read_ftl},
     Cache, Data, Duration, Error,
 };
-use poise::serenity_prelude::{ChannelId, Client, ClientBuilder, FullEvent, GatewayIntents};
+use database::{commands::CommandLog, escrow::Escrow, league::League, market::Market};
+use poise::serenity_prelude::{
+    ChannelId, Client, ClientBuilder, CreateInteractionResponse, CreateInteractionResponseMessage,
+    FullEvent, GatewayIntents, UserId,
+};
+use types::{rooms::Route, Rooms};
+
+const LEAGUE_CHECK: Duration = Duration::from_secs(3600);
 
 pub async fn build() -> Result<Client, Error> {
     let token = std::env::var("token").unwrap();
@@ -14,20 +21,52 @@ pub async fn build() -> Result<Client, Error> {
         commands::work::work(),
         commands::contradiction::contradict(),
         commands::nim::nim(),
+        commands::blackjack::blackjack(),
+        commands::blackjack::table::bjtable(),
+        commands::ecard::ecard(),
+        commands::rr::rr(),
+        commands::roulette::roulette(),
+        commands::dices::dices(),
+        commands::oldmaid::oldmaid(),
+        commands::airpoker::airpoker(),
+        commands::history::history(),
+        commands::leaderboard::leaderboard(),
         commands::rewards::daily(),
+        commands::rewards::rewards(),
         commands::profile::balance(),
         commands::profile::points(),
         commands::profile::inventory(),
+        commands::profile::use_item(),
+        commands::profile::quests(),
+        commands::store::shop(),
+        commands::guildshop::guildshop(),
+        commands::market::market(),
         commands::give::give(),
+        commands::trade::trade(),
         commands::greeting::greet(),
         commands::club::club(),
+        commands::rooms::rooms(),
+        commands::stats::stats(),
+        commands::rpg::rpg(),
+        commands::rpg::season(),
+        commands::rpg::craft(),
+        commands::rpg::fight(),
     ];
 
     let translations = read_ftl()?;
     apply_translations(&translations, &mut commands);
 
+    let pool = ::database::connect().await?;
+
+    // nothing that was on the table before a restart can be played anymore
+    let refunded = Escrow::refund_all(&pool).await?;
+    tracing::info!("refunded {} stakes", refunded.len());
+
+    let expired = Market::expire(&pool).await?;
+    tracing::info!("returned {} expired listings", expired.len());
+
     let data = Data {
-        pool: ::database::connect().await?,
+        pool,
         members: Cache::builder()
             .time_to_live(Duration::from_secs(600))
             .build(),
@@ -41,8 +80,34 @@ pub async fn build() -> Result<Client, Error> {
         clubs: Cache::builder()
             .time_to_live(Duration::from_secs(600))
             .build(),
+        rooms: Rooms::default(),
     };
 
+    // closed groups are paid as soon as the week is over
+    let league_pool = data.pool.clone();
+    let league_members = data.members.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(LEAGUE_CHECK);
+
+        loop {
+            interval.tick().await;
+
+            match League::rollover(&league_pool).await {
+                Ok(payouts) if !payouts.is_empty() => {
+                    for payout in &payouts {
+                        league_members
+                            .invalidate(&UserId::new(payout.member as u64))
+                            .await;
+                    }
+
+                    tracing::info!("closed leagues with {} members", payouts.len());
+                }
+                Ok(_) => {}
+                Err(why) => tracing::error!("error while closing leagues: {}", why),
+            }
+        }
+    });
+
     let options = poise::FrameworkOptions {
         commands,
         pre_command: |ctx| {
@@ -55,6 +120,12 @@ pub async fn build() -> Result<Client, Error> {
 
                 crate::cache(ctx, ctx.author().id).await;
                 crate::cache_system(ctx).await;
+
+                // the member row exists once cached
+                let pool = &ctx.data().pool;
+                if let Err(why) = CommandLog::log(pool, ctx.author().id.into(), command).await {
+                    tracing::error!("error while logging {}: {}", command, why);
+                }
             })
         },
         post_command: |ctx| {
@@ -63,10 +134,11 @@ pub async fn build() -> Result<Client, Error> {
                 let member = crate::get_member(ctx, ctx.author().id).await.unwrap();
                 let mut write = member.write().await;
                 write.increase_points(&data.pool, 15).await.unwrap();
+                drop(write);
 
                 if let Some(category) = &ctx.command().category {
                     if category == "gambling" {
-                        write.state.in_gamble = false;
+                        crate::free_room(ctx).await.unwrap();
                     }
                 }
             })
@@ -98,6 +170,10 @@ async fn on_error(error: poise::FrameworkError<'_, Data, Error>) {
     match error {
         poise::FrameworkError::Setup { error, .. } => panic!("Failed to start bot: {:?}", error),
         poise::FrameworkError::Command { error, ctx, .. } => {
+            if let Err(why) = crate::free_room(ctx).await {
+                tracing::error!("error while refunding stakes: {}", why);
+            }
+
             ctx.reply(error.to_string()).await.unwrap();
         }
         poise::FrameworkError::CommandCheckFailed { error, ctx, .. } => {
@@ -132,6 +208,31 @@ async fn event_handler(
                 }
             }
         }
+        // games started before a restart have nobody collecting their buttons
+        FullEvent::InteractionCreate { interaction } => {
+            if let Some(inter) = interaction.as_message_component() {
+                if let Route::Stale = data.rooms.route(&inter.data.custom_id).await {
+                    let content = get_with_locale(
+                        &data.translations,
+                        Some(inter.locale.as_str()),
+                        "room-expired",
+                        None,
+                        None,
+                    );
+
+                    inter
+                        .create_response(
+                            ctx,
+                            CreateInteractionResponse::Message(
+                                CreateInteractionResponseMessage::new()
+                                    .content(content)
+                                    .ephemeral(true),
+                            ),
+                        )
+                        .await?;
+                }
+            }
+        }
         _ => {}
     }
 
@@ -1,115 +1,437 @@
-use crate::{Context, Error, Parser};
-use poise::serenity_prelude::{
-    ComponentInteraction, ComponentInteractionCollector, ModalInteraction,
-    ModalInteractionCollector, User,
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
 };
-use tokio::select;
-
+use crate::{
+    serenity::{
+        ComponentInteraction, ComponentInteractionCollector, ModalInteraction,
+        ModalInteractionCollector, User,
+    },
+    translate, Context, Duration, Error, Parser,
+};
+use poise::{Modal, ReplyHandle};
+use responses::Response;
 use std::time::Instant;
+use tokio::select;
+use types::{
+    airpoker::{AirPoker, BetAction, Phase, Player},
+    rooms::RoomHandle,
+};
 
 mod responses;
-use responses::Response;
 
-use types::airpoker::{AirPoker, Player};
+const REQUEST_SECS: u64 = 60;
+// the table is redrawn every few seconds so the air left stays visible
+const REFRESH_SECS: u64 = 5;
 
 #[derive(poise::Modal)]
 struct BetModal {
     amount: String,
 }
 
-#[derive(Debug)]
 enum Signal {
-    SelectCardRound { inter: ComponentInteraction },
-    BetRound { inter: ComponentInteraction },
-    GameEnd { inter: ComponentInteraction },
-    BetRoundEnd,
     SelectCardEnd,
+    BetRoundEnd,
+    Update,
     Tick,
 }
 
 enum Event {
     Interaction(ComponentInteraction),
     ModalInter(ModalInteraction),
-    Timeout,
+    Tick,
 }
 
-// choose, breathe
-// bet, breathe
-
 #[poise::command(
     prefix_command,
     slash_command,
     install_context = "Guild|User",
     interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
     category = "gambling"
 )]
 pub async fn airpoker(ctx: Context<'_>, user: User, bios: Option<String>) -> Result<(), Error> {
     if user.id == ctx.author().id {
-        return Err("hola".into());
+        return Err("cannot play with yourself".into());
     }
 
-    let bet = Parser::amount(ctx, ctx.author().id, bios, 500).await?;
+    user_can_gamble(ctx, user.clone()).await?;
+
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    let game = AirPoker::new(Player::new(ctx.author().clone()), Player::new(user.clone()));
+
+    let channel = ctx.channel_id().get();
+    let guild = ctx.guild_id().map(|id| id.get());
+    let rooms = &ctx.data().rooms;
+
+    let room = match rooms
+        .airpoker
+        .open(channel, guild, ctx.id(), author_id, game)
+        .await
+    {
+        Ok(room) => room,
+        Err(_) => return Err(translate!(ctx, "room-taken").into()),
+    };
+
+    let result = run(ctx, &room, &user, bet).await;
+    rooms.airpoker.close(channel, ctx.id()).await;
+
+    result
+}
+
+async fn run(
+    ctx: Context<'_>,
+    room: &RoomHandle<AirPoker>,
+    user: &User,
+    bet: i64,
+) -> Result<(), Error> {
+    let reply = Response::request(ctx, user, bet).await?;
+
+    crate::set_gamble(ctx, ctx.author().id).await?;
+
+    if !request(ctx, &reply, room, user, bet).await? {
+        return Ok(());
+    }
+
+    let mut airpoker = room.game.lock().await;
+
+    airpoker.deal_cards();
+    airpoker.start_round();
+
+    let last = play(ctx, &reply, room, &mut airpoker).await?;
+
+    // whoever aborted the room already freed the players
+    if room.is_aborted() {
+        Response::aborted(ctx, &reply).await?;
+        return Ok(());
+    }
+
+    settle(ctx, &airpoker, bet).await?;
+    Response::end(ctx, &reply, &airpoker, last.as_deref(), bet).await?;
+
+    Ok(())
+}
 
-    let mut airpoker = AirPoker::new(Player::new(ctx.author().clone()), Player::new(user.clone()));
+// only the challenged user can accept, true once the game starts
+async fn request(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    room: &RoomHandle<AirPoker>,
+    user: &User,
+    bet: i64,
+) -> Result<bool, Error> {
+    let author_id = ctx.author().id;
+
+    while let Some(inter) = ComponentInteractionCollector::new(ctx)
+        .timeout(Duration::from_secs(REQUEST_SECS))
+        .await
+    {
+        if room.is_aborted() {
+            Response::aborted(ctx, reply).await?;
+            return Ok(false);
+        }
 
-    let mut elapsed = Instant::now();
+        if inter.user.id != user.id && inter.user.id != author_id {
+            continue;
+        }
 
-    loop {
-        let collector = ComponentInteractionCollector::new(ctx);
-        let modal_collector = ModalInteractionCollector::new(ctx);
+        if inter.data.custom_id == format!("{}_accept", ctx.id()) {
+            if inter.user.id != user.id {
+                CommonRes::self_accept(ctx, &inter).await?;
+                continue;
+            }
 
-        let timeout = async {
-            if elapsed.elapsed().as_secs() > 60 {
-                std::future::ready(()).await
-            } else {
-                std::future::pending().await
+            if user_can_gamble(ctx, inter.user.clone()).await.is_err()
+                || !crate::can_partial_bet(ctx, user.id, bet).await?
+            {
+                crate::free_gamble(ctx, vec![author_id]).await?;
+                CommonRes::cannot_accept(ctx, &inter).await?;
+                return Ok(false);
             }
-        };
+
+            crate::set_gamble(ctx, user.id).await?;
+
+            if crate::hold_bet(ctx, vec![author_id, user.id], bet)
+                .await
+                .is_err()
+            {
+                crate::free_gamble(ctx, vec![author_id, user.id]).await?;
+                CommonRes::cannot_accept(ctx, &inter).await?;
+                return Ok(false);
+            }
+
+            ctx.data()
+                .rooms
+                .airpoker
+                .join(ctx.channel_id().get(), user.id)
+                .await;
+
+            inter.defer(ctx).await?;
+
+            return Ok(true);
+        }
+
+        if inter.data.custom_id == format!("{}_decline", ctx.id()) {
+            crate::free_gamble(ctx, vec![author_id]).await?;
+            Response::declined(ctx, &inter, &inter.user).await?;
+            return Ok(false);
+        }
+    }
+
+    crate::free_gamble(ctx, vec![author_id]).await?;
+    Response::expired(ctx, reply).await?;
+
+    Ok(false)
+}
+
+// both players breathe every second until the rounds are over or someone
+// runs out of air, returns the result of the last showdown
+async fn play(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    room: &RoomHandle<AirPoker>,
+    airpoker: &mut AirPoker,
+) -> Result<Option<String>, Error> {
+    let tick = Duration::from_secs(1);
+    let mut last_tick = Instant::now();
+    let mut seconds = 0;
+    let mut last = None;
+
+    Response::table(ctx, reply, airpoker, None).await?;
+
+    while !airpoker.is_finished() && !room.is_aborted() {
+        let remaining = tick.saturating_sub(last_tick.elapsed());
+
+        let collector = ComponentInteractionCollector::new(ctx).timeout(remaining);
+        let modal_collector = ModalInteractionCollector::new(ctx).timeout(remaining);
 
         let event = select! {
-            inter = collector.next() => Event::Interaction(inter.unwrap()),
-            modal_inter = modal_collector.next() => Event::ModalInter(modal_inter.unwrap()),
-            _ = timeout => Event::Timeout,
+            inter = collector.next() => match inter {
+                Some(inter) => Event::Interaction(inter),
+                None => Event::Tick,
+            },
+            modal_inter = modal_collector.next() => match modal_inter {
+                Some(modal_inter) => Event::ModalInter(modal_inter),
+                None => Event::Tick,
+            },
+        };
+
+        let mut refresh = false;
+
+        while last_tick.elapsed() >= tick && !airpoker.is_finished() {
+            last_tick += tick;
+            seconds += 1;
+
+            if !airpoker.is_select_card_timeout() {
+                airpoker.decrement_select_card_timeout();
+            }
+
+            if !airpoker.is_bet_timeout() {
+                airpoker.decrement_bet_timeout();
+            }
+
+            airpoker.breathe();
+            refresh |= seconds % REFRESH_SECS == 0;
+        }
+
+        if airpoker.is_finished() || room.is_aborted() {
+            break;
+        }
+
+        let mut signal = match event {
+            Event::Interaction(inter) => handle_interaction(ctx, &inter, airpoker).await?,
+            Event::ModalInter(inter) => handle_modal(ctx, &inter, airpoker).await?,
+            Event::Tick => Signal::Tick,
+        };
+
+        if airpoker.phase == Phase::Selecting && airpoker.is_select_card_timeout() {
+            airpoker.select_missing();
+            signal = Signal::SelectCardEnd;
+        }
+
+        // letting the bet time run out folds the hand
+        if airpoker.phase == Phase::Betting && airpoker.is_bet_timeout() {
+            let id = airpoker.current_player().id;
+            airpoker.bet(id, BetAction::Fold)?;
+            signal = Signal::BetRoundEnd;
+        }
+
+        match signal {
+            Signal::SelectCardEnd => {
+                airpoker.start_betting();
+                last = None;
+            }
+            Signal::BetRoundEnd => {
+                let revealed = airpoker
+                    .players
+                    .iter()
+                    .filter_map(|player| {
+                        player
+                            .selected_card
+                            .clone()
+                            .map(|card| (player.name.clone(), card))
+                    })
+                    .collect::<Vec<_>>();
+
+                let result = airpoker.showdown();
+                last = Some(Response::showdown(ctx, airpoker, &revealed, &result));
+            }
+            Signal::Update => {}
+            Signal::Tick if refresh => {}
+            Signal::Tick => continue,
+        }
+
+        if !airpoker.is_finished() {
+            Response::table(ctx, reply, airpoker, last.as_deref()).await?;
+        }
+    }
+
+    Ok(last)
+}
+
+async fn handle_interaction(
+    ctx: Context<'_>,
+    inter: &ComponentInteraction,
+    airpoker: &mut AirPoker,
+) -> Result<Signal, Error> {
+    let action = match inter.data.custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+        Some(action) => action,
+        None => return Ok(Signal::Tick),
+    };
+
+    let player = match airpoker.get_player(inter.user.id) {
+        Ok(player) => player,
+        Err(_) => return Ok(Signal::Tick),
+    };
+
+    if action == "hand" {
+        Response::your_cards(ctx, inter, player).await?;
+        return Ok(Signal::Tick);
+    }
+
+    if action == "choose" {
+        if airpoker.phase != Phase::Selecting || player.selected_card.is_some() {
+            CommonRes::already_action(ctx, inter).await?;
+            return Ok(Signal::Tick);
+        }
+
+        inter.defer_ephemeral(ctx).await?;
+        let message = Response::choose_card(ctx, inter, player).await?;
+
+        airpoker
+            .get_mut_player(inter.user.id)?
+            .set_ephemeral(message);
+        return Ok(Signal::Tick);
+    }
+
+    if let Some(index) = action.strip_prefix("card_") {
+        let index = match index.parse::<usize>() {
+            Ok(index) => index,
+            Err(_) => return Ok(Signal::Tick),
         };
 
-        match event {
-            Event::Interaction(inter) => {
-                if inter.user.id != ctx.author().id && inter.user.id != user.id {
-                    continue;
-                }
+        inter.defer(ctx).await?;
 
-                elapsed = Instant::now();
+        let player = airpoker.get_mut_player(inter.user.id)?;
 
-                if inter.data.custom_id == format!("{}_accept", ctx.id()) {
-                    airpoker.deal_cards();
-                }
+        if let Some(message) = player.ephemeral_message.take() {
+            inter.delete_followup(ctx, message.id).await?;
+        }
 
-                if inter.data.custom_id == format!("{}_decline", ctx.id()) {
-                    return Ok(());
-                }
+        if airpoker.select(inter.user.id, index).is_err() {
+            return Ok(Signal::Tick);
+        }
 
-                if inter.data.custom_id == format!("{}_choose", ctx.id()) {
-                    inter.defer_ephemeral(ctx).await?;
+        return match airpoker.all_selected() {
+            true => Ok(Signal::SelectCardEnd),
+            false => Ok(Signal::Update),
+        };
+    }
 
-                    let player = airpoker.get_player(inter.user.id).unwrap();
-                }
+    if airpoker.phase != Phase::Betting {
+        return Ok(Signal::Tick);
+    }
 
-                if inter.data.custom_id == format!("{}_bet", ctx.id()) {
-                    let player = airpoker.get_player(inter.user.id).unwrap();
+    if airpoker.current_player().id != inter.user.id {
+        CommonRes::isnt_your_turn(ctx, inter).await?;
+        return Ok(Signal::Tick);
+    }
+
+    let bet_action = match action {
+        "call" => BetAction::Call,
+        "fold" => BetAction::Fold,
+        "raise" => {
+            let rival = airpoker.get_rival(inter.user.id)?;
 
-                    if player.get_betable_air_bios() < airpoker.blind as usize {
-                        Response::inform_all_in(ctx, inter).await?;
-                    } else {
-                        Response::open_bet_modal(ctx, inter, player).await?;
-                    }
-                }
+            match player.get_betable_air_bios() <= rival.bet as usize {
+                true => Response::inform_all_in(ctx, inter).await?,
+                false => Response::open_bet_modal(ctx, inter, player).await?,
             }
-            Event::ModalInter(inter) => {}
-            Event::Timeout => {
-                // Handle timeout logic here
-                break;
+
+            return Ok(Signal::Tick);
+        }
+        _ => return Ok(Signal::Tick),
+    };
+
+    inter.defer(ctx).await?;
+
+    match airpoker.bet(inter.user.id, bet_action)? {
+        true => Ok(Signal::BetRoundEnd),
+        false => Ok(Signal::Update),
+    }
+}
+
+async fn handle_modal(
+    ctx: Context<'_>,
+    inter: &ModalInteraction,
+    airpoker: &mut AirPoker,
+) -> Result<Signal, Error> {
+    if inter.data.custom_id != format!("{}_raise", ctx.id()) {
+        return Ok(Signal::Tick);
+    }
+
+    let modal = BetModal::parse(inter.data.clone())?;
+
+    let closed = match modal.amount.trim().parse::<u8>() {
+        Ok(amount) => airpoker.bet(inter.user.id, BetAction::Raise(amount)),
+        Err(_) => Err("Invalid raise".into()),
+    };
+
+    match closed {
+        Ok(closed) => {
+            CommonRes::know_modal(ctx, inter).await?;
+
+            match closed {
+                true => Ok(Signal::BetRoundEnd),
+                false => Ok(Signal::Update),
             }
         }
+        Err(_) => {
+            CommonRes::incorrect_bet(ctx, inter).await?;
+            Ok(Signal::Tick)
+        }
+    }
+}
+
+// the player left with more air takes the pot, a draw refunds both stakes
+async fn settle(ctx: Context<'_>, airpoker: &AirPoker, bet: i64) -> Result<(), Error> {
+    match airpoker.winner() {
+        Some(winner) => {
+            let loser = airpoker.get_rival(winner.id)?;
+
+            crate::charge_bet(ctx, winner.id, loser.id, bet, Game::AirPoker).await?;
+            crate::release_bet(ctx, Some(winner.id)).await?;
+        }
+        None => {
+            crate::free_gamble(
+                ctx,
+                airpoker.players.iter().map(|player| player.id).collect(),
+            )
+            .await?;
+        }
     }
 
     Ok(())
@@ -1,18 +1,257 @@
-use crate::{translate, Context, Error};
+use crate::{commands::CommonButton, translate, Context, Error, Parser};
 use poise::{
     serenity_prelude::{
         ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInputText,
         CreateInteractionResponse, CreateInteractionResponseFollowup,
-        CreateInteractionResponseMessage, CreateModal, InputTextStyle, Message, MessageId,
+        CreateInteractionResponseMessage, CreateModal, InputTextStyle, Mentionable, Message, User,
     },
-    CreateReply,
+    CreateReply, ReplyHandle,
+};
+use types::{
+    airpoker::{AirPoker, Phase, Player, RoundResult, ROUNDS},
+    cards::{air::SteelCard, poker::Card},
 };
-use types::airpoker::Player;
 
 pub struct Response;
 
+struct Button;
+
+fn cards(hand: &[Card]) -> String {
+    hand.iter()
+        .map(|card| card.short_name())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn table(ctx: Context<'_>, airpoker: &AirPoker) -> String {
+    let mut content = translate!(
+        ctx,
+        "air-table",
+        round: airpoker.round,
+        rounds: ROUNDS,
+        blind: airpoker.blind
+    );
+
+    for player in airpoker.players.iter() {
+        let line = translate!(
+            ctx,
+            "air-player",
+            user: player.name.as_str(),
+            tanks: player.air_bios.len(),
+            air: player.get_active_tank_duration(),
+            bet: player.bet
+        );
+        content.push_str(&format!("\n{}", line));
+    }
+
+    let phase = match airpoker.phase {
+        Phase::Selecting => {
+            let waiting = airpoker
+                .players
+                .iter()
+                .filter(|player| player.selected_card.is_none())
+                .map(|player| player.name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ");
+
+            translate!(ctx, "air-selecting", users: waiting)
+        }
+        Phase::Betting => {
+            translate!(ctx, "air-betting", user: airpoker.current_player().name.as_str())
+        }
+        Phase::Finished => return content,
+    };
+
+    format!("{}\n\n{}", content, phase)
+}
+
 impl Response {
-    pub async fn inform_all_in(ctx: Context<'_>, inter: ComponentInteraction) -> Result<(), Error> {
+    pub async fn request<'a>(
+        ctx: Context<'a>,
+        user: &User,
+        amount: i64,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let bet = Parser::abbreviate_number(amount);
+        let content =
+            translate!(ctx, "gamble-request", user: user.mention().to_string(), amount: bet);
+
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(content)
+                    .components(CommonButton::accept_or_decline(ctx, false))
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn declined(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        user: &User,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "game-declined", user: user.mention().to_string());
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(CommonButton::accept_or_decline(ctx, true))
+                        .allowed_mentions(crate::mentions()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn expired(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "air-expired");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(content)
+                    .components(CommonButton::accept_or_decline(ctx, true)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn aborted(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "room-aborted");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn table(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        airpoker: &AirPoker,
+        last: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut content = table(ctx, airpoker);
+
+        if let Some(last) = last {
+            content = format!("{}\n\n{}", last, content);
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(content)
+                    .components(Button::actions(ctx, airpoker)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // both selected cards are revealed with the tanks that changed hands,
+    // they are taken before the showdown since the next round resets them
+    pub fn showdown(
+        ctx: Context<'_>,
+        airpoker: &AirPoker,
+        revealed: &[(String, SteelCard)],
+        result: &RoundResult,
+    ) -> String {
+        let name = |id| {
+            airpoker
+                .get_player(id)
+                .map(|player| player.name.clone())
+                .unwrap_or_default()
+        };
+
+        match result {
+            RoundResult::Fold { winner, tanks } => {
+                translate!(ctx, "air-fold", user: name(*winner), tanks: *tanks)
+            }
+            RoundResult::Win { winner, tanks } => {
+                let mut content = translate!(ctx, "air-win", user: name(*winner), tanks: *tanks);
+
+                for (name, card) in revealed.iter() {
+                    let line = translate!(
+                        ctx,
+                        "air-showdown",
+                        user: name.as_str(),
+                        value: card.value(),
+                        cards: cards(&card.hand)
+                    );
+                    content.push_str(&format!("\n{}", line));
+                }
+
+                content
+            }
+            RoundResult::Draw => translate!(ctx, "air-draw"),
+        }
+    }
+
+    pub async fn end(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        airpoker: &AirPoker,
+        last: Option<&str>,
+        bet: i64,
+    ) -> Result<(), Error> {
+        let amount = Parser::num_with_commas(bet);
+
+        let mut content = match airpoker.winner() {
+            Some(winner) => {
+                let loser = airpoker.get_rival(winner.id)?;
+
+                match loser.is_alive {
+                    true => translate!(
+                        ctx,
+                        "air-end",
+                        winner: winner.name.as_str(),
+                        amount: amount
+                    ),
+                    false => translate!(
+                        ctx,
+                        "air-dead",
+                        winner: winner.name.as_str(),
+                        loser: loser.name.as_str(),
+                        amount: amount
+                    ),
+                }
+            }
+            None => translate!(ctx, "air-end-draw"),
+        };
+
+        if let Some(last) = last {
+            content = format!("{}\n\n{}", last, content);
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(format!("{}\n\n{}", content, table(ctx, airpoker)))
+                    .components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn inform_all_in(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+    ) -> Result<(), Error> {
         let content = translate!(ctx, "inform-all-in");
         inter
             .create_response(
@@ -30,15 +269,19 @@ impl Response {
 
     pub async fn your_cards(
         ctx: Context<'_>,
-        inter: ComponentInteraction,
+        inter: &ComponentInteraction,
         player: &Player,
     ) -> Result<(), Error> {
-        let cards = player
+        let mut cards = player
             .hand
             .iter()
             .map(|card| card.value().to_string())
             .collect::<Vec<_>>();
 
+        if let Some(card) = &player.selected_card {
+            cards.insert(0, format!("**{}**", card.value()));
+        }
+
         let content = translate!(ctx, "your-cards", cards: cards.join(", "));
 
         inter
@@ -55,28 +298,98 @@ impl Response {
         Ok(())
     }
 
+    // steel cards only show their number, the hand behind it stays hidden
+    pub async fn choose_card(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        player: &Player,
+    ) -> Result<Message, Error> {
+        let content = translate!(ctx, "air-choose-card");
+
+        let message = inter
+            .create_followup(
+                ctx,
+                CreateInteractionResponseFollowup::new()
+                    .content(content)
+                    .components(Button::cards(ctx, player)),
+            )
+            .await?;
+
+        Ok(message)
+    }
+
     pub async fn open_bet_modal(
         ctx: Context<'_>,
-        inter: ComponentInteraction,
+        inter: &ComponentInteraction,
         player: &Player,
     ) -> Result<(), Error> {
         let title = translate!(ctx, "air-modal-title");
         let question = translate!(ctx, "air-modal-question");
-        let placeholder = translate!(ctx, "air-modal-placeholder",
-            bios: player.get_betable_air_bios());
+        let placeholder = translate!(
+            ctx,
+            "air-modal-placeholder",
+            bios: player.get_betable_air_bios()
+        );
 
         inter
             .create_response(
                 ctx,
-                CreateInteractionResponse::Modal(CreateModal::new("hola", title).components(
-                    vec![CreateActionRow::InputText(
-                CreateInputText::new(InputTextStyle::Short, question, "hola")
-                .placeholder(placeholder)
-            )],
-                )),
+                CreateInteractionResponse::Modal(
+                    CreateModal::new(format!("{}_raise", ctx.id()), title).components(vec![
+                        CreateActionRow::InputText(
+                            CreateInputText::new(InputTextStyle::Short, question, "amount")
+                                .placeholder(placeholder),
+                        ),
+                    ]),
+                ),
             )
             .await?;
 
         Ok(())
     }
 }
+
+impl Button {
+    fn actions(ctx: Context<'_>, airpoker: &AirPoker) -> Vec<CreateActionRow> {
+        let buttons = match airpoker.phase {
+            Phase::Selecting => vec![CreateButton::new(format!("{}_choose", ctx.id()))
+                .style(ButtonStyle::Primary)
+                .label(translate!(ctx, "choose-card"))],
+            Phase::Betting => vec![
+                CreateButton::new(format!("{}_call", ctx.id()))
+                    .style(ButtonStyle::Secondary)
+                    .label(translate!(ctx, "call")),
+                CreateButton::new(format!("{}_raise", ctx.id()))
+                    .style(ButtonStyle::Primary)
+                    .label(translate!(ctx, "raise")),
+                CreateButton::new(format!("{}_fold", ctx.id()))
+                    .style(ButtonStyle::Danger)
+                    .label(translate!(ctx, "fold")),
+            ],
+            Phase::Finished => return vec![],
+        };
+
+        let hand = CreateButton::new(format!("{}_hand", ctx.id()))
+            .style(ButtonStyle::Secondary)
+            .label(translate!(ctx, "air-hand"));
+
+        vec![CreateActionRow::Buttons(
+            buttons.into_iter().chain([hand]).collect(),
+        )]
+    }
+
+    fn cards(ctx: Context<'_>, player: &Player) -> Vec<CreateActionRow> {
+        let buttons = player
+            .hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| {
+                CreateButton::new(format!("{}_card_{}", ctx.id(), index))
+                    .style(ButtonStyle::Secondary)
+                    .label(card.value().to_string())
+            })
+            .collect::<Vec<CreateButton>>();
+
+        vec![CreateActionRow::Buttons(buttons)]
+    }
+}
@@ -1,4 +1,9 @@
+use super::rpg::stat_key;
 use crate::{helpers, translate, Context};
+use database::{
+    market::Market,
+    player::{Player, Slot, STATS},
+};
 use futures::{Stream, StreamExt};
 
 pub async fn items_auto<'a>(ctx: Context<'_>, partial: &'a str) -> impl Stream<Item = String> + 'a {
@@ -30,6 +35,205 @@ pub async fn items_auto<'a>(ctx: Context<'_>, partial: &'a str) -> impl Stream<I
         .map(|name| name.to_string())
 }
 
+// items on sale in the market, once per item
+pub async fn market_auto<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let listings = Market::browse(&ctx.data().pool, None, None)
+        .await
+        .unwrap_or_default();
+
+    let mut items = listings
+        .iter()
+        .map(|listing| (listing.item, listing.name.clone()))
+        .collect::<Vec<(i32, String)>>();
+
+    items.sort();
+    items.dedup();
+
+    let items = items
+        .into_iter()
+        .map(|(id, name)| format!("{} id {}", translate!(ctx, &name), id))
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(items)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// every item there is, whether someone owns it or not
+pub async fn all_items_auto<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let items = {
+        let system = helpers::get_system(ctx).await;
+        let lock = system.lock().await;
+        lock.get_item_names(&ctx.data().pool)
+            .await
+            .unwrap_or_default()
+    };
+
+    let items = items
+        .into_iter()
+        .map(|(id, name)| format!("{} id {}", translate!(ctx, &name), id))
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(items)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// items of the author in the running season
+pub async fn rpg_items_auto<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let pool = &ctx.data().pool;
+
+    let items = match Player::current(pool, ctx.author().id.into()).await {
+        Ok((_, player)) => player.inventory(pool).await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+
+    let mut items = items
+        .iter()
+        .map(|item| {
+            format!(
+                "{} {} id {}",
+                item.amount,
+                translate!(ctx, &item.name),
+                item.id
+            )
+        })
+        .collect::<Vec<String>>();
+
+    if items.is_empty() {
+        items.push(translate!(ctx, "empty-inventory"));
+    }
+
+    futures::stream::iter(items)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// recipes the author can make right now
+pub async fn craftable_auto<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let pool = &ctx.data().pool;
+
+    let recipes = match Player::current(pool, ctx.author().id.into()).await {
+        Ok((_, player)) => player.recipes(pool).await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+
+    let recipes = recipes
+        .iter()
+        .filter(|recipe| recipe.can_craft())
+        .map(|recipe| format!("{} id {}", translate!(ctx, &recipe.name), recipe.item))
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(recipes)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// monsters the author can fight this season, ending with their id
+pub async fn monster_auto<'a>(
+    ctx: Context<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let pool = &ctx.data().pool;
+
+    let monsters = match Player::current(pool, ctx.author().id.into()).await {
+        Ok((_, player)) => player.monsters(pool).await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+
+    let monsters = monsters
+        .iter()
+        .map(|monster| {
+            format!(
+                "{} · {} id {}",
+                translate!(ctx, &format!("monster-{}", monster.name)),
+                translate!(ctx, "fight-level", level: monster.level),
+                monster.id
+            )
+        })
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(monsters)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// equipment slots, ending with their position in Slot::ALL
+pub async fn slot_auto<'a>(ctx: Context<'_>, partial: &'a str) -> impl Stream<Item = String> + 'a {
+    let slots = Slot::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, slot)| format!("{} {}", translate!(ctx, slot.key()), index + 1))
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(slots)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// skills of the running season, ending with their id
+pub async fn skill_auto<'a>(ctx: Context<'_>, partial: &'a str) -> impl Stream<Item = String> + 'a {
+    let pool = &ctx.data().pool;
+
+    let skills = match Player::current(pool, ctx.author().id.into()).await {
+        Ok((_, player)) => player.skills(pool).await.unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+
+    let skills = skills
+        .iter()
+        .map(|skill| {
+            let name = translate!(ctx, &format!("skill-{}", skill.name));
+            format!("{} id {}", name, skill.id)
+        })
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(skills)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
+// skill stats, ending with their position in STATS
+pub async fn stat_auto<'a>(ctx: Context<'_>, partial: &'a str) -> impl Stream<Item = String> + 'a {
+    let stats = STATS
+        .iter()
+        .enumerate()
+        .map(|(index, stat)| format!("{} {}", translate!(ctx, stat_key(*stat)), index + 1))
+        .collect::<Vec<String>>();
+
+    futures::stream::iter(stats)
+        .filter(move |name| {
+            futures::future::ready(name.to_lowercase().contains(&partial.to_lowercase()))
+        })
+        .map(|name| name.to_string())
+}
+
 pub async fn dices_auto<'a>(
     _ctx: Context<'_>,
     partial: &'a str,
@@ -1,58 +1,154 @@
 mod response;
+pub mod table;
 
+use super::{check::self_can_gamble, choice::Game};
 use crate::{Context, Duration, Error, Parser};
-use poise::serenity_prelude::ComponentInteractionCollector;
+use poise::serenity_prelude::{ComponentInteraction, ComponentInteractionCollector};
+use response::{ErrorRes, Response};
 use types::blackjack::Blackjack;
 
 #[poise::command(
     prefix_command,
     slash_command,
     install_context = "Guild|User",
-    interaction_context = "Guild|BotDm|PrivateChannel"
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
 )]
-pub async fn blackjack(ctx: Context<'_>, amount: Option<String>) -> Result<(), Error> {
-    let bet = Parser::amount(ctx, ctx.author().id, amount, 500).await?;
+pub async fn blackjack(ctx: Context<'_>, bios: Option<String>) -> Result<(), Error> {
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    crate::set_gamble(ctx, author_id).await?;
 
     let mut blackjack = Blackjack::new(ctx.author().clone(), bet);
 
     {
-        let member = crate::get_member(ctx, ctx.author().id).await?;
+        let member = crate::get_member(ctx, author_id).await?;
         let mut write = member.write().await;
 
+        if write.deck.len() < 26 {
+            write.reload_deck();
+        }
+
         blackjack.deal_cards(&mut write.deck);
     }
 
+    // without an ace showing the dealer checks the hole card right away
+    if !blackjack.insurance_open() {
+        blackjack.peek();
+    }
+
+    if blackjack.is_round_over() {
+        settle(ctx, &blackjack).await?;
+        Response::natural(ctx, &blackjack).await?;
+        return Ok(());
+    }
+
+    let reply = Response::start(ctx, &blackjack).await?;
+
     while let Some(inter) = ComponentInteractionCollector::new(ctx)
         .timeout(Duration::from_secs(60))
         .await
     {
-        if inter.user.id != ctx.author().id {
+        if inter.user.id != author_id {
             continue;
         }
 
-        if inter.data.custom_id == format!("hit_{}", ctx.id()) {
-            let member = crate::get_member(ctx, ctx.author().id).await?;
-            let mut write = member.write().await;
+        let custom_id = inter.data.custom_id.as_str();
+
+        let action = match custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+            Some(action) => action,
+            None => continue,
+        };
+
+        if action == "insurance" && blackjack.can_insure(author_id) {
+            let total = blackjack.seat().total_bet() + bet / 2;
 
-            blackjack.player_hit(&mut write.deck);
+            if !crate::can_partial_bet(ctx, author_id, total).await? {
+                ErrorRes::not_enough_bios(ctx, &inter).await?;
+                continue;
+            }
+
+            blackjack.take_insurance(author_id);
         }
 
-        if inter.data.custom_id == format!("stand_{}", ctx.id()) {
-            let member = crate::get_member(ctx, ctx.author().id).await?;
-            let mut write = member.write().await;
+        if action == "double" || action == "split" {
+            let total = blackjack.seat().total_bet() + blackjack.player().bet;
 
-            while blackjack.dealer.hand_value(false) < 17 {
-                blackjack.dealer_hit(&mut write.deck);
+            if !crate::can_partial_bet(ctx, author_id, total).await? {
+                ErrorRes::not_enough_bios(ctx, &inter).await?;
+                continue;
             }
         }
 
-        if inter.data.custom_id == format!("double_{}", ctx.id()) {
-            let member = crate::get_member(ctx, ctx.author().id).await?;
-            let mut write = member.write().await;
+        // any action closes insurance and the dealer checks the hole card
+        if !blackjack.peeked && blackjack.peek() {
+            break_round(ctx, &inter, &blackjack).await?;
+            return Ok(());
+        }
+
+        let member = crate::get_member(ctx, author_id).await?;
+        let mut write = member.write().await;
 
-            blackjack.player_hit(&mut write.deck);
+        match action {
+            "hit" => blackjack.player_hit(&mut write.deck),
+            "stand" => blackjack.player_stand(),
+            "double" if blackjack.player().can_double() => blackjack.double_down(&mut write.deck),
+            "split" if blackjack.can_split() => blackjack.split(&mut write.deck),
+            _ => {}
         }
+
+        if blackjack.is_round_over() {
+            blackjack.dealer_play(&mut write.deck);
+            drop(write);
+
+            break_round(ctx, &inter, &blackjack).await?;
+            return Ok(());
+        }
+
+        drop(write);
+        Response::update(ctx, &inter, &blackjack).await?;
     }
 
+    // unanswered hands stand when the table times out
+    blackjack.stand_all();
+
+    {
+        let member = crate::get_member(ctx, author_id).await?;
+        let mut write = member.write().await;
+
+        blackjack.dealer_play(&mut write.deck);
+    }
+
+    settle(ctx, &blackjack).await?;
+    Response::timeout(ctx, &reply, &blackjack).await?;
+
+    Ok(())
+}
+
+async fn break_round(
+    ctx: Context<'_>,
+    inter: &ComponentInteraction,
+    blackjack: &Blackjack,
+) -> Result<(), Error> {
+    settle(ctx, blackjack).await?;
+    Response::end(ctx, inter, blackjack).await?;
+
+    Ok(())
+}
+
+async fn settle(ctx: Context<'_>, blackjack: &Blackjack) -> Result<(), Error> {
+    let net = blackjack.settle(blackjack.seat());
+    let id = ctx.author().id;
+
+    if net != 0 {
+        crate::charge_single_bet(ctx, id, net.abs(), net > 0).await?;
+    }
+
+    crate::log_solo(ctx, id, net, blackjack.seat().total_bet(), Game::BlackJack).await?;
+
+    crate::free_gamble(ctx, vec![id]).await?;
+
     Ok(())
 }
@@ -0,0 +1,509 @@
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInputText,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle,
+        ModalInteraction,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::{
+    blackjack::{Blackjack, RoundResult, State, MAX_SEATS},
+    cards::poker::Card,
+};
+
+pub struct Response;
+
+pub struct ErrorRes;
+
+pub struct ModalRes;
+
+struct Button;
+
+fn cards(hand: &[Card]) -> String {
+    hand.iter()
+        .map(|card| format!("`{}`", card.short_name()))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn table(ctx: Context<'_>, blackjack: &Blackjack, reveal: bool) -> String {
+    let dealer_cards = if reveal {
+        cards(&blackjack.dealer.hand)
+    } else {
+        format!("{} `??`", cards(&blackjack.dealer.hand[..1]))
+    };
+
+    let mut content = translate!(
+        ctx,
+        "blackjack-dealer",
+        cards: dealer_cards,
+        value: blackjack.dealer.hand_value(!reveal)
+    );
+
+    let marked = blackjack.seats.len() > 1 || blackjack.seat().hands.len() > 1;
+
+    for (seat_index, seat) in blackjack.seats.iter().enumerate() {
+        for (index, hand) in seat.hands.iter().enumerate() {
+            let mut line = translate!(
+                ctx,
+                "blackjack-hand",
+                user: hand.name.as_str(),
+                cards: cards(&hand.hand),
+                value: hand.hand_value(),
+                amount: Parser::abbreviate_number(hand.bet)
+            );
+
+            let playing = seat_index == blackjack.turn && index == seat.current;
+
+            if !reveal && marked && playing && !blackjack.players_done() {
+                line = format!("▸ {}", line);
+            }
+
+            content.push_str(&format!("\n{}", line));
+        }
+
+        if seat.insurance > 0 {
+            let insurance = translate!(
+                ctx,
+                "blackjack-insured",
+                user: seat.name(),
+                amount: Parser::abbreviate_number(seat.insurance)
+            );
+            content.push_str(&format!("\n{}", insurance));
+        }
+    }
+
+    if !reveal && blackjack.seats.len() > 1 {
+        let turn = translate!(ctx, "blackjack-turn", user: blackjack.seat().name());
+        content.push_str(&format!("\n\n{}", turn));
+    }
+
+    content
+}
+
+fn results(ctx: Context<'_>, blackjack: &Blackjack) -> String {
+    let mut content = table(ctx, blackjack, true);
+    content.push('\n');
+
+    for seat in blackjack.seats.iter() {
+        for (hand, result) in seat.hands.iter().zip(blackjack.round_results(seat)) {
+            let line = match result {
+                RoundResult::Draw => translate!(ctx, "blackjack-draw", user: hand.name.as_str()),
+                RoundResult::Win {
+                    state: State::Blackjack,
+                } => translate!(ctx, "blackjack-natural", user: hand.name.as_str()),
+                RoundResult::Win { .. } => {
+                    translate!(ctx, "blackjack-win", user: hand.name.as_str())
+                }
+                RoundResult::Lose { bust: true } => {
+                    translate!(ctx, "blackjack-bust", user: hand.name.as_str())
+                }
+                RoundResult::Lose { bust: false } => {
+                    translate!(ctx, "blackjack-lose", user: hand.name.as_str())
+                }
+            };
+
+            content.push_str(&format!("\n{}", line));
+        }
+    }
+
+    content.push('\n');
+
+    for seat in blackjack.seats.iter() {
+        let net = blackjack.settle(seat);
+        let amount = Parser::num_with_commas(net.abs());
+
+        let summary = if blackjack.seats.len() == 1 {
+            match net {
+                0 => translate!(ctx, "blackjack-push"),
+                n if n > 0 => translate!(ctx, "won", amount: amount),
+                _ => translate!(ctx, "lost", amount: amount),
+            }
+        } else {
+            match net {
+                0 => translate!(ctx, "blackjack-seat-push", user: seat.name()),
+                n if n > 0 => {
+                    translate!(ctx, "blackjack-seat-won", user: seat.name(), amount: amount)
+                }
+                _ => translate!(ctx, "blackjack-seat-lost", user: seat.name(), amount: amount),
+            }
+        };
+
+        content.push_str(&format!("\n{}", summary));
+    }
+
+    content
+}
+
+fn lobby(ctx: Context<'_>, blackjack: &Blackjack, decks: usize) -> String {
+    let mut content = translate!(
+        ctx,
+        "blackjack-lobby",
+        decks: decks,
+        seats: blackjack.seats.len(),
+        max: MAX_SEATS
+    );
+
+    for seat in blackjack.seats.iter() {
+        let line = translate!(
+            ctx,
+            "blackjack-lobby-seat",
+            user: seat.name(),
+            amount: Parser::abbreviate_number(seat.bet)
+        );
+        content.push_str(&format!("\n{}", line));
+    }
+
+    content
+}
+
+impl ErrorRes {
+    pub async fn not_enough_bios(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "blackjack-no-bios");
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn ephemeral(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn invalid_seat_bet(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ModalRes {
+    pub async fn seat_bet(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        let modal = CreateModal::new(
+            format!("{}_seat", ctx.id()),
+            translate!(ctx, "bet-modal-title"),
+        )
+        .components(vec![CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Short,
+                translate!(ctx, "how-many-bios"),
+                "bios",
+            )
+            .required(false)
+            .placeholder("500"),
+        )]);
+
+        inter
+            .create_response(ctx, CreateInteractionResponse::Modal(modal))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Response {
+    pub async fn start<'a>(
+        ctx: Context<'a>,
+        blackjack: &Blackjack,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(table(ctx, blackjack, false))
+                    .components(Button::actions(ctx, blackjack)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn natural(ctx: Context<'_>, blackjack: &Blackjack) -> Result<(), Error> {
+        ctx.send(CreateReply::default().content(results(ctx, blackjack)))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn update(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        blackjack: &Blackjack,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(table(ctx, blackjack, false))
+                        .components(Button::actions(ctx, blackjack)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn end(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        blackjack: &Blackjack,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(results(ctx, blackjack))
+                        .components(vec![]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn timeout(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        blackjack: &Blackjack,
+    ) -> Result<(), Error> {
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(results(ctx, blackjack))
+                    .components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn lobby<'a>(
+        ctx: Context<'a>,
+        blackjack: &Blackjack,
+        decks: usize,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(lobby(ctx, blackjack, decks))
+                    .components(Button::lobby(ctx, false)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn seated(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        blackjack: &Blackjack,
+        decks: usize,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(lobby(ctx, blackjack, decks))
+                        .components(Button::lobby(ctx, blackjack.seats.len() == MAX_SEATS)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // edits the table when no interaction is at hand, after a seat timeout
+    pub async fn table(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        blackjack: &Blackjack,
+        reshuffled: bool,
+    ) -> Result<(), Error> {
+        let mut content = table(ctx, blackjack, false);
+
+        if reshuffled {
+            content = format!("{}\n{}", translate!(ctx, "blackjack-reshuffle"), content);
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(content)
+                    .components(Button::actions(ctx, blackjack)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn round_end(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        blackjack: &Blackjack,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(results(ctx, blackjack))
+                        .components(Button::next_round(ctx)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn round_end_edit(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        blackjack: &Blackjack,
+    ) -> Result<(), Error> {
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(results(ctx, blackjack))
+                    .components(Button::next_round(ctx)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn left(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        user: &str,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "blackjack-left", user: user);
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new().content(content),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn closed(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "blackjack-closed");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn actions(ctx: Context<'_>, blackjack: &Blackjack) -> Vec<CreateActionRow> {
+        let player = blackjack.player();
+
+        let mut buttons = vec![
+            CreateButton::new(format!("{}_hit", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "hit")),
+            CreateButton::new(format!("{}_stand", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "stand")),
+            CreateButton::new(format!("{}_double", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "double-down"))
+                .disabled(!player.can_double()),
+            CreateButton::new(format!("{}_split", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "split"))
+                .disabled(!blackjack.can_split()),
+        ];
+
+        let insurable = blackjack.seats.iter().any(|seat| seat.can_insure());
+
+        if blackjack.insurance_open() && insurable {
+            buttons.push(
+                CreateButton::new(format!("{}_insurance", ctx.id()))
+                    .style(ButtonStyle::Secondary)
+                    .label(translate!(ctx, "insurance")),
+            );
+        }
+
+        vec![CreateActionRow::Buttons(buttons)]
+    }
+
+    fn lobby(ctx: Context<'_>, full: bool) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_join", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "join"))
+                .disabled(full),
+            CreateButton::new(format!("{}_start", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "start")),
+        ])]
+    }
+
+    fn next_round(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_next", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "blackjack-next-round")),
+            CreateButton::new(format!("{}_leave", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "leave")),
+        ])]
+    }
+}
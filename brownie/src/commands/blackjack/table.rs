@@ -0,0 +1,375 @@
+use super::response::{ErrorRes, ModalRes, Response};
+use crate::{
+    commands::{
+        check::{self_can_gamble, user_can_gamble},
+        choice::Game,
+        CommonRes,
+    },
+    translate, Context, Duration, Error, Parser,
+};
+use poise::{
+    serenity_prelude::{
+        ComponentInteraction, ComponentInteractionCollector, ModalInteraction,
+        ModalInteractionCollector, UserId,
+    },
+    Modal, ReplyHandle,
+};
+use std::time::Instant;
+use types::blackjack::{Blackjack, Shoe, MAX_SEATS};
+
+const LOBBY_SECS: u64 = 45;
+const BREAK_SECS: u64 = 20;
+
+enum Event {
+    Inter(ComponentInteraction),
+    ModalInter(ModalInteraction),
+}
+
+#[derive(poise::Modal)]
+struct BetModal {
+    bios: Option<String>,
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn bjtable(
+    ctx: Context<'_>,
+    bios: Option<String>,
+    decks: Option<u8>,
+) -> Result<(), Error> {
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    crate::set_gamble(ctx, author_id).await?;
+
+    let mut blackjack = Blackjack::new(ctx.author().clone(), bet);
+    let mut shoe = Shoe::new(decks.unwrap_or(6) as usize);
+
+    let reply = Response::lobby(ctx, &blackjack, shoe.decks).await?;
+
+    lobby(ctx, &mut blackjack, shoe.decks).await?;
+
+    loop {
+        let reshuffled = shoe.reached_cut();
+
+        if reshuffled {
+            shoe.shuffle();
+        }
+
+        blackjack.deal_cards(&mut shoe);
+
+        // without an ace showing the dealer checks the hole card right away
+        if !blackjack.insurance_open() {
+            blackjack.peek();
+        }
+
+        Response::table(ctx, &reply, &blackjack, reshuffled).await?;
+
+        play_round(ctx, &reply, &mut blackjack, &mut shoe).await?;
+        next_round(ctx, &mut blackjack).await?;
+
+        if blackjack.seats.is_empty() {
+            Response::closed(ctx, &reply).await?;
+            break;
+        }
+
+        blackjack.clear_hands();
+    }
+
+    Ok(())
+}
+
+// users join with their own bet until the host starts or time runs out
+async fn lobby(ctx: Context<'_>, blackjack: &mut Blackjack, decks: usize) -> Result<(), Error> {
+    let started = Instant::now();
+    let lobby_time = Duration::from_secs(LOBBY_SECS);
+
+    while let Some(remaining) = lobby_time.checked_sub(started.elapsed()) {
+        let collector = ComponentInteractionCollector::new(ctx).timeout(remaining);
+        let modal_collector = ModalInteractionCollector::new(ctx).timeout(remaining);
+
+        let event = tokio::select! {
+            inter = collector.next() => match inter {
+                Some(inter) => Event::Inter(inter),
+                None => break,
+            },
+            modal_inter = modal_collector.next() => match modal_inter {
+                Some(modal_inter) => Event::ModalInter(modal_inter),
+                None => break,
+            },
+        };
+
+        match event {
+            Event::Inter(inter) => {
+                if inter.data.custom_id == format!("{}_join", ctx.id()) {
+                    if blackjack.get_seat(inter.user.id).is_some() {
+                        CommonRes::already_action(ctx, &inter).await?;
+                    } else if blackjack.seats.len() == MAX_SEATS {
+                        let content = translate!(ctx, "blackjack-full");
+                        ErrorRes::ephemeral(ctx, &inter, content).await?;
+                    } else if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                        ErrorRes::ephemeral(ctx, &inter, why.to_string()).await?;
+                    } else {
+                        ModalRes::seat_bet(ctx, &inter).await?;
+                    }
+                }
+
+                if inter.data.custom_id == format!("{}_start", ctx.id()) {
+                    if inter.user.id == ctx.author().id {
+                        inter.defer(ctx).await?;
+                        break;
+                    }
+
+                    let content = translate!(ctx, "error-button");
+                    ErrorRes::ephemeral(ctx, &inter, content).await?;
+                }
+            }
+            Event::ModalInter(inter) => {
+                if inter.data.custom_id != format!("{}_seat", ctx.id()) {
+                    continue;
+                }
+
+                let modal = BetModal::parse(inter.data.clone())?;
+                let bios = modal.bios.unwrap_or(500.to_string());
+
+                let bet = match Parser::abbreviation_to_number(&bios) {
+                    Ok(bet) if bet >= 500 => bet,
+                    _ => {
+                        CommonRes::incorrect_bet(ctx, &inter).await?;
+                        continue;
+                    }
+                };
+
+                if !crate::can_partial_bet(ctx, inter.user.id, bet).await? {
+                    CommonRes::incorrect_bet(ctx, &inter).await?;
+                    continue;
+                }
+
+                if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                    ErrorRes::invalid_seat_bet(ctx, &inter, why.to_string()).await?;
+                    continue;
+                }
+
+                if let Err(why) = blackjack.add_seat(inter.user.clone(), bet) {
+                    ErrorRes::invalid_seat_bet(ctx, &inter, why.to_string()).await?;
+                    continue;
+                }
+
+                crate::set_gamble(ctx, inter.user.id).await?;
+                Response::seated(ctx, &inter, blackjack, decks).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// seats play in order, a seat that runs out of time stands
+async fn play_round(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    blackjack: &mut Blackjack,
+    shoe: &mut Shoe,
+) -> Result<(), Error> {
+    let mut last_tick = Instant::now();
+
+    while !blackjack.is_round_over() {
+        let inter = ComponentInteractionCollector::new(ctx)
+            .timeout(Duration::from_secs(1))
+            .await;
+
+        while last_tick.elapsed() >= Duration::from_secs(1) && !blackjack.is_timeout() {
+            last_tick += Duration::from_secs(1);
+            blackjack.decrement_timeout();
+        }
+
+        if let Some(inter) = inter {
+            let user_id = inter.user.id;
+
+            if blackjack.get_seat(user_id).is_none() {
+                continue;
+            }
+
+            let custom_id = inter.data.custom_id.as_str();
+
+            let action = match custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+                Some(action) => action,
+                None => continue,
+            };
+
+            // insurance stays open for every seat until the first play
+            if action == "insurance" {
+                if !blackjack.can_insure(user_id) {
+                    CommonRes::already_action(ctx, &inter).await?;
+                    continue;
+                }
+
+                let seat = blackjack.get_seat(user_id).unwrap();
+                let total = seat.total_bet() + seat.bet / 2;
+
+                if !crate::can_partial_bet(ctx, user_id, total).await? {
+                    ErrorRes::not_enough_bios(ctx, &inter).await?;
+                    continue;
+                }
+
+                blackjack.take_insurance(user_id);
+                Response::update(ctx, &inter, blackjack).await?;
+                continue;
+            }
+
+            if user_id != blackjack.seat().id {
+                CommonRes::isnt_your_turn(ctx, &inter).await?;
+                continue;
+            }
+
+            if action == "double" || action == "split" {
+                let total = blackjack.seat().total_bet() + blackjack.player().bet;
+
+                if !crate::can_partial_bet(ctx, user_id, total).await? {
+                    ErrorRes::not_enough_bios(ctx, &inter).await?;
+                    continue;
+                }
+            }
+
+            if !blackjack.peeked && blackjack.peek() {
+                finish_round(ctx, Some(&inter), reply, blackjack, shoe).await?;
+                return Ok(());
+            }
+
+            match action {
+                "hit" => blackjack.player_hit(shoe),
+                "stand" => blackjack.player_stand(),
+                "double" if blackjack.player().can_double() => blackjack.double_down(shoe),
+                "split" if blackjack.can_split() => blackjack.split(shoe),
+                _ => {}
+            }
+
+            if blackjack.is_round_over() {
+                finish_round(ctx, Some(&inter), reply, blackjack, shoe).await?;
+                return Ok(());
+            }
+
+            Response::update(ctx, &inter, blackjack).await?;
+        }
+
+        if blackjack.is_timeout() {
+            blackjack.peek();
+            blackjack.seat_timeout();
+
+            if !blackjack.is_round_over() {
+                Response::table(ctx, reply, blackjack, false).await?;
+            }
+        }
+    }
+
+    finish_round(ctx, None, reply, blackjack, shoe).await?;
+
+    Ok(())
+}
+
+async fn finish_round(
+    ctx: Context<'_>,
+    inter: Option<&ComponentInteraction>,
+    reply: &ReplyHandle<'_>,
+    blackjack: &mut Blackjack,
+    shoe: &mut Shoe,
+) -> Result<(), Error> {
+    blackjack.dealer_play(shoe);
+
+    for seat in blackjack.seats.iter() {
+        let net = blackjack.settle(seat);
+
+        if net != 0 {
+            crate::charge_single_bet(ctx, seat.id, net.abs(), net > 0).await?;
+        }
+
+        crate::log_solo(ctx, seat.id, net, seat.total_bet(), Game::BlackJack).await?;
+    }
+
+    match inter {
+        Some(inter) => Response::round_end(ctx, inter, blackjack).await?,
+        None => Response::round_end_edit(ctx, reply, blackjack).await?,
+    }
+
+    Ok(())
+}
+
+// idle and broke seats are removed, the rest can leave before the next deal
+async fn next_round(ctx: Context<'_>, blackjack: &mut Blackjack) -> Result<(), Error> {
+    let idle = blackjack
+        .seats
+        .iter()
+        .filter(|seat| seat.timed_out)
+        .map(|seat| seat.id)
+        .collect::<Vec<UserId>>();
+
+    for id in idle.iter() {
+        blackjack.remove_seat(*id);
+    }
+
+    crate::free_gamble(ctx, idle).await?;
+
+    let started = Instant::now();
+    let break_time = Duration::from_secs(BREAK_SECS);
+
+    let mut ready = Vec::new();
+
+    while let Some(remaining) = break_time.checked_sub(started.elapsed()) {
+        if blackjack.seats.is_empty() || ready.len() == blackjack.seats.len() {
+            break;
+        }
+
+        let inter = match ComponentInteractionCollector::new(ctx)
+            .timeout(remaining)
+            .await
+        {
+            Some(inter) => inter,
+            None => break,
+        };
+
+        let user_id = inter.user.id;
+
+        let name = match blackjack.get_seat(user_id) {
+            Some(seat) => seat.name().to_string(),
+            None => continue,
+        };
+
+        if inter.data.custom_id == format!("{}_next", ctx.id()) {
+            if !ready.contains(&user_id) {
+                ready.push(user_id);
+            }
+
+            inter.defer(ctx).await?;
+        }
+
+        if inter.data.custom_id == format!("{}_leave", ctx.id()) {
+            blackjack.remove_seat(user_id);
+            ready.retain(|id| *id != user_id);
+
+            crate::free_gamble(ctx, vec![user_id]).await?;
+            Response::left(ctx, &inter, &name).await?;
+        }
+    }
+
+    let mut broke = Vec::new();
+
+    for seat in blackjack.seats.iter() {
+        if !crate::can_partial_bet(ctx, seat.id, seat.bet).await? {
+            broke.push(seat.id);
+        }
+    }
+
+    for id in broke.iter() {
+        blackjack.remove_seat(*id);
+    }
+
+    crate::free_gamble(ctx, broke).await?;
+
+    Ok(())
+}
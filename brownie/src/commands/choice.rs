@@ -20,6 +20,8 @@ pub enum Game {
     Falaris,
     ECard,
     AirPoker,
+    OldMaid,
+    Roulette,
 }
 
 impl std::fmt::Display for Game {
@@ -33,6 +35,8 @@ impl std::fmt::Display for Game {
             Game::Falaris => write!(f, "Falaris"),
             Game::ECard => write!(f, "ECard"),
             Game::AirPoker => write!(f, "AirPoker"),
+            Game::OldMaid => write!(f, "OldMaid"),
+            Game::Roulette => write!(f, "Roulette"),
         }
     }
 }
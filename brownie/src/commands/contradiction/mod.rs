@@ -12,7 +12,11 @@ use poise::{
 };
 use types::contradiction::{Battle, Contradiction, Player, Role};
 
-use super::{choice::Game, CommonRes};
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
 use crate::{Context, Error, Parser};
 
 enum Event {
@@ -30,6 +34,7 @@ struct BetModal {
     slash_command,
     install_context = "Guild|User",
     interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
     category = "gambling"
 )]
 pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> Result<(), Error> {
@@ -37,12 +42,16 @@ pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> R
         return Err("cannot play with yourself".into());
     }
 
+    user_can_gamble(ctx, user.clone()).await?;
+
     let bet = Parser::amount(ctx, ctx.author().id, bios, 1000).await?;
 
     let mut contradict = Contradiction::new(vec![Player::new(ctx.author())]);
 
     let mut message = Response::request(ctx, &user, bet).await?;
 
+    crate::set_gamble(ctx, ctx.author().id).await?;
+
     let mut last_inter_player: Option<UserId> = None;
     let mut last_inter: Option<ComponentInteraction> = None;
 
@@ -77,7 +86,21 @@ pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> R
 
                 if inter.data.custom_id == format!("{}_accept", ctx.id()) {
                     if inter.user.id == user.id {
-                        if !crate::can_partial_bet(ctx, inter.user.id, bet).await? {
+                        if user_can_gamble(ctx, inter.user.clone()).await.is_err()
+                            || !crate::can_partial_bet(ctx, inter.user.id, bet).await?
+                        {
+                            crate::free_gamble(ctx, vec![author_id]).await?;
+                            CommonRes::cannot_accept(ctx, &inter).await?;
+                            return Ok(());
+                        }
+
+                        crate::set_gamble(ctx, user.id).await?;
+
+                        if crate::hold_bet(ctx, vec![author_id, user.id], bet)
+                            .await
+                            .is_err()
+                        {
+                            crate::free_gamble(ctx, vec![author_id, user.id]).await?;
                             CommonRes::cannot_accept(ctx, &inter).await?;
                             return Ok(());
                         }
@@ -94,6 +117,7 @@ pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> R
                 }
 
                 if inter.data.custom_id == format!("{}_decline", ctx.id()) {
+                    crate::free_gamble(ctx, vec![author_id]).await?;
                     Response::declined(ctx, &inter, &inter.user).await?;
                     return Ok(());
                 }
@@ -211,6 +235,8 @@ pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> R
 
                             crate::charge_bet(ctx, winner.id, loser.id, bet, Game::Contradict)
                                 .await?;
+                            crate::release_bet(ctx, Some(winner.id)).await?;
+                            crate::free_gamble(ctx, vec![winner.id, loser.id]).await?;
 
                             Response::final_result(ctx, &inter, &winner.name, &loser.name).await?;
 
@@ -239,11 +265,15 @@ pub async fn contradict(ctx: Context<'_>, user: User, bios: Option<String>) -> R
         let loser = contradict.players.iter().find(|p| p.id != id).unwrap();
 
         crate::charge_bet(ctx, winner.id, loser.id, bet, Game::Contradict).await?;
+        crate::release_bet(ctx, Some(winner.id)).await?;
 
         if let Some(inter) = last_inter {
             CommonRes::vs_timeout(ctx, &inter, message.id, &winner.name, &loser.name).await?;
         }
     }
 
+    // refunds the stakes if nobody could be named the winner
+    crate::free_gamble(ctx, vec![ctx.author().id, user.id]).await?;
+
     Ok(())
 }
@@ -0,0 +1,178 @@
+use super::{
+    autocomplete::dices_auto,
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
+use crate::{
+    parser::ChoiceKind,
+    serenity::{
+        ComponentInteraction, ComponentInteractionCollector, ModalInteraction,
+        ModalInteractionCollector, User, UserId,
+    },
+    translate, Context, Duration, Error, Parser,
+};
+use poise::Modal;
+use response::{ErrorRes, ModalRes, Response};
+use std::time::Instant;
+use types::dices::{Dices, Player};
+
+mod response;
+
+const BETTING_SECS: u64 = 30;
+const MIN_BET: i64 = 500;
+
+enum Event {
+    Inter(ComponentInteraction),
+    ModalInter(ModalInteraction),
+}
+
+#[derive(poise::Modal)]
+struct JoinModal {
+    choice: String,
+    bios: Option<String>,
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn dices(
+    ctx: Context<'_>,
+    #[autocomplete = "dices_auto"] choice: String,
+    bios: Option<String>,
+) -> Result<(), Error> {
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, MIN_BET).await?;
+
+    let player = player(ctx.author(), choice, bet)?;
+    let mut dices = Dices::new(player);
+
+    crate::set_gamble(ctx, author_id).await?;
+
+    let reply = Response::table(ctx, &dices).await?;
+
+    betting(ctx, &mut dices).await?;
+
+    dices.roll_dices();
+
+    for ((id, net), player) in dices.payouts().into_iter().zip(dices.players.iter()) {
+        crate::charge_single_bet(ctx, id, net.abs(), net > 0).await?;
+        crate::log_solo(ctx, id, net, player.bet, Game::Dices).await?;
+    }
+
+    crate::free_gamble(
+        ctx,
+        dices
+            .players
+            .iter()
+            .map(|player| player.id)
+            .collect::<Vec<UserId>>(),
+    )
+    .await?;
+
+    Response::result(ctx, &reply, &dices).await?;
+
+    Ok(())
+}
+
+// a number is an exact bet on the sum, anything else is pair or unpair
+fn player(user: &User, choice: String, bet: i64) -> Result<Player, Error> {
+    let mut player = Player::new(user, bet);
+
+    match Parser::choice_kind(choice.clone()) {
+        ChoiceKind::Number => player.number(Parser::dice_choice_num(choice)?),
+        ChoiceKind::String => player.choice(Parser::dice_choice(choice)?),
+    }
+
+    Ok(player)
+}
+
+// other users join with their own choice until the host throws
+async fn betting(ctx: Context<'_>, dices: &mut Dices) -> Result<(), Error> {
+    let started = Instant::now();
+    let betting_time = Duration::from_secs(BETTING_SECS);
+
+    while let Some(remaining) = betting_time.checked_sub(started.elapsed()) {
+        let collector = ComponentInteractionCollector::new(ctx).timeout(remaining);
+        let modal_collector = ModalInteractionCollector::new(ctx).timeout(remaining);
+
+        let event = tokio::select! {
+            inter = collector.next() => match inter {
+                Some(inter) => Event::Inter(inter),
+                None => break,
+            },
+            modal_inter = modal_collector.next() => match modal_inter {
+                Some(modal_inter) => Event::ModalInter(modal_inter),
+                None => break,
+            },
+        };
+
+        match event {
+            Event::Inter(inter) => {
+                if inter.data.custom_id == format!("{}_join", ctx.id()) {
+                    if dices.get_player(inter.user.id).is_some() {
+                        CommonRes::already_action(ctx, &inter).await?;
+                    } else if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                        ErrorRes::ephemeral(ctx, &inter, why.to_string()).await?;
+                    } else {
+                        ModalRes::join(ctx, &inter).await?;
+                    }
+                }
+
+                if inter.data.custom_id == format!("{}_throw", ctx.id()) {
+                    if inter.user.id == ctx.author().id {
+                        inter.defer(ctx).await?;
+                        break;
+                    }
+
+                    let content = translate!(ctx, "error-button");
+                    ErrorRes::ephemeral(ctx, &inter, content).await?;
+                }
+            }
+            Event::ModalInter(inter) => {
+                if inter.data.custom_id != format!("{}_dice", ctx.id()) {
+                    continue;
+                }
+
+                let modal = JoinModal::parse(inter.data.clone())?;
+                let bios = modal.bios.unwrap_or(MIN_BET.to_string());
+
+                let bet = match Parser::abbreviation_to_number(&bios) {
+                    Ok(bet) if bet >= MIN_BET => bet,
+                    _ => {
+                        CommonRes::incorrect_bet(ctx, &inter).await?;
+                        continue;
+                    }
+                };
+
+                if !crate::can_partial_bet(ctx, inter.user.id, bet).await? {
+                    CommonRes::incorrect_bet(ctx, &inter).await?;
+                    continue;
+                }
+
+                if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                    ErrorRes::invalid_join(ctx, &inter, why.to_string()).await?;
+                    continue;
+                }
+
+                let joined = player(&inter.user, modal.choice, bet)
+                    .and_then(|player| dices.add_player(player));
+
+                if let Err(why) = joined {
+                    ErrorRes::invalid_join(ctx, &inter, why.to_string()).await?;
+                    continue;
+                }
+
+                crate::set_gamble(ctx, inter.user.id).await?;
+                Response::joined(ctx, &inter, dices).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
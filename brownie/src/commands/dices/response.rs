@@ -0,0 +1,200 @@
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInputText,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle,
+        ModalInteraction,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::dices::{Dices, Player, Selection};
+
+pub struct Response;
+
+pub struct ErrorRes;
+
+pub struct ModalRes;
+
+struct Button;
+
+fn choice(ctx: Context<'_>, player: &Player) -> String {
+    match (&player.selection, player.number) {
+        (Some(Selection::Pair), _) => translate!(ctx, "evens"),
+        (Some(Selection::Unpair), _) => translate!(ctx, "odds"),
+        (None, Some(number)) => number.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+fn table(ctx: Context<'_>, dices: &Dices) -> String {
+    let mut content = translate!(ctx, "dices-table");
+
+    for player in dices.players.iter() {
+        let line = translate!(
+            ctx,
+            "dices-player",
+            user: player.name.as_str(),
+            choice: choice(ctx, player),
+            amount: Parser::abbreviate_number(player.bet)
+        );
+        content.push_str(&format!("\n{}", line));
+    }
+
+    content
+}
+
+impl ErrorRes {
+    pub async fn ephemeral(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn invalid_join(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ModalRes {
+    pub async fn join(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        let modal = CreateModal::new(
+            format!("{}_dice", ctx.id()),
+            translate!(ctx, "bet-modal-title"),
+        )
+        .components(vec![
+            CreateActionRow::InputText(
+                CreateInputText::new(
+                    InputTextStyle::Short,
+                    translate!(ctx, "dices-choice-field"),
+                    "choice",
+                )
+                .placeholder("pair, unpair, 7"),
+            ),
+            CreateActionRow::InputText(
+                CreateInputText::new(
+                    InputTextStyle::Short,
+                    translate!(ctx, "how-many-bios"),
+                    "bios",
+                )
+                .required(false)
+                .placeholder("500"),
+            ),
+        ]);
+
+        inter
+            .create_response(ctx, CreateInteractionResponse::Modal(modal))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Response {
+    pub async fn table<'a>(ctx: Context<'a>, dices: &Dices) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(table(ctx, dices))
+                    .components(Button::table(ctx)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn joined(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        dices: &Dices,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(table(ctx, dices))
+                        .components(Button::table(ctx)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn result(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        dices: &Dices,
+    ) -> Result<(), Error> {
+        let number = dices.total();
+
+        let parity = match dices.check_pair(number) {
+            Selection::Pair => translate!(ctx, "evens"),
+            Selection::Unpair => translate!(ctx, "odds"),
+        };
+
+        let mut content = translate!(ctx, "dices-result", result: parity, number: number);
+
+        for player in dices.players.iter() {
+            let result = dices.player_result(player);
+            let amount = Parser::num_with_commas(player.bet * result.multiplier().abs());
+
+            let line = match result.is_win() {
+                true => translate!(ctx, "dices-won", user: player.name.as_str(), amount: amount),
+                false => translate!(ctx, "dices-lost", user: player.name.as_str(), amount: amount),
+            };
+
+            content.push_str(&format!("\n{}", line));
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn table(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_join", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "join")),
+            CreateButton::new(format!("{}_throw", ctx.id()))
+                .style(ButtonStyle::Primary)
+                .label(translate!(ctx, "throw-dices")),
+        ])]
+    }
+}
@@ -0,0 +1,205 @@
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
+use crate::{
+    serenity::{ComponentInteraction, ComponentInteractionCollector, Message, User, UserId},
+    Context, Duration, Error, Parser,
+};
+use response::Response;
+use types::ecard::{ECard, State};
+
+mod response;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn ecard(ctx: Context<'_>, user: User, bios: Option<String>) -> Result<(), Error> {
+    if user.id == ctx.author().id {
+        return Err("cannot play with yourself".into());
+    }
+
+    user_can_gamble(ctx, user.clone()).await?;
+
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    let mut ecard = ECard::new(ctx.author());
+
+    let message = Response::request(ctx, &user, bet).await?;
+
+    crate::set_gamble(ctx, author_id).await?;
+
+    let mut last_interaction = None;
+
+    while let Some(interaction) = ComponentInteractionCollector::new(ctx)
+        .timeout(Duration::from_secs(60))
+        .await
+    {
+        if interaction.user.id != user.id && interaction.user.id != author_id {
+            continue;
+        }
+
+        last_interaction = Some(interaction.clone());
+
+        if interaction.data.custom_id == format!("{}_accept", ctx.id()) {
+            if interaction.user.id != user.id {
+                CommonRes::self_accept(ctx, &interaction).await?;
+                continue;
+            }
+
+            if user_can_gamble(ctx, interaction.user.clone())
+                .await
+                .is_err()
+                || !crate::can_partial_bet(ctx, user.id, bet).await?
+            {
+                crate::free_gamble(ctx, vec![author_id]).await?;
+                CommonRes::cannot_accept(ctx, &interaction).await?;
+                return Ok(());
+            }
+
+            crate::set_gamble(ctx, user.id).await?;
+
+            if crate::hold_bet(ctx, vec![author_id, user.id], bet)
+                .await
+                .is_err()
+            {
+                crate::free_gamble(ctx, vec![author_id, user.id]).await?;
+                CommonRes::cannot_accept(ctx, &interaction).await?;
+                return Ok(());
+            }
+
+            ecard.add_player(&interaction.user)?;
+            ecard.start()?;
+
+            Response::start(ctx, &interaction, &ecard).await?;
+        }
+
+        if interaction.data.custom_id == format!("{}_decline", ctx.id()) {
+            crate::free_gamble(ctx, vec![author_id]).await?;
+            Response::declined(ctx, &interaction, &interaction.user).await?;
+            return Ok(());
+        }
+
+        if interaction.data.custom_id == format!("{}_choose", ctx.id()) {
+            let player = match ecard.get_player(interaction.user.id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            if player.has_selected() {
+                CommonRes::already_action(ctx, &interaction).await?;
+                continue;
+            }
+
+            interaction.defer_ephemeral(ctx).await?;
+            let message_id = Response::choose_card(ctx, &interaction, player).await?;
+
+            if let Some(player) = ecard.get_mut_player(interaction.user.id) {
+                player.set_ephemeral(message_id);
+            }
+        }
+
+        if let Some(index) = interaction
+            .data
+            .custom_id
+            .strip_prefix(&format!("{}_card_", ctx.id()))
+        {
+            if let Ok(index) = index.parse::<usize>() {
+                interaction.defer(ctx).await?;
+
+                let player = match ecard.get_mut_player(interaction.user.id) {
+                    Some(player) => player,
+                    None => continue,
+                };
+
+                if let Some(id) = player.take_ephemeral() {
+                    interaction.delete_followup(ctx, id).await?;
+                }
+
+                if ecard.select(interaction.user.id, index).is_err() {
+                    continue;
+                }
+
+                if !ecard.all_selected() {
+                    let player = ecard.get_player(interaction.user.id).unwrap();
+                    Response::selected(ctx, &interaction, message.id, &ecard, player).await?;
+                    continue;
+                }
+
+                let play = ecard.resolve()?;
+                Response::play(ctx, &interaction, message.id, &ecard, &play).await?;
+
+                if ecard.is_finished() {
+                    settle(ctx, &interaction, &ecard, bet).await?;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    if ecard.state != State::Waiting {
+        if let Some(interaction) = last_interaction {
+            timeout(ctx, &interaction, &message, &ecard, bet).await?;
+        }
+    }
+
+    // refunds the stakes if nobody could be named the winner
+    crate::free_gamble(ctx, vec![author_id, user.id]).await?;
+
+    Ok(())
+}
+
+async fn settle(
+    ctx: Context<'_>,
+    interaction: &ComponentInteraction,
+    ecard: &ECard,
+    bet: i64,
+) -> Result<(), Error> {
+    let ids = ecard
+        .players
+        .iter()
+        .map(|player| player.id)
+        .collect::<Vec<UserId>>();
+
+    if let Some(winner) = ecard.winner() {
+        let loser = ecard.rival(winner.id).unwrap();
+
+        crate::charge_bet(ctx, winner.id, loser.id, bet, Game::ECard).await?;
+        crate::release_bet(ctx, Some(winner.id)).await?;
+    }
+
+    crate::free_gamble(ctx, ids).await?;
+    Response::end(ctx, interaction, ecard, bet).await?;
+
+    Ok(())
+}
+
+// whoever pressed last was still playing, the rival loses
+async fn timeout(
+    ctx: Context<'_>,
+    interaction: &ComponentInteraction,
+    message: &Message,
+    ecard: &ECard,
+    bet: i64,
+) -> Result<(), Error> {
+    let winner = match ecard.get_player(interaction.user.id) {
+        Some(winner) => winner,
+        None => return Ok(()),
+    };
+
+    let loser = ecard.rival(winner.id).unwrap();
+
+    crate::charge_bet(ctx, winner.id, loser.id, bet, Game::ECard).await?;
+    crate::release_bet(ctx, Some(winner.id)).await?;
+
+    CommonRes::vs_timeout(ctx, interaction, message.id, &winner.name, &loser.name).await?;
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use super::super::CommonButton;
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseFollowup,
+        CreateInteractionResponseMessage, Mentionable, Message, MessageId, User,
+    },
+    CreateReply,
+};
+use types::ecard::{ECard, Play, PlayResult, Player, ROUNDS};
+
+pub struct Response;
+
+struct Button;
+
+fn score(ctx: Context<'_>, ecard: &ECard) -> String {
+    let emperor = ecard.get_side(types::ecard::Side::Emperor);
+    let slave = ecard.get_side(types::ecard::Side::Slave);
+
+    translate!(
+        ctx,
+        "ecard-score",
+        round: ecard.round,
+        rounds: ROUNDS,
+        emperor: emperor.name.as_str(),
+        ePoints: emperor.points,
+        slave: slave.name.as_str(),
+        sPoints: slave.points
+    )
+}
+
+impl Response {
+    pub async fn request(ctx: Context<'_>, user: &User, amount: i64) -> Result<Message, Error> {
+        let bet = Parser::abbreviate_number(amount);
+        let content =
+            translate!(ctx, "gamble-request", user: user.mention().to_string(), amount: bet);
+
+        let message = ctx
+            .send(
+                CreateReply::default()
+                    .content(content)
+                    .components(CommonButton::accept_or_decline(ctx, false))
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?
+            .into_message()
+            .await?;
+
+        Ok(message)
+    }
+
+    pub async fn declined(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        user: &User,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "game-declined", user: user.mention().to_string());
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(CommonButton::accept_or_decline(ctx, true))
+                        .allowed_mentions(crate::mentions()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn start(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        ecard: &ECard,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "ecard-start");
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(format!("{}\n{}", score(ctx, ecard), content))
+                        .components(Button::choose(ctx, false))
+                        .allowed_mentions(crate::mentions()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn choose_card(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        player: &Player,
+    ) -> Result<MessageId, Error> {
+        let side = translate!(ctx, &player.side.name());
+        let content = translate!(ctx, "ecard-your-cards", side: side);
+
+        let message = inter
+            .create_followup(
+                ctx,
+                CreateInteractionResponseFollowup::new()
+                    .content(content)
+                    .components(Button::cards(ctx, player)),
+            )
+            .await?;
+
+        Ok(message.id)
+    }
+
+    pub async fn selected(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        message_id: MessageId,
+        ecard: &ECard,
+        player: &Player,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "ecard-selected", user: player.name.as_str());
+
+        inter
+            .edit_followup(
+                ctx,
+                message_id,
+                CreateInteractionResponseFollowup::new()
+                    .content(format!("{}\n{}", score(ctx, ecard), content))
+                    .components(Button::choose(ctx, false))
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn play(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        message_id: MessageId,
+        ecard: &ECard,
+        play: &Play,
+    ) -> Result<(), Error> {
+        let mut content = play
+            .cards
+            .iter()
+            .map(|(id, card)| {
+                let name = ecard.get_player(*id).map(|p| p.name.as_str()).unwrap_or("");
+                let card = translate!(ctx, &card.name());
+                translate!(ctx, "ecard-played", user: name, card: card)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let result = match &play.result {
+            PlayResult::Draw => translate!(ctx, "ecard-draw"),
+            PlayResult::RoundWin { winner, side } => {
+                let name = ecard
+                    .get_player(*winner)
+                    .map(|p| p.name.as_str())
+                    .unwrap_or("");
+                let side = translate!(ctx, &side.name());
+                translate!(ctx, "ecard-round-win", user: name, side: side)
+            }
+        };
+
+        content.push_str(&format!("\n{}", result));
+
+        if play.swapped {
+            content.push_str(&format!("\n{}", translate!(ctx, "ecard-swap")));
+        }
+
+        let finished = ecard.is_finished();
+
+        inter
+            .edit_followup(
+                ctx,
+                message_id,
+                CreateInteractionResponseFollowup::new()
+                    .content(format!("{}\n\n{}", content, score(ctx, ecard)))
+                    .components(Button::choose(ctx, finished))
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn end(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        ecard: &ECard,
+        bet: i64,
+    ) -> Result<(), Error> {
+        let amount = Parser::num_with_commas(bet);
+
+        let content = match ecard.winner() {
+            Some(winner) => {
+                translate!(ctx, "ecard-end", winner: winner.name.as_str(), amount: amount)
+            }
+            None => translate!(ctx, "ecard-end-draw"),
+        };
+
+        inter
+            .create_followup(
+                ctx,
+                CreateInteractionResponseFollowup::new()
+                    .content(content)
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn choose(ctx: Context<'_>, disabled: bool) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![CreateButton::new(format!(
+            "{}_choose",
+            ctx.id()
+        ))
+        .style(ButtonStyle::Secondary)
+        .label(translate!(ctx, "choose-card"))
+        .disabled(disabled)])]
+    }
+
+    fn cards(ctx: Context<'_>, player: &Player) -> Vec<CreateActionRow> {
+        let buttons = player
+            .hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| {
+                CreateButton::new(format!("{}_card_{}", ctx.id(), index))
+                    .style(ButtonStyle::Secondary)
+                    .label(translate!(ctx, &card.name()))
+            })
+            .collect::<Vec<CreateButton>>();
+
+        vec![CreateActionRow::Buttons(buttons)]
+    }
+}
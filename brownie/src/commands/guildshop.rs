@@ -0,0 +1,236 @@
+use crate::{
+    get_guild,
+    serenity::{Role, RoleId},
+    translate, Context, Error, PageField, Parser,
+};
+use database::error::ShopError;
+use poise::serenity_prelude::Mentionable;
+
+// items and shop defined by the admins of each guild, paid with bios
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    category = "economy",
+    subcommand_required,
+    subcommands("view", "buy", "create", "delete", "stock", "unstock")
+)]
+pub async fn guildshop(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, guild_only, category = "economy")]
+pub async fn view(ctx: Context<'_>) -> Result<(), Error> {
+    let guild = get_guild(ctx.data(), ctx.guild_id().unwrap()).await?;
+    let read = guild.read().await;
+
+    if read.shop.is_empty() {
+        let content = translate!(ctx, "guildshop-empty");
+        ctx.reply(content).await?;
+        return Ok(());
+    }
+
+    let products = read
+        .shop
+        .iter()
+        .map(|product| {
+            let mut description = match product.stock {
+                Some(stock) => translate!(ctx, "product-info-stock",
+                    id: product.item.id,
+                    price: Parser::num_with_commas(product.price),
+                    stock: stock),
+                None => translate!(ctx, "product-info",
+                    id: product.item.id,
+                    price: Parser::num_with_commas(product.price)),
+            };
+
+            if let Some(text) = &product.item.description {
+                description = format!("{}\n{}", text, description);
+            }
+
+            if let Some(role) = product.item.role {
+                let role = RoleId::new(role as u64).mention().to_string();
+                let reward = translate!(ctx, "guildshop-role-reward", role: role);
+                description = format!("{}\n{}", description, reward);
+            }
+
+            PageField {
+                title: product.item.name.clone(),
+                description,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    drop(read);
+
+    crate::paginate(ctx, products).await?;
+
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, guild_only, category = "economy")]
+pub async fn buy(ctx: Context<'_>, item: i32, #[min = 1] amount: Option<i32>) -> Result<(), Error> {
+    let amount = amount.unwrap_or(1);
+    let guild_id = ctx.guild_id().unwrap();
+    let author_id = ctx.author().id;
+
+    // the member is locked before the purchase so its cached balance is read
+    // before the debit
+    let member = crate::get_member(ctx, author_id).await?;
+    let mut member_write = member.write().await;
+
+    let guild = get_guild(ctx.data(), guild_id).await?;
+    let mut write = guild.write().await;
+
+    let purchase = match write
+        .buy(&ctx.data().pool, author_id.into(), item, amount)
+        .await
+    {
+        Ok(purchase) => purchase,
+        Err(error) => return Err(shop_error(ctx, error).into()),
+    };
+
+    member_write.balance.bios -= purchase.total;
+    drop(member_write);
+
+    let product = write.get_product(item).unwrap().item.clone();
+    drop(write);
+
+    let mut content = translate!(ctx, "shop-bought",
+        amount: amount,
+        item: product.name.clone(),
+        bios: Parser::num_with_commas(purchase.total));
+
+    if let Some(role) = product.role {
+        let role = RoleId::new(role as u64);
+
+        let given = ctx
+            .http()
+            .add_member_role(guild_id, author_id, role, None)
+            .await;
+
+        let line = match given {
+            Ok(_) => translate!(ctx, "guildshop-role-given", role: role.mention().to_string()),
+            Err(_) => translate!(ctx, "guildshop-role-failed"),
+        };
+
+        content.push_str(&format!("\n{}", line));
+    }
+
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "economy"
+)]
+pub async fn create(
+    ctx: Context<'_>,
+    #[max_length = 50] name: String,
+    #[max_length = 255] details: Option<String>,
+    role: Option<Role>,
+) -> Result<(), Error> {
+    let guild = get_guild(ctx.data(), ctx.guild_id().unwrap()).await?;
+    let mut write = guild.write().await;
+
+    let role = role.map(|role| i64::from(role.id));
+
+    if let Err(error) = write
+        .create_item(&ctx.data().pool, &name, details, role)
+        .await
+    {
+        return Err(shop_error(ctx, error).into());
+    }
+
+    let content = translate!(ctx, "guildshop-created", item: name);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "economy"
+)]
+pub async fn delete(ctx: Context<'_>, name: String) -> Result<(), Error> {
+    let guild = get_guild(ctx.data(), ctx.guild_id().unwrap()).await?;
+    let mut write = guild.write().await;
+
+    if let Err(error) = write.delete_item(&ctx.data().pool, &name).await {
+        return Err(shop_error(ctx, error).into());
+    }
+
+    let content = translate!(ctx, "guildshop-deleted", item: name);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "economy"
+)]
+pub async fn stock(
+    ctx: Context<'_>,
+    name: String,
+    #[min = 1] price: i64,
+    #[min = 0] stock: Option<i32>,
+) -> Result<(), Error> {
+    let guild = get_guild(ctx.data(), ctx.guild_id().unwrap()).await?;
+    let mut write = guild.write().await;
+
+    if let Err(error) = write
+        .stock_item(&ctx.data().pool, &name, price, stock)
+        .await
+    {
+        return Err(shop_error(ctx, error).into());
+    }
+
+    let content = translate!(ctx, "guildshop-stocked", item: name);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "economy"
+)]
+pub async fn unstock(ctx: Context<'_>, name: String) -> Result<(), Error> {
+    let guild = get_guild(ctx.data(), ctx.guild_id().unwrap()).await?;
+    let mut write = guild.write().await;
+
+    if let Err(error) = write.unstock_item(&ctx.data().pool, &name).await {
+        return Err(shop_error(ctx, error).into());
+    }
+
+    let content = translate!(ctx, "guildshop-unstocked", item: name);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+fn shop_error(ctx: Context<'_>, error: ShopError) -> String {
+    match error {
+        ShopError::ItemNotFound => translate!(ctx, "shop-not-found"),
+        ShopError::ItemAlreadyExists => translate!(ctx, "guildshop-item-exists"),
+        ShopError::InvalidAmount => translate!(ctx, "shop-invalid-amount"),
+        ShopError::OutOfStock => translate!(ctx, "shop-out-of-stock"),
+        ShopError::InsufficientFunds => translate!(ctx, "not-enough-bios"),
+        _ => translate!(ctx, "unknown-error"),
+    }
+}
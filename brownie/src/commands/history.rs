@@ -0,0 +1,85 @@
+use crate::{serenity::User, translate, Context, Error, PageField, Parser};
+use database::gamble::GambleLog;
+use poise::serenity_prelude::{Mentionable, UserId};
+
+const RECENT_GAMES: i64 = 30;
+
+// win rate and net bios per game first, then the latest games
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "gambling"
+)]
+pub async fn history(ctx: Context<'_>, user: Option<User>) -> Result<(), Error> {
+    let user = user.unwrap_or(ctx.author().clone());
+    let member = i64::from(user.id);
+    let pool = &ctx.data().pool;
+
+    let summary = GambleLog::summary(pool, member).await?;
+
+    if summary.is_empty() {
+        let content = translate!(ctx, "history-empty", user: user.mention().to_string());
+        ctx.reply(content).await?;
+        return Ok(());
+    }
+
+    let records = GambleLog::history(pool, member, RECENT_GAMES).await?;
+
+    let mut pages = summary
+        .iter()
+        .map(|game| PageField {
+            title: game.name.clone(),
+            description: translate!(
+                ctx,
+                "history-summary",
+                played: game.played,
+                won: game.won,
+                rate: format!("{:.1}", game.win_rate()),
+                net: signed(game.net)
+            ),
+        })
+        .collect::<Vec<PageField>>();
+
+    for record in records.iter() {
+        let result = match record.net(member) {
+            0 => translate!(ctx, "history-push"),
+            net if net > 0 => translate!(ctx, "history-won", bios: Parser::num_with_commas(net)),
+            net => translate!(ctx, "history-lost", bios: Parser::num_with_commas(net.abs())),
+        };
+
+        let description = match record.rival(member) {
+            Some(rival) => translate!(
+                ctx,
+                "history-versus",
+                result: result,
+                rival: UserId::new(rival as u64).mention().to_string(),
+                since: format!("<t:{}:R>", record.created_at)
+            ),
+            None => translate!(
+                ctx,
+                "history-solo",
+                result: result,
+                since: format!("<t:{}:R>", record.created_at)
+            ),
+        };
+
+        pages.push(PageField {
+            title: record.name.clone(),
+            description,
+        });
+    }
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+// num_with_commas doesn't expect a sign
+pub fn signed(bios: i64) -> String {
+    match bios < 0 {
+        true => format!("-{}", Parser::num_with_commas(bios.abs())),
+        false => Parser::num_with_commas(bios),
+    }
+}
@@ -0,0 +1,227 @@
+use super::history::signed;
+use crate::{get_system, translate, Context, Error, PageField, Parser};
+use chrono::{Datelike, Duration, Utc};
+use database::league::{self, League};
+use poise::serenity_prelude::{Mentionable, UserId};
+
+const TOP: i64 = 10;
+
+fn mention(id: i64) -> String {
+    UserId::new(id as u64).mention().to_string()
+}
+
+// groups close when the week does, monday at midnight UTC
+fn week_end() -> i64 {
+    let today = Utc::now().date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+
+    (monday + Duration::days(7))
+        .and_hms_opt(0, 0, 0)
+        .map(|end| end.and_utc().timestamp())
+        .unwrap_or_default()
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy",
+    subcommand_required,
+    subcommands("global", "week", "month")
+)]
+pub async fn leaderboard(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+// richest members and the ones with more points
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn global(ctx: Context<'_>) -> Result<(), Error> {
+    let (money, points) = {
+        let system = get_system(ctx).await;
+        let lock = system.lock().await;
+        (lock.top_money.clone(), lock.top_level.clone())
+    };
+
+    let money = money
+        .iter()
+        .enumerate()
+        .map(|(index, member)| {
+            translate!(
+                ctx,
+                "leaderboard-bios-line",
+                rank: index + 1,
+                user: mention(member.id),
+                bios: Parser::num_with_commas(member.balance)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let points = points
+        .iter()
+        .enumerate()
+        .map(|(index, member)| {
+            translate!(
+                ctx,
+                "leaderboard-points-line",
+                rank: index + 1,
+                user: mention(member.id),
+                points: Parser::num_with_commas(member.points as i64)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let pages = vec![
+        PageField {
+            title: translate!(ctx, "leaderboard-money"),
+            description: money.join("\n"),
+        },
+        PageField {
+            title: translate!(ctx, "leaderboard-points"),
+            description: points.join("\n"),
+        },
+    ];
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+// the weekly group of the author, the first ones move up a tier
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn week(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let group = match League::group(pool, ctx.author().id.into()).await? {
+        Some(group) => group,
+        None => return Err(translate!(ctx, "leaderboard-no-group").into()),
+    };
+
+    let standings = League::standings(pool, group.id).await?;
+    let size = standings.len() as i64;
+
+    let lines = standings
+        .iter()
+        .map(|standing| {
+            let next = league::next_tier(group.tier, standing.rank, size);
+
+            // members without points are left out of the rollover
+            let arrow = match next.cmp(&group.tier) {
+                _ if standing.points <= 0 => "•",
+                std::cmp::Ordering::Greater => "▲",
+                std::cmp::Ordering::Less => "▼",
+                std::cmp::Ordering::Equal => "•",
+            };
+
+            translate!(
+                ctx,
+                "leaderboard-week-line",
+                arrow: arrow,
+                rank: standing.rank,
+                user: mention(standing.member),
+                points: Parser::num_with_commas(standing.points as i64),
+                bios: signed(standing.balance)
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let rewards = League::rewards(pool)
+        .await?
+        .iter()
+        .map(|reward| {
+            let (bios, special) = reward.for_tier(group.tier);
+
+            translate!(
+                ctx,
+                "leaderboard-reward-line",
+                rank: reward.rank,
+                bios: Parser::num_with_commas(bios),
+                special: special
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let pages = vec![
+        PageField {
+            title: translate!(
+                ctx,
+                "leaderboard-week-title",
+                tier: translate!(ctx, &format!("league-tier-{}", group.tier))
+            ),
+            description: format!(
+                "{}\n\n{}",
+                translate!(ctx, "leaderboard-week-end", end: format!("<t:{}:R>", week_end())),
+                lines.join("\n")
+            ),
+        },
+        PageField {
+            title: translate!(ctx, "leaderboard-rewards"),
+            description: rewards.join("\n"),
+        },
+    ];
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+// special balance earned in the leagues of this month
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn month(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let lines = League::month(pool, TOP)
+        .await?
+        .iter()
+        .enumerate()
+        .map(|(index, standing)| {
+            translate!(
+                ctx,
+                "leaderboard-special-line",
+                rank: index + 1,
+                user: mention(standing.member),
+                special: standing.special_balance
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let own = League::month_of(pool, ctx.author().id.into()).await?;
+
+    let description = match lines.is_empty() {
+        true => translate!(ctx, "leaderboard-month-empty"),
+        false => lines.join("\n"),
+    };
+
+    let pages = vec![
+        PageField {
+            title: translate!(ctx, "leaderboard-month-title"),
+            description,
+        },
+        PageField {
+            title: translate!(ctx, "leaderboard-month-own"),
+            description: translate!(ctx, "leaderboard-special", special: own),
+        },
+    ];
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use super::{
+    autocomplete::{items_auto, market_auto},
+    check::self_can_gamble,
+};
+use crate::{get_member, get_system, serenity::User, translate, Context, Error, PageField, Parser};
+use database::market::{Market, LISTING_HOURS};
+use poise::serenity_prelude::{Mentionable, UserId};
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy",
+    subcommand_required,
+    subcommands("sell", "view", "buy", "cancel", "tax")
+)]
+pub async fn market(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+// the items leave the inventory until they're sold, cancelled or expire
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy",
+    check = "self_can_gamble"
+)]
+pub async fn sell(
+    ctx: Context<'_>,
+    #[autocomplete = "items_auto"] item: String,
+    #[min = 1] amount: i32,
+    #[min = 1] price: i64,
+) -> Result<(), Error> {
+    expire_listings(ctx).await?;
+
+    let item_id = parse_item(ctx, &item)?;
+
+    let seller = get_member(ctx, ctx.author().id).await?;
+    let mut write = seller.write().await;
+
+    let owned = match write.get_item_by_id(item_id) {
+        Some(owned) => owned,
+        None => return Err(translate!(ctx, "use-not-owned").into()),
+    };
+
+    if owned.amount < amount {
+        return Err(translate!(ctx, "market-not-enough-items").into());
+    }
+
+    let data = ctx.data();
+    let mut tx = data.pool.begin().await?;
+
+    if write.remove_item(&mut *tx, item_id, amount).await.is_err() {
+        tx.rollback().await?;
+        crate::refresh_cache(ctx, ctx.author().id).await;
+
+        let err = translate!(ctx, "transaction-error");
+        return Err(err.into());
+    }
+
+    let id = Market::create(&mut tx, write.id, item_id, amount, price).await?;
+    tx.commit().await?;
+
+    let content = translate!(
+        ctx,
+        "market-listed",
+        id: id,
+        amount: amount,
+        item: translate!(ctx, &owned.info.name),
+        price: Parser::num_with_commas(price),
+        hours: LISTING_HOURS
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn view(
+    ctx: Context<'_>,
+    #[autocomplete = "market_auto"] item: Option<String>,
+    user: Option<User>,
+) -> Result<(), Error> {
+    expire_listings(ctx).await?;
+
+    let item = match item {
+        Some(item) => Some(parse_item(ctx, &item)?),
+        None => None,
+    };
+
+    let seller = user.map(|user| i64::from(user.id));
+    let listings = Market::browse(&ctx.data().pool, item, seller).await?;
+
+    if listings.is_empty() {
+        let content = translate!(ctx, "market-empty");
+        ctx.reply(content).await?;
+        return Ok(());
+    }
+
+    let pages = listings
+        .iter()
+        .map(|listing| PageField {
+            title: format!(
+                "#{} {} {}",
+                listing.id,
+                listing.amount,
+                translate!(ctx, &listing.name)
+            ),
+            description: translate!(
+                ctx,
+                "market-listing",
+                price: Parser::num_with_commas(listing.price),
+                seller: UserId::new(listing.seller as u64).mention().to_string(),
+                expires: format!("<t:{}:R>", listing.expires_at)
+            ),
+        })
+        .collect::<Vec<PageField>>();
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+// the seller gets the price minus the market tax
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy",
+    check = "self_can_gamble"
+)]
+pub async fn buy(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    expire_listings(ctx).await?;
+
+    let data = ctx.data();
+
+    let listing = match Market::get(&data.pool, id).await? {
+        Some(listing) => listing,
+        None => return Err(translate!(ctx, "market-not-found").into()),
+    };
+
+    let buyer_id = ctx.author().id;
+    let seller_id = UserId::new(listing.seller as u64);
+
+    if buyer_id == seller_id {
+        return Err(translate!(ctx, "market-own-listing").into());
+    }
+
+    let item = {
+        let system = get_system(ctx).await;
+        let lock = system.lock().await;
+        lock.get_item_by_id(&data.pool, listing.item).await?
+    };
+
+    let buyer = get_member(ctx, buyer_id).await?;
+    let seller = get_member(ctx, seller_id).await?;
+
+    // always in the same order so two members buying from each other can't
+    // wait on one another
+    let (mut buyer_write, mut seller_write) = if buyer_id < seller_id {
+        let buyer_write = buyer.write().await;
+        (buyer_write, seller.write().await)
+    } else {
+        let seller_write = seller.write().await;
+        (buyer.write().await, seller_write)
+    };
+
+    if buyer_write.get_bios() < listing.price {
+        let err = translate!(ctx, "not-enough-bios");
+        return Err(err.into());
+    }
+
+    let mut tx = data.pool.begin().await?;
+
+    // someone else may have bought it in the meantime
+    if Market::take(&mut tx, id, None).await?.is_none() {
+        tx.rollback().await?;
+        return Err(translate!(ctx, "market-not-found").into());
+    }
+
+    let tax = Market::collect(&mut tx, listing.price).await?;
+
+    let sold = async {
+        buyer_write.decrease_bios(&mut *tx, listing.price).await?;
+        seller_write
+            .increase_bios(&mut *tx, listing.price - tax)
+            .await?;
+        buyer_write.add_item(&mut *tx, item, listing.amount).await?;
+
+        Ok::<(), Error>(())
+    }
+    .await;
+
+    if sold.is_err() {
+        tx.rollback().await?;
+
+        drop(buyer_write);
+        drop(seller_write);
+        crate::refresh_cache(ctx, buyer_id).await;
+        crate::refresh_cache(ctx, seller_id).await;
+
+        let err = translate!(ctx, "transaction-error");
+        return Err(err.into());
+    }
+
+    tx.commit().await?;
+
+    let content = translate!(
+        ctx,
+        "market-bought",
+        amount: listing.amount,
+        item: translate!(ctx, &listing.name),
+        price: Parser::num_with_commas(listing.price),
+        seller: seller_id.mention().to_string(),
+        tax: Parser::num_with_commas(tax)
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn cancel(ctx: Context<'_>, id: i32) -> Result<(), Error> {
+    expire_listings(ctx).await?;
+
+    let data = ctx.data();
+
+    let seller = get_member(ctx, ctx.author().id).await?;
+    let mut write = seller.write().await;
+
+    let mut tx = data.pool.begin().await?;
+
+    let listing = match Market::take(&mut tx, id, Some(write.id)).await? {
+        Some(listing) => listing,
+        None => {
+            tx.rollback().await?;
+            return Err(translate!(ctx, "market-not-found").into());
+        }
+    };
+
+    let item = {
+        let system = get_system(ctx).await;
+        let lock = system.lock().await;
+        lock.get_item_by_id(&data.pool, listing.item).await?
+    };
+
+    write.add_item(&mut *tx, item, listing.amount).await?;
+    tx.commit().await?;
+
+    let content = translate!(
+        ctx,
+        "market-cancelled",
+        amount: listing.amount,
+        item: translate!(ctx, &listing.name)
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// sets the percent of every sale that leaves the economy
+#[poise::command(prefix_command, slash_command, owners_only, category = "economy")]
+pub async fn tax(
+    ctx: Context<'_>,
+    #[min = 0]
+    #[max = 100]
+    percent: Option<i32>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    if let Some(percent) = percent {
+        Market::set_tax(pool, percent).await?;
+    }
+
+    let content = translate!(
+        ctx,
+        "market-tax-info",
+        percent: Market::get_tax(pool).await?,
+        collected: Parser::num_with_commas(Market::collected(pool).await?)
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// items_auto and market_auto end every choice with the id of the item
+fn parse_item(ctx: Context<'_>, item: &str) -> Result<i32, Error> {
+    match item
+        .split_whitespace()
+        .last()
+        .and_then(|id| id.parse::<i32>().ok())
+    {
+        Some(id) => Ok(id),
+        None => Err(translate!(ctx, "market-not-found").into()),
+    }
+}
+
+// expired listings go back to their sellers before anything else is done
+async fn expire_listings(ctx: Context<'_>) -> Result<(), Error> {
+    let data = ctx.data();
+
+    for listing in Market::expire(&data.pool).await? {
+        let seller = UserId::new(listing.seller as u64);
+
+        if data.members.contains_key(seller.as_ref()) {
+            crate::refresh_cache(ctx, seller).await;
+        }
+    }
+
+    Ok(())
+}
@@ -12,15 +12,25 @@ pub mod check;
 pub mod choice;
 pub mod club;
 pub mod contradiction;
+pub mod dices;
 pub mod ecard;
 pub mod give;
 pub mod greeting;
+pub mod guildshop;
+pub mod history;
+pub mod leaderboard;
+pub mod market;
 pub mod nim;
+pub mod oldmaid;
 pub mod profile;
 pub mod rewards;
+pub mod rooms;
 pub mod roulette;
+pub mod rpg;
 pub mod rr;
+pub mod stats;
 pub mod store;
+pub mod trade;
 pub mod work;
 
 pub struct CommonRes;
@@ -4,10 +4,13 @@ use super::{
 };
 use crate::{
     serenity::{ComponentInteraction, ComponentInteractionCollector, Message, User, UserId},
-    Context, Duration, Error, Parser,
+    translate, Context, Duration, Error, Parser,
 };
 use responses::{ErrorRes, Response};
-use types::nim_type_zero::{Nim, Player};
+use types::{
+    nim_type_zero::{Nim, Player},
+    rooms::RoomHandle,
+};
 
 mod responses;
 
@@ -30,7 +33,34 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
 
     let bet = Parser::amount(ctx, ctx.author().id, bios, 500).await?;
 
-    let mut nim = Nim::new(Player::new(Some(ctx.author()), false), bet);
+    let game = Nim::new(Player::new(Some(ctx.author()), false), bet);
+
+    let channel = ctx.channel_id().get();
+    let guild = ctx.guild_id().map(|id| id.get());
+    let rooms = &ctx.data().rooms;
+
+    let room = match rooms
+        .nim
+        .open(channel, guild, ctx.id(), ctx.author().id, game)
+        .await
+    {
+        Ok(room) => room,
+        Err(_) => return Err(translate!(ctx, "room-taken").into()),
+    };
+
+    let result = run(ctx, &room, user, bet).await;
+    rooms.nim.close(channel, ctx.id()).await;
+
+    result
+}
+
+async fn run(
+    ctx: Context<'_>,
+    room: &RoomHandle<Nim>,
+    user: Option<User>,
+    bet: i64,
+) -> Result<(), Error> {
+    let mut nim = room.game.lock().await;
 
     let mut message = match user {
         Some(ref user) => Response::nim_request(ctx, user, bet).await?,
@@ -57,6 +87,12 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
             continue;
         }
 
+        // whoever aborted the room already freed the players
+        if room.is_aborted() {
+            Response::nim_aborted(ctx, &interaction).await?;
+            return Ok(());
+        }
+
         last_interaction = Some(interaction.clone());
 
         if interaction.data.custom_id == format!("{}_continue", ctx.id()) {
@@ -64,6 +100,8 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
                 Some(&ctx.http().get_user(UserId::new(896535593641734164)).await?),
                 true,
             ))?;
+            crate::hold_bet(ctx, vec![author_id], bet).await?;
+
             nim.deal_cards();
             Response::nim_start(ctx, &interaction, nim.current_player()).await?;
         }
@@ -85,6 +123,19 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
 
                 crate::set_gamble(ctx, user.clone().unwrap().id).await?;
 
+                let players = vec![author_id, interaction.user.id];
+
+                if crate::hold_bet(ctx, players.clone(), bet).await.is_err() {
+                    crate::free_gamble(ctx, players).await?;
+                    ErrorRes::cannot_accept(ctx, &interaction).await?;
+                    return Ok(());
+                }
+                ctx.data()
+                    .rooms
+                    .nim
+                    .join(ctx.channel_id().get(), interaction.user.id)
+                    .await;
+
                 nim.add_player(Player::new(Some(&interaction.user), false))?;
                 nim.deal_cards();
                 Response::nim_start(ctx, &interaction, nim.current_player()).await?;
@@ -135,6 +186,22 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
         }
     }
 
+    if room.is_aborted() {
+        return Ok(());
+    }
+
+    if let Some(interaction) = last_interaction.filter(|_| nim.players.len() == 2) {
+        let user_id = interaction.user.id;
+
+        let winner = nim.get_player(user_id);
+        let loser = nim.players.iter().find(|p| p.id != user_id).unwrap();
+
+        settle(ctx, &nim, winner, loser, bet).await?;
+
+        ErrorRes::nim_timeout(ctx, &interaction, message.id, &winner.name, &loser.name).await?;
+    }
+
+    // refunds the stakes when the game never started
     crate::free_gamble(
         ctx,
         nim.players
@@ -144,27 +211,35 @@ pub async fn nim(ctx: Context<'_>, user: Option<User>, bios: Option<String>) ->
     )
     .await?;
 
-    if let Some(interaction) = last_interaction {
-        let user_id = interaction.user.id;
-
-        let winner = nim.get_player(user_id);
-        let loser = nim.players.iter().find(|p| p.id != user_id).unwrap();
-
-        if !nim.players.iter().any(|player| player.is_bot()) {
-            crate::charge_bet(ctx, winner.id, loser.id, bet, Game::NimTypeZero).await?;
-        }
+    Ok(())
+}
 
-        if !winner.is_bot() {
-            crate::charge_single_bet(ctx, winner.id, bet, true).await?;
-        }
+// the pot goes to the winner, the house also pays the bet when a player beats
+// the bot and keeps the pot when the bot wins
+async fn settle(
+    ctx: Context<'_>,
+    nim: &Nim,
+    winner: &Player,
+    loser: &Player,
+    bet: i64,
+) -> Result<(), Error> {
+    if !nim.players.iter().any(|player| player.is_bot()) {
+        crate::charge_bet(ctx, winner.id, loser.id, bet, Game::NimTypeZero).await?;
+    }
 
-        if !loser.is_bot() {
-            crate::charge_single_bet(ctx, loser.id, bet, false).await?;
-        }
+    if loser.is_bot() {
+        crate::charge_single_bet(ctx, winner.id, bet, true).await?;
+        crate::log_solo(ctx, winner.id, bet, bet, Game::NimTypeZero).await?;
+    }
 
-        ErrorRes::nim_timeout(ctx, &interaction, message.id, &winner.name, &loser.name).await?;
+    if winner.is_bot() {
+        crate::add_points(ctx, loser.id, crate::points_revenue(bet).loser).await?;
+        crate::log_solo(ctx, loser.id, -bet, bet, Game::NimTypeZero).await?;
     }
 
+    let winner = (!winner.is_bot()).then_some(winner.id);
+    crate::release_bet(ctx, winner).await?;
+
     Ok(())
 }
 
@@ -179,17 +254,7 @@ async fn played_card_process(
         let loser = nim.current_player();
         let winner = nim.rival_player();
 
-        if !nim.players.iter().any(|player| player.is_bot()) {
-            crate::charge_bet(ctx, winner.id, loser.id, bet, Game::NimTypeZero).await?;
-        }
-
-        if !winner.is_bot() {
-            crate::charge_single_bet(ctx, winner.id, bet, true).await?;
-        }
-
-        if !loser.is_bot() {
-            crate::charge_single_bet(ctx, loser.id, bet, false).await?;
-        }
+        settle(ctx, nim, winner, loser, bet).await?;
 
         crate::free_gamble(
             ctx,
@@ -159,6 +159,23 @@ impl Response {
         Ok(())
     }
 
+    pub async fn nim_aborted(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        let content = translate!(ctx, "room-aborted");
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(vec![]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn choose_card(
         ctx: Context<'_>,
         inter: &ComponentInteraction,
@@ -0,0 +1,266 @@
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
+use crate::{
+    serenity::{ComponentInteractionCollector, UserId},
+    translate, Context, Duration, Error, Parser,
+};
+use poise::ReplyHandle;
+use response::{ErrorRes, Response};
+use std::time::Instant;
+use types::oldmaid::{Oldmaid, MAX_PLAYERS};
+
+mod response;
+
+const LOBBY_SECS: u64 = 45;
+const TURN_SECS: u64 = 60;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn oldmaid(ctx: Context<'_>, bios: Option<String>) -> Result<(), Error> {
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    if !crate::can_partial_bet(ctx, author_id, cover(bet)).await? {
+        return Err(translate!(ctx, "oldmaid-no-bios").into());
+    }
+
+    let mut oldmaid = Oldmaid::new(ctx.author());
+
+    crate::set_gamble(ctx, author_id).await?;
+
+    let reply = Response::lobby(ctx, &oldmaid, bet).await?;
+
+    lobby(ctx, &mut oldmaid, bet).await?;
+
+    if oldmaid.players.len() < 2 {
+        crate::free_gamble(ctx, ids(&oldmaid)).await?;
+        Response::cancelled(ctx, &reply).await?;
+        return Ok(());
+    }
+
+    // every player holds what they would pay if they lose
+    if crate::hold_bet(ctx, ids(&oldmaid), stake(&oldmaid, bet))
+        .await
+        .is_err()
+    {
+        crate::free_gamble(ctx, ids(&oldmaid)).await?;
+        Response::cancelled(ctx, &reply).await?;
+        return Err(translate!(ctx, "oldmaid-hold-failed").into());
+    }
+
+    oldmaid.deal_cards();
+    oldmaid.discard_pairs();
+
+    if oldmaid.get_actual().hand.is_empty() {
+        oldmaid.next_turn();
+    }
+
+    // stakes still held after an error go back to their players
+    let result = play(ctx, &reply, &mut oldmaid, bet).await;
+    crate::free_gamble(ctx, ids(&oldmaid)).await?;
+
+    result
+}
+
+// the loser may have to pay every other seat
+fn cover(bet: i64) -> i64 {
+    bet * (MAX_PLAYERS as i64 - 1)
+}
+
+fn stake(oldmaid: &Oldmaid, bet: i64) -> i64 {
+    bet * (oldmaid.players.len() as i64 - 1)
+}
+
+fn ids(oldmaid: &Oldmaid) -> Vec<UserId> {
+    oldmaid.players.iter().map(|player| player.id).collect()
+}
+
+// everyone joins with the host's bet until the host starts or time runs out
+async fn lobby(ctx: Context<'_>, oldmaid: &mut Oldmaid, bet: i64) -> Result<(), Error> {
+    let started = Instant::now();
+    let lobby_time = Duration::from_secs(LOBBY_SECS);
+
+    while let Some(remaining) = lobby_time.checked_sub(started.elapsed()) {
+        let inter = match ComponentInteractionCollector::new(ctx)
+            .timeout(remaining)
+            .await
+        {
+            Some(inter) => inter,
+            None => break,
+        };
+
+        if inter.data.custom_id == format!("{}_join", ctx.id()) {
+            if oldmaid
+                .players
+                .iter()
+                .any(|player| player.id == inter.user.id)
+            {
+                CommonRes::already_action(ctx, &inter).await?;
+            } else if oldmaid.players.len() == MAX_PLAYERS {
+                let content = translate!(ctx, "oldmaid-full");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+            } else if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                ErrorRes::ephemeral(ctx, &inter, why.to_string()).await?;
+            } else if !crate::can_partial_bet(ctx, inter.user.id, cover(bet)).await? {
+                let content = translate!(ctx, "oldmaid-no-bios");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+            } else {
+                oldmaid.add_player(&inter.user)?;
+                crate::set_gamble(ctx, inter.user.id).await?;
+                Response::joined(ctx, &inter, oldmaid, bet).await?;
+            }
+        }
+
+        if inter.data.custom_id == format!("{}_start", ctx.id()) {
+            if inter.user.id != ctx.author().id {
+                let content = translate!(ctx, "error-button");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+                continue;
+            }
+
+            if oldmaid.players.len() < 2 {
+                let content = translate!(ctx, "oldmaid-not-enough");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+                continue;
+            }
+
+            inter.defer(ctx).await?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// a player who lets the turn run out loses as if holding the joker
+async fn play(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    oldmaid: &mut Oldmaid,
+    bet: i64,
+) -> Result<(), Error> {
+    while !oldmaid.is_over() {
+        Response::turn(ctx, reply, oldmaid).await?;
+
+        if !turn(ctx, oldmaid).await? {
+            let loser = oldmaid.get_actual().id;
+            settle(ctx, oldmaid, loser, bet).await?;
+
+            let loser = oldmaid.get_actual();
+            Response::end(ctx, reply, oldmaid, loser, bet, true).await?;
+            return Ok(());
+        }
+
+        oldmaid.reset_confirmed_card_index();
+
+        if !oldmaid.is_over() {
+            oldmaid.next_turn();
+        }
+    }
+
+    let loser = oldmaid.get_player_with_oldmaid().id;
+    settle(ctx, oldmaid, loser, bet).await?;
+
+    let loser = oldmaid.get_player(loser);
+    Response::end(ctx, reply, oldmaid, loser, bet, false).await?;
+
+    Ok(())
+}
+
+// the actual player picks a face down card and confirms it, false on timeout
+async fn turn(ctx: Context<'_>, oldmaid: &mut Oldmaid) -> Result<bool, Error> {
+    let started = Instant::now();
+    let turn_time = Duration::from_secs(TURN_SECS);
+
+    while let Some(remaining) = turn_time.checked_sub(started.elapsed()) {
+        let inter = match ComponentInteractionCollector::new(ctx)
+            .timeout(remaining)
+            .await
+        {
+            Some(inter) => inter,
+            None => break,
+        };
+
+        let custom_id = inter.data.custom_id.as_str();
+
+        let action = match custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+            Some(action) => action,
+            None => continue,
+        };
+
+        if !oldmaid
+            .players
+            .iter()
+            .any(|player| player.id == inter.user.id)
+        {
+            continue;
+        }
+
+        if inter.user.id != oldmaid.get_actual().id {
+            CommonRes::isnt_your_turn(ctx, &inter).await?;
+            continue;
+        }
+
+        if action == "draw" {
+            Response::pick(ctx, &inter, oldmaid).await?;
+            continue;
+        }
+
+        if let Some(index) = action.strip_prefix("card_") {
+            if let Ok(index) = index.parse::<usize>() {
+                oldmaid.get_player_mut(inter.user.id).confirmed_card_index = Some(index);
+                Response::picked(ctx, &inter, oldmaid).await?;
+            }
+
+            continue;
+        }
+
+        if action == "confirm" {
+            if let Some(card) = oldmaid.draw_confirmed() {
+                Response::drawn(ctx, &inter, &card).await?;
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+// the stake of the loser pays the bet to every other player, who also get
+// their own stake back
+async fn settle(ctx: Context<'_>, oldmaid: &Oldmaid, loser: UserId, bet: i64) -> Result<(), Error> {
+    let winners = oldmaid
+        .players
+        .iter()
+        .filter(|player| player.id != loser)
+        .map(|player| player.id)
+        .collect::<Vec<UserId>>();
+
+    let shares = winners
+        .iter()
+        .map(|winner| (*winner, stake(oldmaid, bet) + bet))
+        .collect::<Vec<(UserId, i64)>>();
+
+    crate::release_shares(ctx, shares).await?;
+
+    let revenue = crate::points_revenue(bet);
+
+    for winner in winners.iter() {
+        crate::log_versus(ctx, *winner, loser, bet, Game::OldMaid).await?;
+        crate::add_points(ctx, *winner, revenue.winner).await?;
+    }
+
+    let lost = crate::points_revenue(bet * winners.len() as i64);
+    crate::add_points(ctx, loser, lost.loser).await?;
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseMessage,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::{
+    cards::poker::Card,
+    oldmaid::{Oldmaid, Player, MAX_PLAYERS},
+};
+
+pub struct Response;
+
+pub struct ErrorRes;
+
+struct Button;
+
+fn lobby(ctx: Context<'_>, oldmaid: &Oldmaid, bet: i64) -> String {
+    let mut content = translate!(
+        ctx,
+        "oldmaid-lobby",
+        amount: Parser::abbreviate_number(bet),
+        players: oldmaid.players.len(),
+        max: MAX_PLAYERS
+    );
+
+    for player in oldmaid.players.iter() {
+        content.push_str(&format!("\n{}", player.name));
+    }
+
+    content
+}
+
+fn table(ctx: Context<'_>, oldmaid: &Oldmaid) -> String {
+    let mut content = translate!(ctx, "oldmaid-table", pairs: oldmaid.discarded_pairs());
+
+    for player in oldmaid.players.iter() {
+        let line = match player.hand.is_empty() {
+            true => translate!(ctx, "oldmaid-out", user: player.name.as_str()),
+            false => translate!(
+                ctx,
+                "oldmaid-player",
+                user: player.name.as_str(),
+                cards: player.hand.len()
+            ),
+        };
+
+        content.push_str(&format!("\n{}", line));
+    }
+
+    content
+}
+
+fn hand(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|card| card.short_name())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn pick(ctx: Context<'_>, oldmaid: &Oldmaid) -> String {
+    let actual = oldmaid.get_actual();
+
+    format!(
+        "{}\n{}",
+        translate!(ctx, "oldmaid-your-hand", cards: hand(&actual.hand)),
+        translate!(ctx, "oldmaid-pick", user: oldmaid.get_rival().name.as_str())
+    )
+}
+
+impl ErrorRes {
+    pub async fn ephemeral(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Response {
+    pub async fn lobby<'a>(
+        ctx: Context<'a>,
+        oldmaid: &Oldmaid,
+        bet: i64,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(lobby(ctx, oldmaid, bet))
+                    .components(Button::lobby(ctx, false)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn joined(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        oldmaid: &Oldmaid,
+        bet: i64,
+    ) -> Result<(), Error> {
+        let full = oldmaid.players.len() == MAX_PLAYERS;
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(lobby(ctx, oldmaid, bet))
+                        .components(Button::lobby(ctx, full)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn cancelled(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "oldmaid-cancelled");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn turn(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        oldmaid: &Oldmaid,
+    ) -> Result<(), Error> {
+        let turn = translate!(
+            ctx,
+            "oldmaid-turn",
+            user: oldmaid.get_actual().name.as_str(),
+            rival: oldmaid.get_rival().name.as_str()
+        );
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(format!("{}\n\n{}", table(ctx, oldmaid), turn))
+                    .components(Button::draw(ctx)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // the rival's cards are shown face down to the actual player only
+    pub async fn pick(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        oldmaid: &Oldmaid,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(pick(ctx, oldmaid))
+                        .components(Button::cards(ctx, oldmaid))
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn picked(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        oldmaid: &Oldmaid,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(pick(ctx, oldmaid))
+                        .components(Button::cards(ctx, oldmaid)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn drawn(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        card: &Card,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "oldmaid-drawn", card: card.short_name());
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(vec![]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn end(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        oldmaid: &Oldmaid,
+        loser: &Player,
+        bet: i64,
+        timeout: bool,
+    ) -> Result<(), Error> {
+        let rivals = oldmaid.players.len() as i64 - 1;
+        let amount = Parser::num_with_commas(bet * rivals);
+
+        let key = match timeout {
+            true => "oldmaid-timeout",
+            false => "oldmaid-end",
+        };
+
+        let content = translate!(ctx, key, user: loser.name.as_str(), amount: amount);
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(format!("{}\n\n{}", table(ctx, oldmaid), content))
+                    .components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn lobby(ctx: Context<'_>, full: bool) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_join", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "join"))
+                .disabled(full),
+            CreateButton::new(format!("{}_start", ctx.id()))
+                .style(ButtonStyle::Primary)
+                .label(translate!(ctx, "start")),
+        ])]
+    }
+
+    fn draw(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![CreateButton::new(format!(
+            "{}_draw",
+            ctx.id()
+        ))
+        .style(ButtonStyle::Primary)
+        .label(translate!(ctx, "draw-card"))])]
+    }
+
+    // five face down cards per row, the last row holds the confirm button
+    fn cards(ctx: Context<'_>, oldmaid: &Oldmaid) -> Vec<CreateActionRow> {
+        let selected = oldmaid.get_actual().confirmed_card_index;
+
+        let buttons = (0..oldmaid.get_rival().hand.len())
+            .map(|index| {
+                let style = match selected == Some(index) {
+                    true => ButtonStyle::Primary,
+                    false => ButtonStyle::Secondary,
+                };
+
+                CreateButton::new(format!("{}_card_{}", ctx.id(), index))
+                    .style(style)
+                    .label(format!("🂠 {}", index + 1))
+            })
+            .collect::<Vec<CreateButton>>();
+
+        let mut rows = buttons
+            .chunks(5)
+            .map(|chunk| CreateActionRow::Buttons(chunk.to_vec()))
+            .collect::<Vec<CreateActionRow>>();
+
+        rows.push(CreateActionRow::Buttons(vec![CreateButton::new(format!(
+            "{}_confirm",
+            ctx.id()
+        ))
+        .style(ButtonStyle::Success)
+        .label(translate!(ctx, "confirm"))
+        .disabled(selected.is_none())]));
+
+        rows
+    }
+}
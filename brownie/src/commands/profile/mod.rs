@@ -1,6 +1,12 @@
 mod inventory;
 pub use inventory::inventory;
 
+mod quests;
+pub use quests::quests;
+
+mod use_item;
+pub use use_item::use_item;
+
 use super::{translate, Context, Error};
 use crate::{get_member, serenity::User, Parser};
 use inflector::Inflector;
@@ -0,0 +1,66 @@
+use super::{Context, Error};
+use crate::{get_member, translate, Parser};
+use database::quests::{self, Quest, STREAK_BONUS};
+
+const BAR_LENGTH: usize = 10;
+
+fn bar(quest: &Quest) -> String {
+    let filled = (quest.percent().clamp(0, 100) as usize * BAR_LENGTH) / 100;
+    format!("{}{}", "▰".repeat(filled), "▱".repeat(BAR_LENGTH - filled))
+}
+
+// today's three quests, they change at midnight UTC
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn quests(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let member = get_member(ctx, ctx.author().id).await?;
+    let read = member.read().await;
+
+    let quests = read.load_quests(pool).await?;
+    let streak = read.quest_streak(pool).await?;
+
+    if quests.is_empty() {
+        return Err(translate!(ctx, "quests-empty").into());
+    }
+
+    let mut content = translate!(
+        ctx,
+        "quests-header",
+        streak: streak,
+        bonus: streak * STREAK_BONUS
+    );
+
+    for quest in quests.iter() {
+        let status = match quest.completed {
+            true => translate!(ctx, "quests-done"),
+            false => format!(
+                "{} {}/{}",
+                bar(quest),
+                Parser::num_with_commas(quest.progress as i64),
+                Parser::num_with_commas(quest.required as i64)
+            ),
+        };
+
+        let line = translate!(
+            ctx,
+            "quests-line",
+            quest: translate!(ctx, &format!("quest-{}", quest.name), steps: quest.required),
+            status: status,
+            points: Parser::num_with_commas(quests::streak_reward(quest.points as i64, streak)),
+            bios: Parser::num_with_commas(quests::streak_reward(quest.bios as i64, streak))
+        );
+
+        content.push_str(&format!("\n\n{}", line));
+    }
+
+    ctx.reply(content).await?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use super::{Context, Error};
+use crate::{commands::autocomplete::items_auto, get_member, translate, Parser};
+use database::{effects::Effect, error::EffectError, models::ItemType, quests::QuestEvent};
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    rename = "use",
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy"
+)]
+pub async fn use_item(
+    ctx: Context<'_>,
+    #[autocomplete = "items_auto"] item: String,
+) -> Result<(), Error> {
+    // items_auto ends every choice with the id of the item
+    let item_id = match item
+        .split_whitespace()
+        .last()
+        .and_then(|id| id.parse::<i32>().ok())
+    {
+        Some(id) => id,
+        None => return Err(translate!(ctx, "use-not-owned").into()),
+    };
+
+    let member = get_member(ctx, ctx.author().id).await?;
+    let mut write = member.write().await;
+
+    let (name, food) = match write.get_item_by_id(item_id) {
+        Some(item) => (
+            translate!(ctx, &item.info.name),
+            item.info.item_type == ItemType::Food,
+        ),
+        None => return Err(translate!(ctx, "use-not-owned").into()),
+    };
+
+    let effect = match write.use_item(&ctx.data().pool, item_id).await {
+        Ok(effect) => effect,
+        Err(error) => {
+            let content = match error {
+                EffectError::ItemNotFound => translate!(ctx, "use-not-owned"),
+                EffectError::NotUsable => translate!(ctx, "use-not-usable", item: name),
+                _ => translate!(ctx, "unknown-error"),
+            };
+
+            return Err(content.into());
+        }
+    };
+
+    let content = match effect {
+        Effect::Buff { buff, value, secs } => translate!(
+            ctx,
+            "use-buff",
+            item: name,
+            buff: translate!(ctx, &format!("buff-{:?}", buff)),
+            percent: value,
+            time: Parser::format_seconds(secs as u64)
+        ),
+        Effect::Points(points) => translate!(
+            ctx,
+            "use-points",
+            item: name,
+            points: Parser::num_with_commas(points as i64)
+        ),
+    };
+
+    ctx.reply(content).await?;
+
+    if food {
+        crate::progress_quests(ctx, &mut write, QuestEvent::Eat).await?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,22 @@
-use chrono::{Datelike, Weekday};
-use database::structs::Item;
-
-use crate::{get_member, translate, Context, Error, Parser};
+use chrono::Datelike;
+use database::{
+    daily::{self, Daily, DailyReward, STREAK_SAVER},
+    models::Buff,
+};
+use poise::{serenity_prelude::CreateEmbed, CreateReply};
+
+use super::autocomplete::all_items_auto;
+use crate::{get_member, get_system, translate, Context, Error, PageField, Parser};
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
 
 #[poise::command(
     prefix_command,
@@ -14,104 +29,224 @@ pub async fn daily(ctx: Context<'_>) -> Result<(), Error> {
     let mut member = member.write().await;
     let data = ctx.data();
 
-    if member.state.can_claim_daily {
-        let weekday_reward = weekday_reward(ctx).await;
-        let mut rewards = Vec::new();
-
-        let bios_amount = Parser::num_with_commas(weekday_reward.bios);
-        let bios = translate!(ctx, "daily-bios", amount: bios_amount);
-        rewards.push(bios);
-        member
-            .increase_bios(&data.pool, weekday_reward.bios)
-            .await?;
-
-        let points_amount = Parser::num_with_commas(weekday_reward.points as i64);
-        let points = translate!(ctx, "daily-points", amount: points_amount);
-        rewards.push(points);
-        member
-            .increase_points(&data.pool, weekday_reward.points)
-            .await?;
-
-        if let Some(item) = weekday_reward.item {
-            rewards.push(item.name);
-            member
-                .add_item(&data.pool, item.model_item, item.amount)
-                .await?;
-        }
-
-        // registra que el usuario ya reclamó este día
-        member.log_claim_daily(&data.pool).await?;
-
-        let content = translate!(ctx, "daily-claimed");
-        ctx.reply(format!("{}\n{}", content, rewards.join(", ")))
-            .await?;
+    if !member.state.can_claim_daily {
+        let content = translate!(ctx, "daily-already-claimed");
+        ctx.reply(content).await?;
         return Ok(());
     }
 
-    let content = translate!(ctx, "daily-already-claimed");
-    ctx.reply(content).await?;
+    let saved = member.save_streak(&data.pool).await?;
+
+    // the claim of today counts for the streak
+    let streak = member.daily_streak(&data.pool).await? + 1;
+    let milestones = Daily::milestones(&data.pool).await?;
+    let percent = daily::streak_percent(&milestones, streak);
+
+    let weekday = chrono::Utc::now().weekday().num_days_from_monday() as i32;
+    let reward = Daily::get(&data.pool, weekday).await?;
+
+    let (mut bios, points) = match &reward {
+        Some(reward) => reward.scaled(percent),
+        None => (0, 0),
+    };
+
+    let mut rewards = Vec::new();
+
+    if percent > 0 {
+        rewards.push(translate!(ctx, "daily-streak-bonus", percent: percent));
+    }
+
+    if let Some(percent) = member.consume_buff(&data.pool, Buff::DailyBonus).await? {
+        bios += bios * percent as i64 / 100;
+        rewards.push(translate!(ctx, "daily-bonus", percent: percent));
+    }
+
+    let bios_amount = Parser::num_with_commas(bios);
+    rewards.push(translate!(ctx, "daily-bios", amount: bios_amount));
+    member.increase_bios(&data.pool, bios).await?;
+
+    let points_amount = Parser::num_with_commas(points as i64);
+    rewards.push(translate!(ctx, "daily-points", amount: points_amount));
+    member.increase_points(&data.pool, points).await?;
+
+    if let Some(DailyReward {
+        item: Some(item),
+        amount,
+        ..
+    }) = reward
+    {
+        let item = {
+            let system = get_system(ctx).await;
+            let lock = system.lock().await;
+            lock.get_item_by_id(&data.pool, item).await?
+        };
+
+        rewards.push(format!("{} {}", amount, translate!(ctx, &item.name)));
+        member.add_item(&data.pool, item, amount).await?;
+    }
+
+    // registra que el usuario ya reclamó este día
+    member.log_claim_daily(&data.pool).await?;
+
+    let next = match daily::next_milestone(&milestones, streak) {
+        Some(milestone) => translate!(
+            ctx,
+            "daily-next-milestone",
+            days: milestone.streak - streak,
+            percent: milestone.percent
+        ),
+        None => translate!(ctx, "daily-max-milestone"),
+    };
+
+    let mut streak_info = translate!(ctx, "daily-streak-days", days: streak);
+
+    if saved {
+        streak_info = format!(
+            "{}\n{}",
+            streak_info,
+            translate!(ctx, "daily-streak-saved", item: translate!(ctx, STREAK_SAVER))
+        );
+    }
+
+    let embed = CreateEmbed::default()
+        .title(translate!(ctx, "daily-claimed"))
+        .description(rewards.join("\n"))
+        .field(translate!(ctx, "daily-streak"), streak_info, true)
+        .field(translate!(ctx, "daily-milestone"), next, true);
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
     Ok(())
 }
 
-pub struct WeekdayItem {
-    pub id: i32,
-    pub name: String,
-    pub amount: i32,
-    pub model_item: Item,
+#[poise::command(
+    prefix_command,
+    slash_command,
+    owners_only,
+    category = "admin",
+    subcommand_required,
+    subcommands("view", "set", "milestone")
+)]
+pub async fn rewards(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
 }
 
-impl WeekdayItem {
-    pub async fn new(ctx: Context<'_>, id: i32, amount: i32) -> Self {
-        let data = ctx.data();
-        let system = data.system.get(&()).await.unwrap();
-        let lock = system.lock().await;
-
-        let item = lock.get_item_by_id(&data.pool, id).await.unwrap();
-        let name = translate!(ctx, &item.name);
-
-        Self {
-            id,
-            name,
-            amount,
-            model_item: item,
-        }
+// the reward of every weekday and the streak milestones
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn view(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let mut pages = Daily::all(pool)
+        .await?
+        .iter()
+        .map(|reward| {
+            let mut description = translate!(
+                ctx,
+                "rewards-day",
+                bios: Parser::num_with_commas(reward.bios),
+                points: Parser::num_with_commas(reward.points as i64)
+            );
+
+            if let Some(name) = &reward.item_name {
+                description.push_str(&format!(", {} {}", reward.amount, translate!(ctx, name)));
+            }
+
+            PageField {
+                title: translate!(ctx, WEEKDAYS[reward.weekday as usize]),
+                description,
+            }
+        })
+        .collect::<Vec<PageField>>();
+
+    let milestones = Daily::milestones(pool)
+        .await?
+        .iter()
+        .map(|milestone| {
+            translate!(
+                ctx,
+                "rewards-milestone-line",
+                days: milestone.streak,
+                percent: milestone.percent
+            )
+        })
+        .collect::<Vec<String>>();
+
+    if !milestones.is_empty() {
+        pages.push(PageField {
+            title: translate!(ctx, "daily-milestone"),
+            description: milestones.join("\n"),
+        });
     }
-}
 
-pub struct Daily {
-    pub bios: i64,
-    pub points: i32,
-    pub item: Option<WeekdayItem>,
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
 }
 
-impl Daily {
-    pub fn new(bios: i64, points: i32, item: Option<WeekdayItem>) -> Self {
-        Self { bios, points, item }
-    }
+// replaces the reward of a weekday, 1 is monday
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn set(
+    ctx: Context<'_>,
+    #[min = 1]
+    #[max = 7]
+    day: i32,
+    #[min = 0] bios: i64,
+    #[min = 0] points: i32,
+    #[autocomplete = "all_items_auto"] item: Option<String>,
+    #[min = 1] amount: Option<i32>,
+) -> Result<(), Error> {
+    // all_items_auto ends every choice with the id of the item
+    let item = match item {
+        Some(item) => match item
+            .split_whitespace()
+            .last()
+            .and_then(|id| id.parse::<i32>().ok())
+        {
+            Some(id) => Some(id),
+            None => return Err(translate!(ctx, "rewards-unknown-item").into()),
+        },
+        None => None,
+    };
+
+    let weekday = day - 1;
+    Daily::set(
+        &ctx.data().pool,
+        weekday,
+        bios,
+        points,
+        item,
+        amount.unwrap_or(1),
+    )
+    .await?;
+
+    let content = translate!(
+        ctx,
+        "rewards-updated",
+        day: translate!(ctx, WEEKDAYS[weekday as usize])
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
 }
 
-pub async fn weekday_reward(ctx: Context<'_>) -> Daily {
-    let weekday = chrono::Utc::now().weekday();
-
-    match weekday {
-        Weekday::Mon => {
-            // coffee
-            let item = WeekdayItem::new(ctx, 8, 1).await;
-            Daily::new(4500, 2500, Some(item))
-        }
-        Weekday::Tue => {
-            // kariume
-            let item = WeekdayItem::new(ctx, 4, 1).await;
-            Daily::new(2500, 1500, Some(item))
-        }
-        Weekday::Wed => Daily::new(2500, 1500, None),
-        Weekday::Thu => Daily::new(2500, 1500, None),
-        Weekday::Fri => {
-            // life insurance
-            let item = WeekdayItem::new(ctx, 9, 1).await;
-            Daily::new(4500, 1500, Some(item))
-        }
-        Weekday::Sat => Daily::new(2500, 2000, None),
-        Weekday::Sun => Daily::new(2500, 2000, None),
-    }
+// percent zero removes the milestone
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn milestone(
+    ctx: Context<'_>,
+    #[min = 1] streak: i32,
+    #[min = 0]
+    #[max = 500]
+    percent: i32,
+) -> Result<(), Error> {
+    Daily::set_milestone(&ctx.data().pool, streak, percent).await?;
+
+    let content = translate!(
+        ctx,
+        "rewards-milestone-line",
+        days: streak,
+        percent: percent
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
 }
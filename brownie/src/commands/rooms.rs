@@ -0,0 +1,106 @@
+use crate::{serenity::GuildChannel, translate, Context, Error};
+use poise::serenity_prelude::Mentionable;
+use types::RoomKind;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "admin",
+    subcommand_required,
+    subcommands("list", "abort")
+)]
+pub async fn rooms(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+// running games of this guild, oldest first
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "admin"
+)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    let guild = ctx.guild_id().map(|id| id.get());
+
+    let rooms = ctx
+        .data()
+        .rooms
+        .list()
+        .await
+        .into_iter()
+        .filter(|room| room.guild == guild)
+        .collect::<Vec<_>>();
+
+    if rooms.is_empty() {
+        let content = translate!(ctx, "rooms-empty");
+        ctx.reply(content).await?;
+        return Ok(());
+    }
+
+    let mut content = translate!(ctx, "rooms-title", rooms: rooms.len());
+
+    for room in rooms.iter() {
+        let line = translate!(
+            ctx,
+            "rooms-entry",
+            kind: room.kind.to_string(),
+            channel: format!("<#{}>", room.channel),
+            host: room.host.mention().to_string(),
+            players: room.players.len(),
+            since: format!("<t:{}:R>", room.opened)
+        );
+
+        content.push_str(&format!("\n{}", line));
+    }
+
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// the game stops without charging anyone and its players can gamble again
+#[poise::command(
+    prefix_command,
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    category = "admin"
+)]
+pub async fn abort(
+    ctx: Context<'_>,
+    kind: RoomKind,
+    channel: Option<GuildChannel>,
+) -> Result<(), Error> {
+    let channel = match channel {
+        Some(channel) if Some(channel.guild_id) != ctx.guild_id() => {
+            let content = translate!(ctx, "rooms-not-found");
+            return Err(content.into());
+        }
+        Some(channel) => channel.id,
+        None => ctx.channel_id(),
+    };
+
+    let room = match ctx.data().rooms.abort(kind, channel.get()).await {
+        Some(room) => room,
+        None => {
+            let content = translate!(ctx, "rooms-not-found");
+            return Err(content.into());
+        }
+    };
+
+    crate::free_gamble(ctx, room.players.clone()).await?;
+
+    let content = translate!(
+        ctx,
+        "rooms-aborted",
+        kind: room.kind.to_string(),
+        channel: channel.mention().to_string()
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
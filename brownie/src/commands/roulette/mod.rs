@@ -0,0 +1,191 @@
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
+use crate::{
+    serenity::{
+        ComponentInteraction, ComponentInteractionCollector, ModalInteraction,
+        ModalInteractionCollector, UserId,
+    },
+    translate, Context, Duration, Error, Parser,
+};
+use poise::Modal;
+use response::{ErrorRes, ModalRes, Response};
+use std::time::Instant;
+use types::roulette::Roulette;
+
+mod response;
+
+const BETTING_SECS: u64 = 60;
+const MIN_CHIP: i64 = 100;
+
+enum Event {
+    Inter(ComponentInteraction),
+    ModalInter(ModalInteraction),
+}
+
+#[derive(poise::Modal)]
+struct ChipModal {
+    bet: String,
+    bios: Option<String>,
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn roulette(ctx: Context<'_>) -> Result<(), Error> {
+    let mut roulette = Roulette::new();
+
+    let reply = Response::table(ctx, &roulette).await?;
+
+    betting(ctx, &mut roulette).await?;
+
+    if roulette.players.is_empty() {
+        Response::no_bets(ctx, &reply).await?;
+        return Ok(());
+    }
+
+    roulette.spin();
+
+    for ((id, net), player) in roulette.payouts().into_iter().zip(roulette.players.iter()) {
+        if net != 0 {
+            crate::charge_single_bet(ctx, id, net.abs(), net > 0).await?;
+        }
+
+        crate::log_solo(ctx, id, net, player.total_bet(), Game::Roulette).await?;
+    }
+
+    crate::free_gamble(
+        ctx,
+        roulette
+            .players
+            .iter()
+            .map(|player| player.id)
+            .collect::<Vec<UserId>>(),
+    )
+    .await?;
+
+    Response::result(ctx, &reply, &roulette).await?;
+
+    Ok(())
+}
+
+// anyone can place chips until the host spins or time runs out
+async fn betting(ctx: Context<'_>, roulette: &mut Roulette) -> Result<(), Error> {
+    let started = Instant::now();
+    let betting_time = Duration::from_secs(BETTING_SECS);
+
+    while let Some(remaining) = betting_time.checked_sub(started.elapsed()) {
+        let collector = ComponentInteractionCollector::new(ctx).timeout(remaining);
+        let modal_collector = ModalInteractionCollector::new(ctx).timeout(remaining);
+
+        let event = tokio::select! {
+            inter = collector.next() => match inter {
+                Some(inter) => Event::Inter(inter),
+                None => break,
+            },
+            modal_inter = modal_collector.next() => match modal_inter {
+                Some(modal_inter) => Event::ModalInter(modal_inter),
+                None => break,
+            },
+        };
+
+        match event {
+            Event::Inter(inter) => {
+                if inter.data.custom_id == format!("{}_bet", ctx.id()) {
+                    // players already at the table are marked as gambling
+                    if roulette.get_player(inter.user.id).is_none() {
+                        if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                            ErrorRes::ephemeral(ctx, &inter, why.to_string()).await?;
+                            continue;
+                        }
+                    }
+
+                    ModalRes::chip(ctx, &inter).await?;
+                }
+
+                if inter.data.custom_id == format!("{}_spin", ctx.id()) {
+                    if inter.user.id != ctx.author().id {
+                        let content = translate!(ctx, "error-button");
+                        ErrorRes::ephemeral(ctx, &inter, content).await?;
+                        continue;
+                    }
+
+                    if roulette.players.is_empty() {
+                        let content = translate!(ctx, "roulette-no-bets");
+                        ErrorRes::ephemeral(ctx, &inter, content).await?;
+                        continue;
+                    }
+
+                    inter.defer(ctx).await?;
+                    break;
+                }
+            }
+            Event::ModalInter(inter) => {
+                if inter.data.custom_id != format!("{}_chip", ctx.id()) {
+                    continue;
+                }
+
+                let modal = ChipModal::parse(inter.data.clone())?;
+
+                let bet = match Parser::roulette_bet(modal.bet) {
+                    Ok(bet) => bet,
+                    Err(why) => {
+                        ErrorRes::invalid_chip(ctx, &inter, why.to_string()).await?;
+                        continue;
+                    }
+                };
+
+                let bios = modal.bios.unwrap_or(MIN_CHIP.to_string());
+
+                let amount = match Parser::abbreviation_to_number(&bios) {
+                    Ok(amount) if amount >= MIN_CHIP => amount,
+                    _ => {
+                        CommonRes::incorrect_bet(ctx, &inter).await?;
+                        continue;
+                    }
+                };
+
+                let user_id = inter.user.id;
+
+                let placed = roulette
+                    .get_player(user_id)
+                    .map(|player| player.total_bet())
+                    .unwrap_or_default();
+
+                if !crate::can_partial_bet(ctx, user_id, placed + amount).await? {
+                    CommonRes::incorrect_bet(ctx, &inter).await?;
+                    continue;
+                }
+
+                let first = roulette.get_player(user_id).is_none();
+
+                if first {
+                    if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                        ErrorRes::invalid_chip(ctx, &inter, why.to_string()).await?;
+                        continue;
+                    }
+                }
+
+                if let Err(why) = roulette.place(&inter.user, bet, amount) {
+                    ErrorRes::invalid_chip(ctx, &inter, why.to_string()).await?;
+                    continue;
+                }
+
+                if first {
+                    crate::set_gamble(ctx, user_id).await?;
+                }
+
+                Response::placed(ctx, &inter, roulette).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
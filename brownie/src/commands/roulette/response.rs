@@ -0,0 +1,210 @@
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInputText,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle,
+        ModalInteraction,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::roulette::{Color, Roulette};
+
+pub struct Response;
+
+pub struct ErrorRes;
+
+pub struct ModalRes;
+
+struct Button;
+
+fn table(ctx: Context<'_>, roulette: &Roulette) -> String {
+    let mut content = translate!(ctx, "roulette-table");
+
+    for player in roulette.players.iter() {
+        let chips = player
+            .chips
+            .iter()
+            .map(|chip| {
+                format!(
+                    "{} ({})",
+                    chip.bet.name(),
+                    Parser::abbreviate_number(chip.amount)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        content.push_str(&format!("\n{}: {}", player.name, chips));
+    }
+
+    content
+}
+
+impl ErrorRes {
+    pub async fn ephemeral(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn invalid_chip(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ModalRes {
+    pub async fn chip(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        let modal = CreateModal::new(
+            format!("{}_chip", ctx.id()),
+            translate!(ctx, "bet-modal-title"),
+        )
+        .components(vec![
+            CreateActionRow::InputText(
+                CreateInputText::new(
+                    InputTextStyle::Short,
+                    translate!(ctx, "roulette-bet-field"),
+                    "bet",
+                )
+                .placeholder("red, 17, split 17 18, dozen 2"),
+            ),
+            CreateActionRow::InputText(
+                CreateInputText::new(
+                    InputTextStyle::Short,
+                    translate!(ctx, "how-many-bios"),
+                    "bios",
+                )
+                .required(false)
+                .placeholder("100"),
+            ),
+        ]);
+
+        inter
+            .create_response(ctx, CreateInteractionResponse::Modal(modal))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Response {
+    pub async fn table<'a>(
+        ctx: Context<'a>,
+        roulette: &Roulette,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(table(ctx, roulette))
+                    .components(Button::table(ctx)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn placed(
+        ctx: Context<'_>,
+        inter: &ModalInteraction,
+        roulette: &Roulette,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(table(ctx, roulette))
+                        .components(Button::table(ctx)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn no_bets(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "roulette-no-bets");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn result(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        roulette: &Roulette,
+    ) -> Result<(), Error> {
+        let number = roulette.number.unwrap_or_default();
+        let color = translate!(ctx, &Color::of(number).name());
+
+        let mut content = translate!(ctx, "roulette-result", number: number, color: color);
+
+        for (id, net) in roulette.payouts() {
+            let name = roulette.get_player(id).unwrap().name.as_str();
+            let amount = Parser::num_with_commas(net.abs());
+
+            let line = match net {
+                0 => translate!(ctx, "roulette-even", user: name),
+                n if n > 0 => translate!(ctx, "roulette-won", user: name, amount: amount),
+                _ => translate!(ctx, "roulette-lost", user: name, amount: amount),
+            };
+
+            content.push_str(&format!("\n{}", line));
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn table(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_bet", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "bet")),
+            CreateButton::new(format!("{}_spin", ctx.id()))
+                .style(ButtonStyle::Primary)
+                .label(translate!(ctx, "spin")),
+        ])]
+    }
+}
@@ -0,0 +1,119 @@
+use super::season_error;
+use crate::{commands::autocomplete::craftable_auto, translate, Context, Error, PageField};
+use database::{
+    error::CraftError,
+    models::Quality,
+    player::{Player, Recipe},
+};
+
+fn quality_key(quality: &Quality) -> &'static str {
+    match quality {
+        Quality::Common => "quality-common",
+        Quality::Normal => "quality-normal",
+        Quality::Epic => "quality-epic",
+        Quality::Masterpiece => "quality-masterpiece",
+    }
+}
+
+fn components(ctx: Context<'_>, recipe: &Recipe) -> String {
+    recipe
+        .components
+        .iter()
+        .map(|component| {
+            translate!(
+                ctx,
+                "craft-component",
+                item: translate!(ctx, &component.name),
+                owned: component.owned,
+                amount: component.amount
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// without an item shows the recipes, the ones that can be made first
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn craft(
+    ctx: Context<'_>,
+    #[autocomplete = "craftable_auto"] item: Option<String>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (season, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let item = match item {
+        Some(item) => item,
+        None => {
+            let mut recipes = player.recipes(pool).await?;
+            recipes.sort_by_key(|recipe| !recipe.can_craft());
+
+            let pages = recipes
+                .iter()
+                .map(|recipe| {
+                    let key = match recipe.can_craft() {
+                        true => "craft-ready",
+                        false => "craft-missing",
+                    };
+
+                    PageField {
+                        title: translate!(ctx, key, item: translate!(ctx, &recipe.name)),
+                        description: components(ctx, recipe),
+                    }
+                })
+                .collect::<Vec<PageField>>();
+
+            crate::paginate(ctx, pages).await?;
+            return Ok(());
+        }
+    };
+
+    let item = match item
+        .split_whitespace()
+        .last()
+        .and_then(|id| id.parse::<i32>().ok())
+    {
+        Some(item) => item,
+        None => return Err(translate!(ctx, "craft-unknown").into()),
+    };
+
+    let crafted = match player.craft(pool, &season.rules(), item).await {
+        Ok(crafted) => crafted,
+        Err(error) => {
+            let content = match error {
+                CraftError::UnknownRecipe => translate!(ctx, "craft-unknown"),
+                CraftError::MissingComponents => translate!(ctx, "craft-not-enough"),
+                _ => translate!(ctx, "unknown-error"),
+            };
+
+            return Err(content.into());
+        }
+    };
+
+    let mut content = translate!(
+        ctx,
+        "craft-done",
+        item: translate!(ctx, &crafted.name),
+        quality: translate!(ctx, quality_key(&crafted.quality))
+    );
+
+    if crafted.levels > 0 {
+        content.push_str(&format!(
+            "\n{}",
+            translate!(ctx, "craft-level-up", levels: crafted.levels)
+        ));
+    }
+
+    ctx.reply(content).await?;
+
+    Ok(())
+}
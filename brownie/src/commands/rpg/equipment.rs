@@ -0,0 +1,170 @@
+use super::season_error;
+use crate::{
+    commands::autocomplete::{rpg_items_auto, slot_auto},
+    serenity::User,
+    translate, Context, Error,
+};
+use database::{
+    error::EquipError,
+    player::{Player, Slot},
+};
+use poise::{serenity_prelude::CreateEmbed, CreateReply};
+
+fn equip_error(ctx: Context<'_>, error: EquipError) -> String {
+    match error {
+        EquipError::NotOwned => translate!(ctx, "equip-not-owned"),
+        EquipError::NotEquippable => translate!(ctx, "equip-not-equippable"),
+        EquipError::WrongSlot => translate!(ctx, "equip-wrong-slot"),
+        EquipError::EmptySlot => translate!(ctx, "equip-empty-slot"),
+        _ => translate!(ctx, "unknown-error"),
+    }
+}
+
+// autocomplete choices end with the id of the item or the number of the slot
+fn last_number(choice: &str) -> Option<i32> {
+    choice
+        .split_whitespace()
+        .last()
+        .and_then(|number| number.parse::<i32>().ok())
+}
+
+fn parse_slot(choice: &str) -> Option<Slot> {
+    last_number(choice)
+        .filter(|number| *number > 0)
+        .and_then(|number| Slot::ALL.get(number as usize - 1).copied())
+}
+
+// rings and one-handed items can go in either slot, the first free one is used
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn equip(
+    ctx: Context<'_>,
+    #[autocomplete = "rpg_items_auto"] item: String,
+    #[autocomplete = "slot_auto"] slot: Option<String>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (_, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let item = match last_number(&item) {
+        Some(item) => item,
+        None => return Err(translate!(ctx, "equip-not-owned").into()),
+    };
+
+    let slot = match slot {
+        Some(slot) => match parse_slot(&slot) {
+            Some(slot) => Some(slot),
+            None => return Err(translate!(ctx, "equip-wrong-slot").into()),
+        },
+        None => None,
+    };
+
+    let equipment = match player.equip(pool, item, slot).await {
+        Ok(equipment) => equipment,
+        Err(error) => return Err(equip_error(ctx, error).into()),
+    };
+
+    let name = Slot::ALL
+        .iter()
+        .find_map(|slot| equipment.get(*slot).filter(|gear| gear.id == item))
+        .map(|gear| translate!(ctx, &gear.name))
+        .unwrap_or_default();
+
+    let content = translate!(ctx, "equip-done", item: name);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn unequip(
+    ctx: Context<'_>,
+    #[autocomplete = "slot_auto"] slot: String,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (_, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let slot = match parse_slot(&slot) {
+        Some(slot) => slot,
+        None => return Err(translate!(ctx, "equip-wrong-slot").into()),
+    };
+
+    let gear = match player.unequip(pool, slot).await {
+        Ok(gear) => gear,
+        Err(error) => return Err(equip_error(ctx, error).into()),
+    };
+
+    let content = translate!(ctx, "unequip-done", item: translate!(ctx, &gear.name));
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// what someone wears and the stats it gives
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn equipment(ctx: Context<'_>, user: Option<User>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+    let user = user.unwrap_or(ctx.author().clone());
+
+    let (_, player) = match Player::current(pool, user.id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let equipment = player.equipment(pool).await?;
+    let stats = player.stats(pool).await?;
+
+    let slots = Slot::ALL
+        .iter()
+        .map(|slot| {
+            let worn = match equipment.get(*slot) {
+                Some(gear) => translate!(ctx, &gear.name),
+                None => String::from("-"),
+            };
+
+            format!("**{}**: {}", translate!(ctx, slot.key()), worn)
+        })
+        .collect::<Vec<String>>();
+
+    let stats = translate!(
+        ctx,
+        "equipment-stats",
+        damage: stats.damage,
+        defense: stats.defense,
+        accuracy: stats.accuracy,
+        life: stats.max_life
+    );
+
+    let embed = CreateEmbed::default()
+        .title(translate!(ctx, "equipment-title", user: user.display_name()))
+        .description(slots.join("\n"))
+        .field(translate!(ctx, "equipment-stats-title"), stats, false);
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
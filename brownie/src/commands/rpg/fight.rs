@@ -0,0 +1,374 @@
+use super::season_error;
+use crate::{
+    commands::{autocomplete::monster_auto, check::self_can_gamble, CommonRes},
+    serenity::{ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateButton},
+    translate, Context, Duration, Error,
+};
+use database::{
+    error::FightError,
+    player::{FightReward, Monster, Player},
+};
+use poise::{
+    serenity_prelude::{CreateEmbed, CreateEmbedFooter},
+    CreateReply, ReplyHandle,
+};
+use rand::seq::SliceRandom;
+use types::combat::{Action, Combat, Event, Fighter, Side, State, StatusKind};
+
+// the fight is fled when the author doesn't pick an action in time
+const TURN_SECS: u64 = 60;
+const BAR_LENGTH: usize = 10;
+// only the last lines of the log are shown
+const LOG_LINES: usize = 6;
+
+fn life_bar(fighter: &Fighter) -> String {
+    let max = fighter.max_life.max(1) as usize;
+    let filled = (fighter.life.max(0) as usize * BAR_LENGTH)
+        .div_ceil(max)
+        .min(BAR_LENGTH);
+
+    format!(
+        "{}{} {}/{}",
+        "▰".repeat(filled),
+        "▱".repeat(BAR_LENGTH - filled),
+        fighter.life,
+        fighter.max_life
+    )
+}
+
+fn status_key(kind: StatusKind) -> &'static str {
+    match kind {
+        StatusKind::Poison => "fight-status-poison",
+        StatusKind::Stun => "fight-status-stun",
+        StatusKind::Weaken => "fight-status-weaken",
+    }
+}
+
+fn fighter_field(ctx: Context<'_>, fighter: &Fighter) -> String {
+    let statuses = fighter
+        .statuses
+        .iter()
+        .map(|status| {
+            translate!(
+                ctx,
+                "fight-status-turns",
+                status: translate!(ctx, status_key(status.kind)),
+                turns: status.turns
+            )
+        })
+        .collect::<Vec<String>>();
+
+    match statuses.is_empty() {
+        true => life_bar(fighter),
+        false => format!("{}\n{}", life_bar(fighter), statuses.join(", ")),
+    }
+}
+
+fn event_line(ctx: Context<'_>, combat: &Combat, event: &Event) -> String {
+    let name = |side: Side| combat.fighter(side).name.clone();
+
+    match *event {
+        Event::Hit {
+            by,
+            damage,
+            critical,
+        } => {
+            let key = match critical {
+                true => "fight-critical",
+                false => "fight-hit",
+            };
+
+            translate!(
+                ctx,
+                key,
+                attacker: name(by),
+                target: name(by.rival()),
+                damage: damage
+            )
+        }
+        Event::Miss { by } => translate!(ctx, "fight-miss", attacker: name(by)),
+        Event::Guard { by } => translate!(ctx, "fight-guard", fighter: name(by)),
+        Event::Afflicted { target, kind } => translate!(
+            ctx,
+            "fight-afflicted",
+            target: name(target),
+            status: translate!(ctx, status_key(kind))
+        ),
+        Event::Tick {
+            target,
+            kind,
+            damage,
+        } => translate!(
+            ctx,
+            "fight-tick",
+            target: name(target),
+            status: translate!(ctx, status_key(kind)),
+            damage: damage
+        ),
+        Event::Stunned { target } => translate!(ctx, "fight-stunned", target: name(target)),
+        Event::Fled => translate!(ctx, "fight-fled"),
+        Event::FleeFailed => translate!(ctx, "fight-flee-failed"),
+    }
+}
+
+fn embed(ctx: Context<'_>, monster: &Monster, combat: &Combat, log: &[String]) -> CreateEmbed {
+    let start = log.len().saturating_sub(LOG_LINES);
+
+    CreateEmbed::default()
+        .title(translate!(
+            ctx,
+            "fight-title",
+            monster: combat.monster.name.clone(),
+            level: monster.level
+        ))
+        .description(log[start..].join("\n"))
+        .field(
+            combat.player.name.clone(),
+            fighter_field(ctx, &combat.player),
+            true,
+        )
+        .field(
+            combat.monster.name.clone(),
+            fighter_field(ctx, &combat.monster),
+            true,
+        )
+}
+
+fn buttons(ctx: Context<'_>) -> Vec<CreateActionRow> {
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("{}_attack", ctx.id()))
+            .style(ButtonStyle::Primary)
+            .label(translate!(ctx, "fight-attack")),
+        CreateButton::new(format!("{}_heavy", ctx.id()))
+            .style(ButtonStyle::Danger)
+            .label(translate!(ctx, "fight-heavy")),
+        CreateButton::new(format!("{}_defend", ctx.id()))
+            .style(ButtonStyle::Secondary)
+            .label(translate!(ctx, "fight-defend")),
+        CreateButton::new(format!("{}_flee", ctx.id()))
+            .style(ButtonStyle::Secondary)
+            .label(translate!(ctx, "fight-flee")),
+    ])]
+}
+
+fn reward_lines(ctx: Context<'_>, reward: &FightReward) -> Vec<String> {
+    let mut lines = vec![translate!(
+        ctx,
+        "fight-experience",
+        experience: reward.experience
+    )];
+
+    if reward.levels > 0 {
+        lines.push(translate!(ctx, "fight-level-up", levels: reward.levels));
+    }
+
+    if reward.combat_levels > 0 {
+        lines.push(translate!(
+            ctx,
+            "fight-skill-up",
+            skill: translate!(ctx, "skill-combat"),
+            levels: reward.combat_levels
+        ));
+    }
+
+    if reward.looting_levels > 0 {
+        lines.push(translate!(
+            ctx,
+            "fight-skill-up",
+            skill: translate!(ctx, "skill-looting"),
+            levels: reward.looting_levels
+        ));
+    }
+
+    for (item, amount) in &reward.loot {
+        lines.push(translate!(
+            ctx,
+            "fight-loot",
+            item: translate!(ctx, item),
+            amount: *amount
+        ));
+    }
+
+    lines
+}
+
+// a monster of the running season, a random one the player can face without one
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg",
+    check = "self_can_gamble"
+)]
+pub async fn fight(
+    ctx: Context<'_>,
+    #[autocomplete = "monster_auto"] monster: Option<String>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (season, mut player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let monster = match monster {
+        Some(monster) => {
+            let id = match monster
+                .split_whitespace()
+                .last()
+                .and_then(|id| id.parse::<i32>().ok())
+            {
+                Some(id) => id,
+                None => return Err(translate!(ctx, "fight-unknown").into()),
+            };
+
+            match player.monster(pool, id).await {
+                Ok(monster) => monster,
+                Err(error) => {
+                    let content = match error {
+                        FightError::UnknownMonster => translate!(ctx, "fight-unknown"),
+                        FightError::TooStrong => translate!(ctx, "fight-too-strong"),
+                        _ => translate!(ctx, "unknown-error"),
+                    };
+
+                    return Err(content.into());
+                }
+            }
+        }
+        None => match player.monsters(pool).await?.choose(&mut rand::thread_rng()) {
+            Some(monster) => monster.clone(),
+            None => return Err(translate!(ctx, "fight-no-monsters").into()),
+        },
+    };
+
+    let rules = season.rules();
+
+    let mut enemy = monster.fighter(rules.enemy_power);
+    enemy.name = translate!(ctx, &format!("monster-{}", monster.name));
+
+    // the id of the invocation is the seed, so every fight can be replayed
+    let fighter = player.fighter(pool, &ctx.author().name).await?;
+    let mut combat = Combat::new(fighter, enemy, ctx.id());
+    let mut log = vec![translate!(
+        ctx,
+        "fight-start",
+        monster: combat.monster.name.clone()
+    )];
+
+    let reply = ctx
+        .send(
+            CreateReply::default()
+                .embed(embed(ctx, &monster, &combat, &log))
+                .components(buttons(ctx)),
+        )
+        .await?;
+
+    crate::set_gamble(ctx, ctx.author().id).await?;
+
+    let result = run(ctx, &reply, &monster, &mut combat, &mut log).await;
+    crate::free_gamble(ctx, vec![ctx.author().id]).await?;
+    result?;
+
+    let won = match combat.state {
+        State::Won => true,
+        State::Lost => false,
+        _ => {
+            reply
+                .edit(
+                    ctx,
+                    CreateReply::default()
+                        .embed(embed(ctx, &monster, &combat, &log))
+                        .components(vec![]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let reward = player
+        .finish_fight(pool, &rules, &monster, won, combat.rng())
+        .await?;
+
+    log.push(match won {
+        true => translate!(ctx, "fight-won", monster: combat.monster.name.clone()),
+        false => translate!(ctx, "fight-lost", monster: combat.monster.name.clone()),
+    });
+
+    reply
+        .edit(
+            ctx,
+            CreateReply::default()
+                .embed(
+                    embed(ctx, &monster, &combat, &log)
+                        .field(
+                            translate!(ctx, "fight-rewards"),
+                            reward_lines(ctx, &reward).join("\n"),
+                            false,
+                        )
+                        .footer(CreateEmbedFooter::new(
+                            translate!(ctx, "fight-seed", seed: ctx.id().to_string()),
+                        )),
+                )
+                .components(vec![]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+// rounds go on until someone falls or the author flees
+async fn run(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    monster: &Monster,
+    combat: &mut Combat,
+    log: &mut Vec<String>,
+) -> Result<(), Error> {
+    while combat.state == State::Fighting {
+        let inter = match ComponentInteractionCollector::new(ctx)
+            .timeout(Duration::from_secs(TURN_SECS))
+            .await
+        {
+            Some(inter) => inter,
+            None => {
+                combat.state = State::Fled;
+                log.push(translate!(ctx, "fight-timeout"));
+                return Ok(());
+            }
+        };
+
+        let action = match inter.data.custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+            Some("attack") => Action::Attack,
+            Some("heavy") => Action::Heavy,
+            Some("defend") => Action::Defend,
+            Some("flee") => Action::Flee,
+            _ => continue,
+        };
+
+        if inter.user.id != ctx.author().id {
+            CommonRes::isnt_your_turn(ctx, &inter).await?;
+            continue;
+        }
+
+        inter.defer(ctx).await?;
+
+        for event in combat.round(action) {
+            log.push(event_line(ctx, combat, &event));
+        }
+
+        if combat.state == State::Fighting {
+            reply
+                .edit(
+                    ctx,
+                    CreateReply::default()
+                        .embed(embed(ctx, monster, combat, log))
+                        .components(buttons(ctx)),
+                )
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+mod craft;
+pub use craft::craft;
+
+mod equipment;
+use equipment::{equip, equipment, unequip};
+
+mod fight;
+pub use fight::fight;
+
+mod season;
+pub use season::season;
+use season::season_error;
+
+mod skills;
+pub use skills::stat_key;
+use skills::{allocate, reset, skills};
+
+use crate::{serenity::User, translate, Context, Error, PageField, Parser};
+use database::{
+    models::{RpgRole, RpgState},
+    player::{self, Player, Season},
+};
+use poise::{
+    serenity_prelude::{CreateEmbed, Mentionable, UserId},
+    CreateReply,
+};
+
+const TOP: i64 = 10;
+const BAR_LENGTH: usize = 10;
+
+fn bar(player: &Player) -> String {
+    let needed = player::level_experience(player.level).max(1);
+    let filled = (player.experience.max(0) as usize * BAR_LENGTH / needed as usize).min(BAR_LENGTH);
+    format!("{}{}", "▰".repeat(filled), "▱".repeat(BAR_LENGTH - filled))
+}
+
+fn role_key(role: &RpgRole) -> &'static str {
+    match role {
+        RpgRole::Knight => "rpg-role-knight",
+        RpgRole::King => "rpg-role-king",
+        RpgRole::Coordinator => "rpg-role-coordinator",
+    }
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg",
+    subcommand_required,
+    subcommands(
+        "join",
+        "profile",
+        "results",
+        "equip",
+        "unequip",
+        "equipment",
+        "skills",
+        "allocate",
+        "reset"
+    )
+)]
+pub async fn rpg(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn join(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let joined = match Season::running(pool).await {
+        Ok(season) => Player::join(pool, &season, ctx.author().id.into())
+            .await
+            .map(|_| season),
+        Err(error) => Err(error),
+    };
+
+    let season = match joined {
+        Ok(season) => season,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let content = translate!(ctx, "rpg-joined", id: season.id);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// level and standing of someone in the running season
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn profile(ctx: Context<'_>, user: Option<User>) -> Result<(), Error> {
+    let user = user.unwrap_or(ctx.author().clone());
+
+    let (season, player) = match Player::current(&ctx.data().pool, user.id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let phase = match season.state {
+        RpgState::Nightmare => translate!(ctx, "rpg-phase-nightmare"),
+        _ => translate!(ctx, "rpg-phase-active"),
+    };
+
+    let roles = match player.roles.is_empty() {
+        true => translate!(ctx, "rpg-no-roles"),
+        false => player
+            .roles
+            .iter()
+            .map(|role| translate!(ctx, role_key(role)))
+            .collect::<Vec<String>>()
+            .join(", "),
+    };
+
+    let mut description = translate!(
+        ctx,
+        "rpg-profile-level",
+        level: player.level,
+        experience: player.experience,
+        needed: player::level_experience(player.level)
+    );
+    description.push_str(&format!("\n{}", bar(&player)));
+
+    if player.outlaw {
+        description.push_str(&format!("\n{}", translate!(ctx, "rpg-outlaw")));
+    }
+
+    let embed = CreateEmbed::default()
+        .title(translate!(ctx, "rpg-profile-title", user: user.display_name(), id: season.id))
+        .description(description)
+        .field(translate!(ctx, "rpg-phase"), phase, true)
+        .field(
+            translate!(ctx, "rpg-balance"),
+            Parser::num_with_commas(player.balance),
+            true,
+        )
+        .field(translate!(ctx, "rpg-roles"), roles, true);
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
+
+// standings of the running season, or the archive of the last one
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn results(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let mention = |id: i64| UserId::new(id as u64).mention().to_string();
+
+    let page = match Season::current(pool).await? {
+        Some(season) => {
+            let lines = Player::ranking(pool, season.id, TOP)
+                .await?
+                .iter()
+                .enumerate()
+                .map(|(index, player)| {
+                    translate!(
+                        ctx,
+                        "season-result-line",
+                        rank: index + 1,
+                        user: mention(player.id),
+                        level: player.level
+                    )
+                })
+                .collect::<Vec<String>>();
+
+            PageField {
+                title: translate!(ctx, "rpg-results-current", id: season.id),
+                description: lines.join("\n"),
+            }
+        }
+        None => {
+            let season = match Season::last_ended(pool).await? {
+                Some(season) => season,
+                None => return Err(translate!(ctx, "season-none").into()),
+            };
+
+            let lines = Season::results(pool, season.id)
+                .await?
+                .iter()
+                .take(TOP as usize)
+                .map(|result| {
+                    translate!(
+                        ctx,
+                        "season-result-line",
+                        rank: result.rank,
+                        user: mention(result.player),
+                        level: result.level
+                    )
+                })
+                .collect::<Vec<String>>();
+
+            PageField {
+                title: translate!(ctx, "rpg-results-last", id: season.id),
+                description: lines.join("\n"),
+            }
+        }
+    };
+
+    crate::paginate(ctx, vec![page]).await?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::{translate, Context, Error, PageField};
+use database::{error::SeasonError, player::Season};
+use poise::serenity_prelude::{Mentionable, UserId};
+
+pub fn season_error(ctx: Context<'_>, error: SeasonError) -> String {
+    match error {
+        SeasonError::NoSeason => translate!(ctx, "season-none"),
+        SeasonError::AlreadyRunning => translate!(ctx, "season-already-running"),
+        SeasonError::AlreadyNightmare => translate!(ctx, "season-already-nightmare"),
+        SeasonError::JoinClosed => translate!(ctx, "rpg-join-closed"),
+        SeasonError::AlreadyJoined => translate!(ctx, "rpg-already-joined"),
+        SeasonError::NotJoined => translate!(ctx, "rpg-not-joined"),
+        _ => translate!(ctx, "unknown-error"),
+    }
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    owners_only,
+    category = "admin",
+    subcommand_required,
+    subcommands("start", "nightmare", "end")
+)]
+pub async fn season(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn start(ctx: Context<'_>) -> Result<(), Error> {
+    let season = match Season::start(&ctx.data().pool).await {
+        Ok(season) => season,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let content = translate!(ctx, "season-started", id: season.id);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// last phase of a season, nobody new joins and the rules get harder
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn nightmare(ctx: Context<'_>) -> Result<(), Error> {
+    let season = match Season::nightmare(&ctx.data().pool).await {
+        Ok(season) => season,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let rules = season.rules();
+    let content = translate!(
+        ctx,
+        "season-nightmare-started",
+        id: season.id,
+        experience: rules.experience,
+        loot: rules.loot,
+        power: rules.enemy_power
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn end(ctx: Context<'_>) -> Result<(), Error> {
+    let (season, results) = match Season::end(&ctx.data().pool).await {
+        Ok(ended) => ended,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let podium = results
+        .iter()
+        .take(3)
+        .map(|result| {
+            translate!(
+                ctx,
+                "season-result-line",
+                rank: result.rank,
+                user: UserId::new(result.player as u64).mention().to_string(),
+                level: result.level
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let pages = vec![PageField {
+        title: translate!(ctx, "season-ended", id: season.id, players: results.len()),
+        description: podium.join("\n"),
+    }];
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
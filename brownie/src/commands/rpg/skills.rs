@@ -0,0 +1,209 @@
+use super::season_error;
+use crate::{
+    commands::autocomplete::{skill_auto, stat_auto},
+    translate, Context, Error, PageField,
+};
+use database::{
+    error::SkillError,
+    models::SkillStat,
+    player::{self, Player, STATS},
+};
+
+const BAR_LENGTH: usize = 10;
+
+pub fn stat_key(stat: SkillStat) -> &'static str {
+    match stat {
+        SkillStat::Accuracy => "stat-accuracy",
+        SkillStat::Damage => "stat-damage",
+        SkillStat::MaxLife => "stat-max-life",
+        SkillStat::Defense => "stat-defense",
+        SkillStat::Quantity => "stat-quantity",
+        SkillStat::Quality => "stat-quality",
+    }
+}
+
+fn skill_error(ctx: Context<'_>, error: SkillError) -> String {
+    match error {
+        SkillError::UnknownSkill => translate!(ctx, "skill-unknown"),
+        SkillError::WrongStat => translate!(ctx, "skill-wrong-stat"),
+        SkillError::InvalidAmount => translate!(ctx, "skill-invalid-amount"),
+        SkillError::NotEnoughPoints => translate!(ctx, "skill-not-enough-points"),
+        SkillError::NothingToReset => translate!(ctx, "skill-nothing-to-reset"),
+        _ => translate!(ctx, "unknown-error"),
+    }
+}
+
+// autocomplete choices end with the id of the skill or the number of the stat
+fn last_number(choice: &str) -> Option<i32> {
+    choice
+        .split_whitespace()
+        .last()
+        .and_then(|number| number.parse::<i32>().ok())
+}
+
+// level, points and stats of every skill in the running season
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn skills(ctx: Context<'_>) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (_, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let mut pages = player
+        .skills(pool)
+        .await?
+        .iter()
+        .map(|skill| {
+            let needed = player::skill_experience(skill.experience_rate, skill.level);
+            let filled =
+                (skill.experience.max(0) as usize * BAR_LENGTH / needed as usize).min(BAR_LENGTH);
+
+            let mut lines = vec![
+                translate!(
+                    ctx,
+                    "skill-level",
+                    level: skill.level,
+                    max: skill.max_level,
+                    experience: skill.experience,
+                    needed: needed
+                ),
+                format!("{}{}", "▰".repeat(filled), "▱".repeat(BAR_LENGTH - filled)),
+                translate!(ctx, "skill-available", points: skill.available_points),
+            ];
+
+            for stat in player::skill_stats(skill.skill_type) {
+                let points = skill
+                    .points
+                    .iter()
+                    .find(|(spent, _)| spent == stat)
+                    .map(|(_, points)| *points)
+                    .unwrap_or(0);
+
+                lines.push(translate!(
+                    ctx,
+                    "skill-stat-line",
+                    stat: translate!(ctx, stat_key(*stat)),
+                    points: points,
+                    value: points * player::point_value(*stat)
+                ));
+            }
+
+            PageField {
+                title: translate!(ctx, &format!("skill-{}", skill.name)),
+                description: lines.join("\n"),
+            }
+        })
+        .collect::<Vec<PageField>>();
+
+    let stats = player.stats(pool).await?;
+    pages.push(PageField {
+        title: translate!(ctx, "equipment-stats-title"),
+        description: translate!(
+            ctx,
+            "skill-effective",
+            damage: stats.damage,
+            defense: stats.defense,
+            accuracy: stats.accuracy,
+            life: stats.max_life,
+            quantity: stats.quantity,
+            quality: stats.quality
+        ),
+    });
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn allocate(
+    ctx: Context<'_>,
+    #[autocomplete = "skill_auto"] skill: String,
+    #[autocomplete = "stat_auto"] stat: String,
+    #[min = 1] points: Option<i32>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (_, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let skill = match last_number(&skill) {
+        Some(skill) => skill,
+        None => return Err(translate!(ctx, "skill-unknown").into()),
+    };
+
+    let stat = match last_number(&stat)
+        .filter(|number| *number > 0)
+        .and_then(|number| STATS.get(number as usize - 1))
+    {
+        Some(stat) => *stat,
+        None => return Err(translate!(ctx, "skill-wrong-stat").into()),
+    };
+
+    let points = points.unwrap_or(1);
+
+    if let Err(error) = player.allocate(pool, skill, stat, points).await {
+        return Err(skill_error(ctx, error).into());
+    }
+
+    let content = translate!(
+        ctx,
+        "skill-allocated",
+        points: points,
+        stat: translate!(ctx, stat_key(stat))
+    );
+    ctx.reply(content).await?;
+
+    Ok(())
+}
+
+// the points of the skill can be spent again
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "rpg"
+)]
+pub async fn reset(
+    ctx: Context<'_>,
+    #[autocomplete = "skill_auto"] skill: String,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+
+    let (_, player) = match Player::current(pool, ctx.author().id.into()).await {
+        Ok(current) => current,
+        Err(error) => return Err(season_error(ctx, error).into()),
+    };
+
+    let skill = match last_number(&skill) {
+        Some(skill) => skill,
+        None => return Err(translate!(ctx, "skill-unknown").into()),
+    };
+
+    let refunded = match player.reset_skill(pool, skill).await {
+        Ok(refunded) => refunded,
+        Err(error) => return Err(skill_error(ctx, error).into()),
+    };
+
+    let content = translate!(ctx, "skill-reset-done", points: refunded);
+    ctx.reply(content).await?;
+
+    Ok(())
+}
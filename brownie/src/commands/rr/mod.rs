@@ -0,0 +1,191 @@
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    choice::Game,
+    CommonRes,
+};
+use crate::{
+    serenity::{ComponentInteractionCollector, UserId},
+    translate, Context, Duration, Error, Parser,
+};
+use poise::ReplyHandle;
+use response::{ErrorRes, Response};
+use std::time::Instant;
+use types::rr::{Rule, RussianRoulette, MAX_PLAYERS, MIN_PLAYERS};
+
+mod response;
+
+const LOBBY_SECS: u64 = 45;
+const TURN_SECS: u64 = 30;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    check = "self_can_gamble",
+    category = "gambling"
+)]
+pub async fn rr(
+    ctx: Context<'_>,
+    bios: Option<String>,
+    chambers: Option<u8>,
+    bullets: Option<u8>,
+    rule: Option<Rule>,
+) -> Result<(), Error> {
+    let author_id = ctx.author().id;
+    let bet = Parser::amount(ctx, author_id, bios, 500).await?;
+
+    let mut rr = RussianRoulette::new(
+        ctx.author(),
+        bet,
+        chambers.unwrap_or(6) as usize,
+        bullets.unwrap_or(1) as usize,
+        rule.unwrap_or(Rule::Spin),
+    )?;
+
+    crate::set_gamble(ctx, author_id).await?;
+
+    let reply = Response::lobby(ctx, &rr).await?;
+
+    lobby(ctx, &mut rr, bet).await?;
+
+    if rr.start().is_err() {
+        crate::free_gamble(ctx, ids(&rr)).await?;
+        Response::cancelled(ctx, &reply).await?;
+        return Ok(());
+    }
+
+    play(ctx, &reply, &mut rr).await?;
+
+    Ok(())
+}
+
+fn ids(rr: &RussianRoulette) -> Vec<UserId> {
+    rr.players.iter().map(|player| player.id).collect()
+}
+
+// everyone joins with the host's bet until the host starts or time runs out
+async fn lobby(ctx: Context<'_>, rr: &mut RussianRoulette, bet: i64) -> Result<(), Error> {
+    let started = Instant::now();
+    let lobby_time = Duration::from_secs(LOBBY_SECS);
+
+    while let Some(remaining) = lobby_time.checked_sub(started.elapsed()) {
+        let inter = match ComponentInteractionCollector::new(ctx)
+            .timeout(remaining)
+            .await
+        {
+            Some(inter) => inter,
+            None => break,
+        };
+
+        if inter.data.custom_id == format!("{}_join", ctx.id()) {
+            if rr.get_player(inter.user.id).is_some() {
+                CommonRes::already_action(ctx, &inter).await?;
+            } else if rr.players.len() == MAX_PLAYERS {
+                let content = translate!(ctx, "rr-full");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+            } else if let Err(why) = user_can_gamble(ctx, inter.user.clone()).await {
+                ErrorRes::ephemeral(ctx, &inter, why.to_string()).await?;
+            } else if !crate::can_partial_bet(ctx, inter.user.id, bet).await? {
+                let content = translate!(ctx, "rr-no-bios");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+            } else {
+                rr.add_player(&inter.user)?;
+                crate::set_gamble(ctx, inter.user.id).await?;
+                Response::joined(ctx, &inter, rr).await?;
+            }
+        }
+
+        if inter.data.custom_id == format!("{}_start", ctx.id()) {
+            if inter.user.id != ctx.author().id {
+                let content = translate!(ctx, "error-button");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+                continue;
+            }
+
+            if rr.players.len() < MIN_PLAYERS {
+                let content = translate!(ctx, "rr-not-enough");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+                continue;
+            }
+
+            if rr.revolver.bullets() >= rr.players.len() {
+                let content = translate!(ctx, "rr-too-many-bullets");
+                ErrorRes::ephemeral(ctx, &inter, content).await?;
+                continue;
+            }
+
+            inter.defer(ctx).await?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+// a player that takes too long has the trigger pulled for them
+async fn play(
+    ctx: Context<'_>,
+    reply: &ReplyHandle<'_>,
+    rr: &mut RussianRoulette,
+) -> Result<(), Error> {
+    Response::turn(ctx, reply, rr, None).await?;
+
+    loop {
+        let current = rr.current_player().id;
+        let started = Instant::now();
+        let turn_time = Duration::from_secs(TURN_SECS);
+
+        while let Some(remaining) = turn_time.checked_sub(started.elapsed()) {
+            let inter = match ComponentInteractionCollector::new(ctx)
+                .timeout(remaining)
+                .await
+            {
+                Some(inter) => inter,
+                None => break,
+            };
+
+            if inter.data.custom_id != format!("{}_trigger", ctx.id()) {
+                continue;
+            }
+
+            if rr.get_player(inter.user.id).is_none() {
+                continue;
+            }
+
+            if inter.user.id != current {
+                CommonRes::isnt_your_turn(ctx, &inter).await?;
+                continue;
+            }
+
+            inter.defer(ctx).await?;
+            break;
+        }
+
+        let shot = rr.pull_trigger()?;
+
+        if rr.is_finished() {
+            settle(ctx, rr).await?;
+            Response::end(ctx, reply, rr, &shot).await?;
+            break;
+        }
+
+        Response::turn(ctx, reply, rr, Some(&shot)).await?;
+    }
+
+    Ok(())
+}
+
+async fn settle(ctx: Context<'_>, rr: &RussianRoulette) -> Result<(), Error> {
+    for (id, net) in rr.payouts() {
+        if net != 0 {
+            crate::charge_single_bet(ctx, id, net.abs(), net > 0).await?;
+        }
+
+        crate::log_solo(ctx, id, net, rr.bet, Game::RussianRoulette).await?;
+    }
+
+    crate::free_gamble(ctx, ids(rr)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use crate::{translate, Context, Error, Parser};
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseMessage,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::rr::{RussianRoulette, Shot, MAX_PLAYERS};
+
+pub struct Response;
+
+pub struct ErrorRes;
+
+struct Button;
+
+fn lobby(ctx: Context<'_>, rr: &RussianRoulette) -> String {
+    let mut content = translate!(
+        ctx,
+        "rr-lobby",
+        amount: Parser::abbreviate_number(rr.bet),
+        chambers: rr.revolver.size(),
+        bullets: rr.revolver.bullets(),
+        players: rr.players.len(),
+        max: MAX_PLAYERS
+    );
+
+    for player in rr.players.iter() {
+        content.push_str(&format!("\n{}", player.name));
+    }
+
+    content
+}
+
+fn table(ctx: Context<'_>, rr: &RussianRoulette) -> String {
+    let mut content = translate!(
+        ctx,
+        "rr-table",
+        pot: Parser::abbreviate_number(rr.pot()),
+        bullets: rr.revolver.bullets()
+    );
+
+    for player in rr.players.iter() {
+        let line = match player.alive {
+            true => translate!(ctx, "rr-alive", user: player.name.as_str()),
+            false => translate!(ctx, "rr-dead", user: player.name.as_str()),
+        };
+
+        content.push_str(&format!("\n{}", line));
+    }
+
+    content
+}
+
+fn shot(ctx: Context<'_>, rr: &RussianRoulette, shot: &Shot) -> String {
+    let name = rr
+        .get_player(shot.id)
+        .map(|player| player.name.as_str())
+        .unwrap_or("");
+
+    match shot.fired {
+        true => translate!(ctx, "rr-bang", user: name),
+        false => translate!(ctx, "rr-click", user: name),
+    }
+}
+
+impl ErrorRes {
+    pub async fn ephemeral(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        reason: String,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(reason)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Response {
+    pub async fn lobby<'a>(
+        ctx: Context<'a>,
+        rr: &RussianRoulette,
+    ) -> Result<ReplyHandle<'a>, Error> {
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(lobby(ctx, rr))
+                    .components(Button::lobby(ctx, false)),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn joined(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        rr: &RussianRoulette,
+    ) -> Result<(), Error> {
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(lobby(ctx, rr))
+                        .components(Button::lobby(ctx, rr.players.len() == MAX_PLAYERS)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn cancelled(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "rr-cancelled");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn turn(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        rr: &RussianRoulette,
+        last: Option<&Shot>,
+    ) -> Result<(), Error> {
+        let mut content = table(ctx, rr);
+
+        if let Some(last) = last {
+            content = format!("{}\n\n{}", shot(ctx, rr, last), content);
+        }
+
+        let turn = translate!(ctx, "rr-turn", user: rr.current_player().name.as_str());
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(format!("{}\n\n{}", content, turn))
+                    .components(Button::trigger(ctx)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn end(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        rr: &RussianRoulette,
+        last: &Shot,
+    ) -> Result<(), Error> {
+        let mut content = format!("{}\n\n{}\n", shot(ctx, rr, last), table(ctx, rr));
+
+        for (id, net) in rr.payouts() {
+            let name = rr.get_player(id).unwrap().name.as_str();
+            let amount = Parser::num_with_commas(net.abs());
+
+            let line = match net > 0 {
+                true => translate!(ctx, "rr-won", user: name, amount: amount),
+                false => translate!(ctx, "rr-lost", user: name, amount: amount),
+            };
+
+            content.push_str(&format!("\n{}", line));
+        }
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn lobby(ctx: Context<'_>, full: bool) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_join", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "join"))
+                .disabled(full),
+            CreateButton::new(format!("{}_start", ctx.id()))
+                .style(ButtonStyle::Primary)
+                .label(translate!(ctx, "start")),
+        ])]
+    }
+
+    fn trigger(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![CreateButton::new(format!(
+            "{}_trigger",
+            ctx.id()
+        ))
+        .style(ButtonStyle::Danger)
+        .label(translate!(ctx, "pull-trigger"))])]
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{serenity::User, translate, Context, Error, PageField};
+use database::commands::CommandLog;
+use poise::serenity_prelude::{Mentionable, UserId};
+
+const TOP: i64 = 10;
+
+#[poise::command(
+    prefix_command,
+    slash_command,
+    owners_only,
+    category = "admin",
+    subcommand_required,
+    subcommands("commands")
+)]
+pub async fn stats(_ctx: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+// most used commands, most active members and uses per day, or the
+// commands of one member when given
+#[poise::command(prefix_command, slash_command, owners_only, category = "admin")]
+pub async fn commands(
+    ctx: Context<'_>,
+    user: Option<User>,
+    #[min = 1]
+    #[max = 90]
+    days: Option<i32>,
+) -> Result<(), Error> {
+    let pool = &ctx.data().pool;
+    let days = days.unwrap_or(7);
+
+    let mut pages = Vec::new();
+
+    if let Some(user) = user {
+        let usage = CommandLog::member(pool, user.id.into(), days, TOP).await?;
+
+        pages.push(PageField {
+            title: translate!(ctx, "stats-member", user: user.name.clone(), days: days),
+            description: lines(usage.iter().map(|cmd| (cmd.name.clone(), cmd.uses))),
+        });
+    } else {
+        let top = CommandLog::top(pool, days, TOP).await?;
+        let members = CommandLog::top_members(pool, days, TOP).await?;
+        let daily = CommandLog::daily(pool, days).await?;
+
+        pages.push(PageField {
+            title: translate!(ctx, "stats-top", days: days),
+            description: lines(top.iter().map(|cmd| (cmd.name.clone(), cmd.uses))),
+        });
+
+        pages.push(PageField {
+            title: translate!(ctx, "stats-members", days: days),
+            description: lines(members.iter().map(|usage| {
+                let member = UserId::new(usage.member as u64).mention().to_string();
+                (member, usage.uses)
+            })),
+        });
+
+        pages.push(PageField {
+            title: translate!(ctx, "stats-daily", days: days),
+            description: lines(daily.iter().map(|usage| (usage.day.clone(), usage.uses))),
+        });
+    }
+
+    if pages.iter().all(|page| page.description.is_empty()) {
+        let content = translate!(ctx, "stats-empty");
+        ctx.reply(content).await?;
+        return Ok(());
+    }
+
+    crate::paginate(ctx, pages).await?;
+
+    Ok(())
+}
+
+fn lines(entries: impl Iterator<Item = (String, i64)>) -> String {
+    entries
+        .map(|(name, uses)| format!("{} `{}`", name, uses))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
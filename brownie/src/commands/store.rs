@@ -1,4 +1,5 @@
-use crate::{get_system, translate, Context, Error};
+use crate::{get_system, translate, Context, Error, Parser};
+use database::error::ShopError;
 
 #[poise::command(
     prefix_command,
@@ -29,8 +30,15 @@ pub async fn view(ctx: Context<'_>) -> Result<(), Error> {
         .iter()
         .map(|product| crate::PageField {
             title: translate!(ctx, &product.item.name),
-            description: translate!(ctx, "product-info",
-                price: product.price),
+            description: match product.stock {
+                Some(stock) => translate!(ctx, "product-info-stock",
+                    id: product.item.id.unwrap_or_default(),
+                    price: product.price,
+                    stock: stock),
+                None => translate!(ctx, "product-info",
+                    id: product.item.id.unwrap_or_default(),
+                    price: product.price),
+            },
         })
         .collect::<Vec<_>>();
 
@@ -46,6 +54,50 @@ pub async fn view(ctx: Context<'_>) -> Result<(), Error> {
     interaction_context = "Guild|BotDm|PrivateChannel",
     category = "economy"
 )]
-pub async fn buy(ctx: Context<'_>, item: i32) -> Result<(), Error> {
+pub async fn buy(ctx: Context<'_>, item: i32, #[min = 1] amount: Option<i32>) -> Result<(), Error> {
+    let amount = amount.unwrap_or(1);
+
+    let system = get_system(ctx).await;
+
+    let product = {
+        let lock = system.lock().await;
+
+        match lock.get_product(item) {
+            Some(product) => product.item.clone(),
+            None => return Err(translate!(ctx, "shop-not-found").into()),
+        }
+    };
+
+    let member = crate::get_member(ctx, ctx.author().id).await?;
+    let mut write = member.write().await;
+
+    let purchase = match write.buy(&ctx.data().pool, product.clone(), amount).await {
+        Ok(purchase) => purchase,
+        Err(error) => {
+            let content = match error {
+                ShopError::ItemNotFound => translate!(ctx, "shop-not-found"),
+                ShopError::InvalidAmount => translate!(ctx, "shop-invalid-amount"),
+                ShopError::OutOfStock => translate!(ctx, "shop-out-of-stock"),
+                ShopError::InsufficientFunds => translate!(ctx, "not-enough-bios"),
+                _ => translate!(ctx, "unknown-error"),
+            };
+
+            return Err(content.into());
+        }
+    };
+
+    drop(write);
+
+    if purchase.stock.is_some() {
+        let mut lock = system.lock().await;
+        lock.refresh_shop(&ctx.data().pool).await?;
+    }
+
+    let content = translate!(ctx, "shop-bought",
+        amount: amount,
+        item: translate!(ctx, &product.name),
+        bios: Parser::num_with_commas(purchase.total));
+    ctx.reply(content).await?;
+
     Ok(())
 }
@@ -0,0 +1,386 @@
+use super::{
+    check::{self_can_gamble, user_can_gamble},
+    CommonRes,
+};
+use crate::{
+    get_member, get_system,
+    serenity::{
+        ComponentInteraction, ComponentInteractionCollector, ComponentInteractionDataKind,
+        ModalInteraction, ModalInteractionCollector, User,
+    },
+    translate, Context, Duration, Error, Parser,
+};
+use database::structs::Member;
+use poise::{Modal, ReplyHandle};
+use responses::Response;
+use tokio::select;
+use types::trade::{OfferItem, Side, Trade};
+
+mod responses;
+
+const REQUEST_SECS: u64 = 60;
+// the window closes after this long without anyone touching it
+const WINDOW_SECS: u64 = 180;
+
+#[derive(poise::Modal)]
+struct AmountModal {
+    amount: String,
+}
+
+enum Signal {
+    Update,
+    Confirmed,
+    Cancelled,
+    Nothing,
+}
+
+enum Event {
+    Interaction(ComponentInteraction),
+    ModalInter(ModalInteraction),
+    Timeout,
+}
+
+// both members stay marked as playing while the window is open, so nothing
+// they offered can be bet or moved before the swap
+#[poise::command(
+    prefix_command,
+    slash_command,
+    install_context = "Guild|User",
+    interaction_context = "Guild|BotDm|PrivateChannel",
+    category = "economy",
+    check = "self_can_gamble"
+)]
+pub async fn trade(ctx: Context<'_>, user: User) -> Result<(), Error> {
+    if user.id == ctx.author().id || user.bot {
+        return Err(translate!(ctx, "trade-self").into());
+    }
+
+    user_can_gamble(ctx, user.clone()).await?;
+
+    let reply = Response::request(ctx, &user).await?;
+
+    crate::set_gamble(ctx, ctx.author().id).await?;
+
+    if !request(ctx, &reply, &user).await? {
+        return Ok(());
+    }
+
+    let result = run(ctx, &reply, &user).await;
+    crate::free_gamble(ctx, vec![ctx.author().id, user.id]).await?;
+
+    result
+}
+
+// only the requested user can accept, true once the window opens
+async fn request(ctx: Context<'_>, reply: &ReplyHandle<'_>, user: &User) -> Result<bool, Error> {
+    let author_id = ctx.author().id;
+
+    while let Some(inter) = ComponentInteractionCollector::new(ctx)
+        .timeout(Duration::from_secs(REQUEST_SECS))
+        .await
+    {
+        if inter.user.id != user.id && inter.user.id != author_id {
+            continue;
+        }
+
+        if inter.data.custom_id == format!("{}_accept", ctx.id()) {
+            if inter.user.id != user.id {
+                CommonRes::self_accept(ctx, &inter).await?;
+                continue;
+            }
+
+            if user_can_gamble(ctx, inter.user.clone()).await.is_err() {
+                crate::free_gamble(ctx, vec![author_id]).await?;
+                CommonRes::cannot_accept(ctx, &inter).await?;
+                return Ok(false);
+            }
+
+            crate::set_gamble(ctx, user.id).await?;
+            inter.defer(ctx).await?;
+
+            return Ok(true);
+        }
+
+        if inter.data.custom_id == format!("{}_decline", ctx.id()) {
+            crate::free_gamble(ctx, vec![author_id]).await?;
+            Response::declined(ctx, &inter, &inter.user).await?;
+            return Ok(false);
+        }
+    }
+
+    crate::free_gamble(ctx, vec![author_id]).await?;
+    Response::expired(ctx, reply).await?;
+
+    Ok(false)
+}
+
+async fn run(ctx: Context<'_>, reply: &ReplyHandle<'_>, user: &User) -> Result<(), Error> {
+    let mut trade = Trade::new(ctx.author(), user);
+
+    Response::window(ctx, reply, &trade).await?;
+
+    loop {
+        let timeout = Duration::from_secs(WINDOW_SECS);
+
+        let collector = ComponentInteractionCollector::new(ctx).timeout(timeout);
+        let modal_collector = ModalInteractionCollector::new(ctx).timeout(timeout);
+
+        let event = select! {
+            inter = collector.next() => match inter {
+                Some(inter) => Event::Interaction(inter),
+                None => Event::Timeout,
+            },
+            modal_inter = modal_collector.next() => match modal_inter {
+                Some(modal_inter) => Event::ModalInter(modal_inter),
+                None => Event::Timeout,
+            },
+        };
+
+        let signal = match event {
+            Event::Interaction(inter) => handle_interaction(ctx, &inter, &mut trade).await?,
+            Event::ModalInter(inter) => handle_modal(ctx, &inter, &mut trade).await?,
+            Event::Timeout => {
+                Response::end(ctx, reply, &trade, "trade-expired").await?;
+                return Ok(());
+            }
+        };
+
+        match signal {
+            Signal::Update => Response::window(ctx, reply, &trade).await?,
+            Signal::Confirmed => break,
+            Signal::Cancelled => {
+                Response::end(ctx, reply, &trade, "trade-cancelled").await?;
+                return Ok(());
+            }
+            Signal::Nothing => {}
+        }
+    }
+
+    let key = match swap(ctx, &trade).await? {
+        true => "trade-done",
+        false => "trade-failed",
+    };
+
+    Response::end(ctx, reply, &trade, key).await?;
+
+    Ok(())
+}
+
+async fn handle_interaction(
+    ctx: Context<'_>,
+    inter: &ComponentInteraction,
+    trade: &mut Trade,
+) -> Result<Signal, Error> {
+    let action = match inter.data.custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+        Some(action) => action,
+        None => return Ok(Signal::Nothing),
+    };
+
+    if trade.get_side(inter.user.id).is_err() {
+        return Ok(Signal::Nothing);
+    }
+
+    match action {
+        "bios" => Response::bios_modal(ctx, inter).await?,
+        "item" => {
+            let member = get_member(ctx, inter.user.id).await?;
+            let inventory = member.read().await.get_inventory();
+
+            Response::choose_item(ctx, inter, &inventory).await?;
+        }
+        "pick" => {
+            let item_id = match &inter.data.kind {
+                ComponentInteractionDataKind::StringSelect { values } => {
+                    values.first().and_then(|value| value.parse::<i32>().ok())
+                }
+                _ => None,
+            };
+
+            let member = get_member(ctx, inter.user.id).await?;
+            let read = member.read().await;
+
+            if let Some(owned) = item_id.and_then(|id| read.get_item_by_id(id)) {
+                Response::amount_modal(ctx, inter, &owned).await?;
+            }
+        }
+        "confirm" => {
+            if trade.is_empty() {
+                Response::empty(ctx, inter).await?;
+                return Ok(Signal::Nothing);
+            }
+
+            inter.defer(ctx).await?;
+
+            return match trade.confirm(inter.user.id)? {
+                true => Ok(Signal::Confirmed),
+                false => Ok(Signal::Update),
+            };
+        }
+        "cancel" => {
+            inter.defer(ctx).await?;
+            return Ok(Signal::Cancelled);
+        }
+        _ => {}
+    }
+
+    Ok(Signal::Nothing)
+}
+
+async fn handle_modal(
+    ctx: Context<'_>,
+    inter: &ModalInteraction,
+    trade: &mut Trade,
+) -> Result<Signal, Error> {
+    let action = match inter.data.custom_id.strip_prefix(&format!("{}_", ctx.id())) {
+        Some(action) => action,
+        None => return Ok(Signal::Nothing),
+    };
+
+    if trade.get_side(inter.user.id).is_err() {
+        return Ok(Signal::Nothing);
+    }
+
+    let modal = AmountModal::parse(inter.data.clone())?;
+
+    let member = get_member(ctx, inter.user.id).await?;
+    let read = member.read().await;
+
+    let changed = if action == "bios" {
+        match Parser::abbreviation_to_number(modal.amount.trim()) {
+            Ok(bios) if bios >= 0 && bios <= read.get_bios() => trade.set_bios(inter.user.id, bios),
+            _ => Err("invalid amount".into()),
+        }
+    } else if let Some(item_id) = action.strip_prefix("amount_") {
+        let owned = match item_id.parse::<i32>() {
+            Ok(item_id) => read.get_item_by_id(item_id),
+            Err(_) => None,
+        };
+
+        match (owned, modal.amount.trim().parse::<i32>()) {
+            (Some(owned), Ok(amount)) if amount <= owned.amount => {
+                let item = OfferItem {
+                    id: owned.info.id.unwrap_or_default(),
+                    name: owned.info.name,
+                    amount,
+                };
+
+                trade.set_item(inter.user.id, item)
+            }
+            _ => Err("invalid amount".into()),
+        }
+    } else {
+        return Ok(Signal::Nothing);
+    };
+
+    drop(read);
+
+    match changed {
+        Ok(()) => {
+            CommonRes::know_modal(ctx, inter).await?;
+            Ok(Signal::Update)
+        }
+        Err(_) => {
+            Response::invalid_amount(ctx, inter).await?;
+            Ok(Signal::Nothing)
+        }
+    }
+}
+
+// false when someone no longer has what they offered
+async fn swap(ctx: Context<'_>, trade: &Trade) -> Result<bool, Error> {
+    let [first, second] = &trade.sides;
+
+    // always in the same order so two trades between the same members can't
+    // wait on one another
+    let (first, second) = match first.id < second.id {
+        true => (first, second),
+        false => (second, first),
+    };
+
+    let first_member = get_member(ctx, first.id).await?;
+    let second_member = get_member(ctx, second.id).await?;
+
+    let mut first_write = first_member.write().await;
+    let mut second_write = second_member.write().await;
+
+    let has_offer = |member: &Member, side: &Side| {
+        member.get_bios() >= side.bios
+            && side.items.iter().all(|item| {
+                member
+                    .get_item_by_id(item.id)
+                    .is_some_and(|owned| owned.amount >= item.amount)
+            })
+    };
+
+    if !has_offer(&first_write, first) || !has_offer(&second_write, second) {
+        return Ok(false);
+    }
+
+    let items = {
+        let system = get_system(ctx).await;
+        let lock = system.lock().await;
+        let pool = &ctx.data().pool;
+
+        let mut items = Vec::new();
+
+        for item in first.items.iter().chain(second.items.iter()) {
+            items.push(lock.get_item_by_id(pool, item.id).await?);
+        }
+
+        items
+    };
+
+    let (first_items, second_items) = items.split_at(first.items.len());
+
+    let data = ctx.data();
+    let mut tx = data.pool.begin().await?;
+
+    let swapped = async {
+        if first.bios > 0 {
+            first_write.decrease_bios(&mut *tx, first.bios).await?;
+            second_write.increase_bios(&mut *tx, first.bios).await?;
+        }
+
+        if second.bios > 0 {
+            second_write.decrease_bios(&mut *tx, second.bios).await?;
+            first_write.increase_bios(&mut *tx, second.bios).await?;
+        }
+
+        for (offered, item) in first.items.iter().zip(first_items) {
+            first_write
+                .remove_item(&mut *tx, offered.id, offered.amount)
+                .await?;
+            second_write
+                .add_item(&mut *tx, item.clone(), offered.amount)
+                .await?;
+        }
+
+        for (offered, item) in second.items.iter().zip(second_items) {
+            second_write
+                .remove_item(&mut *tx, offered.id, offered.amount)
+                .await?;
+            first_write
+                .add_item(&mut *tx, item.clone(), offered.amount)
+                .await?;
+        }
+
+        Ok::<(), Error>(())
+    }
+    .await;
+
+    if swapped.is_err() {
+        tx.rollback().await?;
+
+        drop(first_write);
+        drop(second_write);
+        crate::refresh_cache(ctx, first.id).await;
+        crate::refresh_cache(ctx, second.id).await;
+
+        let err = translate!(ctx, "transaction-error");
+        return Err(err.into());
+    }
+
+    tx.commit().await?;
+
+    Ok(true)
+}
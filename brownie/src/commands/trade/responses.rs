@@ -0,0 +1,294 @@
+use crate::{commands::CommonButton, translate, Context, Error, Parser};
+use database::structs::ItemAmount;
+use poise::{
+    serenity_prelude::{
+        ButtonStyle, ComponentInteraction, CreateActionRow, CreateButton, CreateInputText,
+        CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, CreateSelectMenu,
+        CreateSelectMenuKind, CreateSelectMenuOption, InputTextStyle, Mentionable,
+        ModalInteraction, User,
+    },
+    CreateReply, ReplyHandle,
+};
+use types::trade::{Side, Trade};
+
+pub struct Response;
+
+struct Button;
+
+fn offer(ctx: Context<'_>, side: &Side) -> String {
+    let mut lines = Vec::new();
+
+    if side.bios > 0 {
+        lines.push(translate!(
+            ctx,
+            "trade-offer-bios",
+            amount: Parser::num_with_commas(side.bios)
+        ));
+    }
+
+    for item in side.items.iter() {
+        lines.push(format!("{} {}", item.amount, translate!(ctx, &item.name)));
+    }
+
+    if lines.is_empty() {
+        lines.push(translate!(ctx, "trade-offer-empty"));
+    }
+
+    let status = match side.confirmed {
+        true => translate!(ctx, "trade-confirmed"),
+        false => translate!(ctx, "trade-waiting"),
+    };
+
+    format!("{} {}\n{}", side.name, status, lines.join("\n"))
+}
+
+fn window(ctx: Context<'_>, trade: &Trade) -> String {
+    let offers = trade
+        .sides
+        .iter()
+        .map(|side| offer(ctx, side))
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    format!("{}\n\n{}", translate!(ctx, "trade-window"), offers)
+}
+
+async fn ephemeral(ctx: Context<'_>, inter: &ComponentInteraction, key: &str) -> Result<(), Error> {
+    let content = translate!(ctx, key);
+
+    inter
+        .create_response(
+            ctx,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+impl Response {
+    pub async fn request<'a>(ctx: Context<'a>, user: &User) -> Result<ReplyHandle<'a>, Error> {
+        let content = translate!(
+            ctx,
+            "trade-request",
+            author: ctx.author().mention().to_string(),
+            user: user.mention().to_string()
+        );
+
+        let reply = ctx
+            .send(
+                CreateReply::default()
+                    .content(content)
+                    .components(CommonButton::accept_or_decline(ctx, false))
+                    .allowed_mentions(crate::mentions()),
+            )
+            .await?;
+
+        Ok(reply)
+    }
+
+    pub async fn declined(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        user: &User,
+    ) -> Result<(), Error> {
+        let content = translate!(ctx, "trade-declined", user: user.mention().to_string());
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(CommonButton::accept_or_decline(ctx, true))
+                        .allowed_mentions(crate::mentions()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn expired(ctx: Context<'_>, reply: &ReplyHandle<'_>) -> Result<(), Error> {
+        let content = translate!(ctx, "trade-expired");
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn window(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        trade: &Trade,
+    ) -> Result<(), Error> {
+        reply
+            .edit(
+                ctx,
+                CreateReply::default()
+                    .content(window(ctx, trade))
+                    .components(Button::actions(ctx)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // the window stays visible with the offers as they were when it ended
+    pub async fn end(
+        ctx: Context<'_>,
+        reply: &ReplyHandle<'_>,
+        trade: &Trade,
+        key: &str,
+    ) -> Result<(), Error> {
+        let content = format!("{}\n\n{}", window(ctx, trade), translate!(ctx, key));
+
+        reply
+            .edit(
+                ctx,
+                CreateReply::default().content(content).components(vec![]),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn bios_modal(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        let title = translate!(ctx, "trade-bios-title");
+        let question = translate!(ctx, "trade-bios-question");
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Modal(
+                    CreateModal::new(format!("{}_bios", ctx.id()), title).components(vec![
+                        CreateActionRow::InputText(CreateInputText::new(
+                            InputTextStyle::Short,
+                            question,
+                            "amount",
+                        )),
+                    ]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // discord only allows 25 options, the rest of the inventory is left out
+    pub async fn choose_item(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        inventory: &[ItemAmount],
+    ) -> Result<(), Error> {
+        if inventory.is_empty() {
+            return ephemeral(ctx, inter, "trade-no-items").await;
+        }
+
+        let options = inventory
+            .iter()
+            .take(25)
+            .map(|item| {
+                let label = format!("{} ({})", translate!(ctx, &item.info.name), item.amount);
+                let value = item.info.id.unwrap_or_default().to_string();
+
+                CreateSelectMenuOption::new(label, value)
+            })
+            .collect::<Vec<CreateSelectMenuOption>>();
+
+        let menu = CreateSelectMenu::new(
+            format!("{}_pick", ctx.id()),
+            CreateSelectMenuKind::String { options },
+        )
+        .placeholder(translate!(ctx, "trade-choose-item"));
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .ephemeral(true)
+                        .components(vec![CreateActionRow::SelectMenu(menu)]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn amount_modal(
+        ctx: Context<'_>,
+        inter: &ComponentInteraction,
+        item: &ItemAmount,
+    ) -> Result<(), Error> {
+        let name = translate!(ctx, &item.info.name);
+        let question = translate!(ctx, "trade-item-question", owned: item.amount);
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Modal(
+                    CreateModal::new(
+                        format!("{}_amount_{}", ctx.id(), item.info.id.unwrap_or_default()),
+                        name,
+                    )
+                    .components(vec![CreateActionRow::InputText(
+                        CreateInputText::new(InputTextStyle::Short, question, "amount")
+                            .placeholder("0"),
+                    )]),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn empty(ctx: Context<'_>, inter: &ComponentInteraction) -> Result<(), Error> {
+        ephemeral(ctx, inter, "trade-empty").await
+    }
+
+    pub async fn invalid_amount(ctx: Context<'_>, inter: &ModalInteraction) -> Result<(), Error> {
+        let content = translate!(ctx, "trade-invalid-amount");
+
+        inter
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Button {
+    fn actions(ctx: Context<'_>) -> Vec<CreateActionRow> {
+        vec![CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("{}_bios", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "trade-set-bios")),
+            CreateButton::new(format!("{}_item", ctx.id()))
+                .style(ButtonStyle::Secondary)
+                .label(translate!(ctx, "trade-set-item")),
+            CreateButton::new(format!("{}_confirm", ctx.id()))
+                .style(ButtonStyle::Success)
+                .label(translate!(ctx, "trade-confirm")),
+            CreateButton::new(format!("{}_cancel", ctx.id()))
+                .style(ButtonStyle::Danger)
+                .label(translate!(ctx, "trade-cancel")),
+        ])]
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{translate, Context, Error, Parser};
-use database::error::WorkError;
+use database::{error::WorkError, quests::QuestEvent};
 use std::time::Duration;
 
 #[poise::command(
@@ -57,6 +57,8 @@ pub async fn shift(ctx: Context<'_>) -> Result<(), Error> {
 
     ctx.reply(content).await?;
 
+    crate::progress_quests(ctx, &mut write, QuestEvent::Work).await?;
+
     Ok(())
 }
 
@@ -1,6 +1,15 @@
-use crate::{commands::choice::Game, Data};
-use database::structs::{club::Club, guild::Guild, Member, System};
-use poise::serenity_prelude::{CreateAllowedMentions, GuildId, UserId};
+use crate::{commands::choice::Game, translate, Data, Parser};
+use database::{
+    escrow::Escrow,
+    gamble::GambleLog,
+    models::Buff,
+    quests::QuestEvent,
+    structs::{club::Club, guild::Guild, Member, System},
+};
+use poise::{
+    serenity_prelude::{CreateAllowedMentions, GuildId, Mentionable, UserId},
+    CreateReply,
+};
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 
@@ -143,22 +152,24 @@ pub fn points_revenue(bet: i64) -> PointsRevenue {
     PointsRevenue { winner, loser }
 }
 
+pub async fn add_points(ctx: Context<'_>, id: UserId, points: i32) -> Result<(), Error> {
+    let member = get_member(ctx, id).await?;
+    let mut write = member.write().await;
+    write.increase_points(&ctx.data().pool, points).await?;
+
+    Ok(())
+}
+
 pub async fn add_win_points(
     ctx: Context<'_>,
     winner: UserId,
     loser: UserId,
     bet: i64,
 ) -> Result<(), Error> {
-    let data = ctx.data();
     let revenue = points_revenue(bet);
 
-    let winner = get_member(ctx, winner).await?;
-    let mut write = winner.write().await;
-    write.increase_points(&data.pool, revenue.winner).await?;
-
-    let loser = get_member(ctx, loser).await?;
-    let mut write = loser.write().await;
-    write.increase_points(&data.pool, revenue.loser).await?;
+    add_points(ctx, winner, revenue.winner).await?;
+    add_points(ctx, loser, revenue.loser).await?;
 
     Ok(())
 }
@@ -182,7 +193,13 @@ pub async fn charge_single_bet(
             .increase_points(&data.pool, points_revenue.winner)
             .await?;
     } else {
-        write.decrease_bios(&data.pool, bet).await?;
+        // a life insurance gives part of the lost bet back
+        let refund = match write.consume_buff(&data.pool, Buff::BetInsurance).await? {
+            Some(percent) => bet * percent as i64 / 100,
+            None => 0,
+        };
+
+        write.decrease_bios(&data.pool, bet - refund).await?;
         write
             .increase_points(&data.pool, points_revenue.loser)
             .await?;
@@ -196,9 +213,83 @@ pub async fn charge_bet(
     winner: UserId,
     loser: UserId,
     bet: i64,
-    _game: Game,
+    game: Game,
 ) -> Result<(), Error> {
     add_win_points(ctx, winner, loser, bet).await?;
+    log_versus(ctx, winner, loser, bet, game).await
+}
+
+// the log and quests of a game between two members, without any points
+pub async fn log_versus(
+    ctx: Context<'_>,
+    winner: UserId,
+    loser: UserId,
+    bet: i64,
+    game: Game,
+) -> Result<(), Error> {
+    GambleLog::versus(&game.to_string(), winner.into(), loser.into(), bet)
+        .save(&ctx.data().pool)
+        .await?;
+
+    for id in [winner, loser] {
+        let member = get_member(ctx, id).await?;
+        let mut write = member.write().await;
+
+        progress_quests(ctx, &mut write, QuestEvent::Game(game.to_string())).await?;
+        progress_quests(ctx, &mut write, QuestEvent::Bet(bet)).await?;
+    }
+
+    Ok(())
+}
+
+// games against the house, net is what the member won or lost and bet what
+// they put on the table
+pub async fn log_solo(
+    ctx: Context<'_>,
+    id: UserId,
+    net: i64,
+    bet: i64,
+    game: Game,
+) -> Result<(), Error> {
+    GambleLog::solo(&game.to_string(), id.into(), net)
+        .save(&ctx.data().pool)
+        .await?;
+
+    let member = get_member(ctx, id).await?;
+    let mut write = member.write().await;
+
+    progress_quests(ctx, &mut write, QuestEvent::Game(game.to_string())).await?;
+    progress_quests(ctx, &mut write, QuestEvent::Bet(bet)).await?;
+
+    Ok(())
+}
+
+// callers holding the member's lock pass it here, the member is told about
+// every quest the event completes
+pub async fn progress_quests(
+    ctx: Context<'_>,
+    member: &mut Member,
+    event: QuestEvent,
+) -> Result<(), Error> {
+    let completed = member.progress_quests(&ctx.data().pool, &event).await?;
+
+    for quest in completed {
+        let content = translate!(
+            ctx,
+            "quest-completed",
+            user: UserId::new(member.id as u64).mention().to_string(),
+            quest: translate!(ctx, &format!("quest-{}", quest.name), steps: quest.required),
+            points: Parser::num_with_commas(quest.points as i64),
+            bios: Parser::num_with_commas(quest.bios)
+        );
+
+        ctx.send(
+            CreateReply::default()
+                .content(content)
+                .allowed_mentions(mentions()),
+        )
+        .await?;
+    }
 
     Ok(())
 }
@@ -218,20 +309,98 @@ pub fn mentions() -> CreateAllowedMentions {
         .all_users(false)
 }
 
+// a member is playing while they have a row in the escrow table, which
+// survives cache evictions and restarts unlike the cached member
 pub async fn set_gamble(ctx: Context<'_>, user_id: UserId) -> Result<(), Error> {
-    let member = get_member(ctx, user_id).await?;
-    let mut member_write = member.write().await;
-    member_write.state.in_gamble = true;
+    let data = ctx.data();
+    let game = &ctx.command().qualified_name;
+
+    Escrow::open(&data.pool, user_id.into(), ctx.id() as i64, game).await?;
 
     Ok(())
 }
 
+// frees the members, refunding whatever they still had in escrow
 pub async fn free_gamble(ctx: Context<'_>, users_ids: Vec<UserId>) -> Result<(), Error> {
+    let data = ctx.data();
+
     for user_id in users_ids {
-        let member = get_member(ctx, user_id).await?;
-        let mut member_write = member.write().await;
-        member_write.state.in_gamble = false;
+        if let Some(stake) = Escrow::refund(&data.pool, user_id.into()).await? {
+            adjust_cached_bios(data, user_id, stake.amount).await;
+        }
+    }
+
+    Ok(())
+}
+
+// refunds every stake left by this invocation
+pub async fn free_room(ctx: Context<'_>) -> Result<(), Error> {
+    let data = ctx.data();
+
+    for stake in Escrow::refund_room(&data.pool, ctx.id() as i64).await? {
+        adjust_cached_bios(data, UserId::new(stake.member as u64), stake.amount).await;
+    }
+
+    Ok(())
+}
+
+pub async fn in_gamble(ctx: Context<'_>, user_id: UserId) -> Result<bool, Error> {
+    Ok(Escrow::is_playing(&ctx.data().pool, user_id.into()).await?)
+}
+
+// debits the bet of every player into escrow, nobody pays if one of them can't
+pub async fn hold_bet(ctx: Context<'_>, users_ids: Vec<UserId>, bet: i64) -> Result<(), Error> {
+    let data = ctx.data();
+    let members = users_ids
+        .iter()
+        .map(|id| i64::from(*id))
+        .collect::<Vec<i64>>();
+
+    Escrow::hold(&data.pool, ctx.id() as i64, &members, bet).await?;
+
+    for user_id in users_ids {
+        adjust_cached_bios(data, user_id, -bet).await;
     }
 
     Ok(())
 }
+
+// the whole pot goes to the winner in one transaction, without a winner the
+// house keeps it, points are up to the caller
+pub async fn release_bet(ctx: Context<'_>, winner: Option<UserId>) -> Result<(), Error> {
+    let data = ctx.data();
+
+    let stakes = Escrow::release(&data.pool, ctx.id() as i64, winner.map(i64::from)).await?;
+    let pot = stakes.iter().map(|stake| stake.amount).sum::<i64>();
+
+    if let Some(winner) = winner {
+        adjust_cached_bios(data, winner, pot).await;
+    }
+
+    Ok(())
+}
+
+// every member gets its share of the pot in one transaction, the house keeps
+// the rest, points are up to the caller
+pub async fn release_shares(ctx: Context<'_>, shares: Vec<(UserId, i64)>) -> Result<(), Error> {
+    let data = ctx.data();
+    let members = shares
+        .iter()
+        .map(|(id, amount)| (i64::from(*id), *amount))
+        .collect::<Vec<(i64, i64)>>();
+
+    Escrow::split(&data.pool, ctx.id() as i64, &members).await?;
+
+    for (user_id, amount) in shares {
+        adjust_cached_bios(data, user_id, amount).await;
+    }
+
+    Ok(())
+}
+
+// keeps a cached member in sync with balance changes made straight in the database
+async fn adjust_cached_bios(data: &Data, user_id: UserId, bios: i64) {
+    if let Some(member) = data.members.get(user_id.as_ref()).await {
+        member.write().await.balance.bios += bios;
+    }
+}
@@ -6,6 +6,7 @@ pub use poise::serenity_prelude as serenity;
 use serenity::{GuildId, UserId};
 pub use std::{sync::Arc, time::Duration};
 pub use tokio::sync::{Mutex, RwLock};
+use types::Rooms;
 
 pub mod translation;
 pub use translation::*;
@@ -29,6 +30,8 @@ pub struct Data {
     pub translations: Translations,
     pub system: Cache<(), Arc<Mutex<System>>>,
     pub clubs: Cache<i64, Arc<RwLock<Club>>>,
+    // running games, one per channel and kind
+    pub rooms: Rooms,
 }
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
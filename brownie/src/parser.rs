@@ -1,6 +1,6 @@
 use crate::{helpers::get_member, Context, Error};
 use poise::serenity_prelude::UserId;
-use types::dices::Selection;
+use types::{dices::Selection, roulette::Bet};
 
 pub struct Parser;
 
@@ -187,6 +187,40 @@ impl Parser {
         Ok(result)
     }
 
+    pub fn roulette_bet(choice: String) -> Result<Bet, Error> {
+        let choice = choice.to_lowercase();
+        let words = choice.split_whitespace().collect::<Vec<&str>>();
+
+        let numbers = words[1.min(words.len())..]
+            .iter()
+            .map(|word| word.parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| "invalid roulette bet")?;
+
+        let bet = match (words.first().copied(), numbers.as_slice()) {
+            (Some("red"), []) => Bet::Red,
+            (Some("black"), []) => Bet::Black,
+            (Some("odd"), []) => Bet::Odd,
+            (Some("even"), []) => Bet::Even,
+            (Some("low" | "1-18"), []) => Bet::Low,
+            (Some("high" | "19-36"), []) => Bet::High,
+            (Some("split"), [a, b]) => Bet::Split(*a, *b),
+            (Some("street"), [n]) => Bet::Street(*n),
+            (Some("corner"), [n]) => Bet::Corner(*n),
+            (Some("sixline" | "line"), [n]) => Bet::SixLine(*n),
+            (Some("dozen"), [n]) => Bet::Dozen(*n),
+            (Some("column"), [n]) => Bet::Column(*n),
+            (Some(number), []) => {
+                Bet::Straight(number.parse::<u8>().map_err(|_| "invalid roulette bet")?)
+            }
+            _ => return Err("invalid roulette bet".into()),
+        };
+
+        bet.numbers()?;
+
+        Ok(bet)
+    }
+
     pub fn choice_kind(choice: String) -> ChoiceKind {
         let parse_num = choice.parse::<i32>();
 
@@ -45,8 +45,18 @@ pub fn get(
     attr: Option<&str>,
     args: Option<&fluent::FluentArgs<'_>>,
 ) -> String {
-    let translations = &ctx.data().translations;
-    ctx.locale()
+    get_with_locale(&ctx.data().translations, ctx.locale(), id, attr, args)
+}
+
+// same lookup as get for places without a command context, like events
+pub fn get_with_locale(
+    translations: &Translations,
+    locale: Option<&str>,
+    id: &str,
+    attr: Option<&str>,
+    args: Option<&fluent::FluentArgs<'_>>,
+) -> String {
+    locale
         .and_then(|locale| format(translations.other.get(locale)?, id, attr, args))
         .or_else(|| format(&translations.main, id, attr, args))
         .unwrap_or_else(|| id.to_string())
@@ -0,0 +1,60 @@
+airpoker = airpoker
+    .description = Bet your air in a five round poker match
+    .user = user
+    .user-description = User to play against
+    .bios = bios
+    .bios-description = Amount of bios to bet
+
+call = Call
+raise = Raise
+fold = Fold
+air-hand = Your cards
+
+air-expired =
+    Nobody accepted the air poker match
+
+air-table =
+    Round { $round }/{ $rounds }, blind { $blind }
+
+air-player =
+    { $user }: { $tanks } tanks, { $air }s of air left, betting { $bet }
+
+air-selecting =
+    Waiting for { $users } to choose a card
+
+air-betting =
+    { $user } has to call, raise or fold
+
+air-choose-card =
+    Choose the card you'll play this round
+
+your-cards =
+    Your cards: { $cards }
+
+inform-all-in =
+    You don't have enough tanks to raise, you can only call
+
+air-modal-title = Raise
+air-modal-question = How many tanks do you bet?
+air-modal-placeholder = Up to { $bios } tanks
+
+air-fold =
+    The hand was folded, { $user } takes { $tanks } tanks
+
+air-win =
+    { $user } wins the hand and takes { $tanks } tanks
+
+air-showdown =
+    { $user }: { $value } { $cards }
+
+air-draw =
+    Both hands are equal, nobody takes any tanks
+
+air-end =
+    { $winner } ends with more air and wins { $amount } bios
+
+air-dead =
+    { $loser } ran out of air, { $winner } wins { $amount } bios
+
+air-end-draw =
+    Both players end with the same air, nobody wins
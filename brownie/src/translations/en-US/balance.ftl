@@ -9,3 +9,6 @@ balance-self =
 
 balance-other =
     { $user } tiene { $amount } bios
+
+not-enough-bios =
+    You don't have enough bios
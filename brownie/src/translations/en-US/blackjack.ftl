@@ -0,0 +1,83 @@
+blackjack = blackjack
+    .description = Play blackjack against the dealer
+    .bios = bios
+    .bios-description = Amount of bios to bet
+
+bjtable = bjtable
+    .description = Open a blackjack table for up to five players
+    .bios = bios
+    .bios-description = Amount of bios to bet each round
+    .decks = decks
+    .decks-description = Amount of decks in the shoe
+
+hit = Hit
+stand = Stand
+split = Split
+insurance = Insurance
+
+blackjack-dealer =
+    Dealer: { $cards } ({ $value })
+
+blackjack-hand =
+    { $user }: { $cards } ({ $value }) · { $amount } bios
+
+blackjack-insured =
+    { $user } insured for { $amount } bios
+
+blackjack-natural =
+    { $user } got blackjack, pays 3:2
+
+blackjack-win =
+    { $user } beats the dealer
+
+blackjack-draw =
+    { $user } ties with the dealer
+
+blackjack-bust =
+    { $user } busted
+
+blackjack-lose =
+    { $user } loses against the dealer
+
+blackjack-push =
+    Nobody wins, your bios are returned
+
+blackjack-no-bios =
+    You don't have enough bios for that
+
+blackjack-turn =
+    { $user } is playing
+
+blackjack-seat-won =
+    { $user } won { $amount } bios
+
+blackjack-seat-lost =
+    { $user } lost { $amount } bios
+
+blackjack-seat-push =
+    { $user } gets the bios back
+
+blackjack-lobby =
+    Blackjack table with { $decks } decks, { $seats }/{ $max } seats taken
+    Join with your own bet before the host starts
+
+blackjack-lobby-seat =
+    { $user } · { $amount } bios
+
+blackjack-full =
+    The table is full
+
+blackjack-reshuffle =
+    The cut card came out, the shoe was reshuffled
+
+blackjack-next-round = Next round
+
+blackjack-left =
+    { $user } left the table
+
+blackjack-closed =
+    Everyone left, the table is closed
+
+join = Join
+start = Start
+leave = Leave
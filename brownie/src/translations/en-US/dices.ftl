@@ -11,3 +11,19 @@ evens = evens
 
 dices-result =
     The dices rolled { $result } on { $number }
+
+dices-table =
+    Pick pair, unpair or the exact sum before the dices are thrown
+    Parity pays 1 to 1, an exact number pays 5 to 1
+
+dices-player =
+    { $user }: { $choice } ({ $amount } bios)
+
+dices-choice-field =
+    Pair, unpair or a number from 2 to 12
+
+dices-won =
+    { $user } wins { $amount } bios
+
+dices-lost =
+    { $user } loses { $amount } bios
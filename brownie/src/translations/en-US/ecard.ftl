@@ -0,0 +1,45 @@
+ecard = ecard
+    .description = Play E-Card against another user
+    .user = user
+    .user-description = Rival user of the bet
+    .bios = bios
+    .bios-description = Amount of bios to bet
+
+emperor = Emperor
+citizen = Citizen
+slave = Slave
+
+emperor-side = Emperor side
+slave-side = Slave side
+
+ecard-start =
+    Both players pick a card in secret
+
+ecard-your-cards =
+    You play the { $side }, pick a card
+
+ecard-selected =
+    { $user } has picked a card
+
+ecard-played =
+    { $user } played { $card }
+
+ecard-draw =
+    Citizen against citizen, both cards are discarded
+
+ecard-round-win =
+    { $user } wins the round with the { $side }
+
+ecard-swap =
+    Sides are swapped
+
+ecard-score =
+    Round { $round } of { $rounds }
+    { $emperor } (emperor): { $ePoints } points
+    { $slave } (slave): { $sPoints } points
+
+ecard-end =
+    { $winner } wins the game and { $amount } bios
+
+ecard-end-draw =
+    The game ends in a draw, no bios are lost
@@ -29,3 +29,9 @@ timeout-vs =
 
 timeout-solo =
     Perdiste por no responder a tiempo
+
+unknown-error =
+    Something went wrong, try again later
+
+transaction-error =
+    The transaction failed, nothing was charged
@@ -0,0 +1,67 @@
+guildshop = guildshop
+    .description = Shop with the items of this server
+
+guildshop-view = view
+    .description = See what this server sells
+
+guildshop-buy = buy
+    .description = Buy an item of this server
+    .item = item
+    .item-description = Id of the item to buy
+    .amount = amount
+    .amount-description = How many to buy, 1 by default
+
+guildshop-create = create
+    .description = Create an item for this server
+    .name = name
+    .name-description = Name of the item
+    .details = details
+    .details-description = What the item is about
+    .role = role
+    .role-description = Role given to whoever buys it
+
+guildshop-delete = delete
+    .description = Delete an item of this server
+    .name = name
+    .name-description = Name of the item
+
+guildshop-stock = stock
+    .description = Put an item for sale or change its price
+    .name = name
+    .name-description = Name of the item
+    .price = price
+    .price-description = Price in bios
+    .stock = stock
+    .stock-description = How many can be bought, unlimited by default
+
+guildshop-unstock = unstock
+    .description = Take an item out of the shop
+    .name = name
+    .name-description = Name of the item
+
+guildshop-empty =
+    This server isn't selling anything yet
+
+guildshop-role-reward =
+    Gives the { $role } role
+
+guildshop-role-given =
+    You got the { $role } role
+
+guildshop-role-failed =
+    The role couldn't be given, ask an admin
+
+guildshop-item-exists =
+    There's already an item with that name
+
+guildshop-created =
+    Created { $item }
+
+guildshop-deleted =
+    Deleted { $item }
+
+guildshop-stocked =
+    { $item } is for sale
+
+guildshop-unstocked =
+    { $item } is no longer for sale
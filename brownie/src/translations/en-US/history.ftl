@@ -0,0 +1,25 @@
+history = history
+    .description = See the latest games of someone
+    .user = user
+    .user-description = User to see the games of
+
+history-empty =
+    { $user } hasn't played any game yet
+
+history-summary =
+    Played { $played }, won { $won } ({ $rate }%), net { $net } bios
+
+history-won =
+    won { $bios } bios
+
+history-lost =
+    lost { $bios } bios
+
+history-push =
+    got the bet back
+
+history-versus =
+    { $result } against { $rival }, { $since }
+
+history-solo =
+    { $result } against the house, { $since }
@@ -0,0 +1,62 @@
+leaderboard = leaderboard
+    .description = See who is on top
+
+leaderboard-global = global
+    .description = Richest members and the ones with more points
+
+leaderboard-week = week
+    .description = Your league of this week
+
+leaderboard-month = month
+    .description = Special balance won in leagues this month
+
+leaderboard-money =
+    Bios
+
+leaderboard-points =
+    Points
+
+leaderboard-bios-line =
+    { $rank }. { $user } · { $bios } bios
+
+leaderboard-points-line =
+    { $rank }. { $user } · { $points } points
+
+leaderboard-no-group =
+    You aren't in a league yet, play or work to join one
+
+leaderboard-week-title =
+    { $tier } league
+
+leaderboard-week-end =
+    Closes { $end }, the first ones move up and the last ones move down
+
+leaderboard-week-line =
+    { $arrow } { $rank }. { $user } · { $points } points, { $bios } bios
+
+leaderboard-rewards =
+    Rewards
+
+leaderboard-reward-line =
+    { $rank }. { $bios } bios and { $special } special
+
+leaderboard-month-title =
+    Special balance this month
+
+leaderboard-month-empty =
+    Nobody has won special balance this month
+
+leaderboard-special-line =
+    { $rank }. { $user } · { $special } special
+
+leaderboard-month-own =
+    Yours
+
+leaderboard-special =
+    { $special } special
+
+league-tier-0 = Bronze
+league-tier-1 = Silver
+league-tier-2 = Gold
+league-tier-3 = Platinum
+league-tier-4 = Diamond
@@ -0,0 +1,60 @@
+market = market
+    .description = Buy and sell items with other members
+
+market-sell = sell
+    .description = Put items of your inventory on sale
+    .item = item
+    .item-description = Item to sell
+    .amount = amount
+    .amount-description = How many to sell
+    .price = price
+    .price-description = Bios for all of them
+
+market-view = view
+    .description = See what's on sale
+    .item = item
+    .item-description = Only show this item
+    .user = user
+    .user-description = Only show what this user sells
+
+market-buy = buy
+    .description = Buy a listing
+    .id = id
+    .id-description = Number of the listing
+
+market-cancel = cancel
+    .description = Take your listing off the market
+    .id = id
+    .id-description = Number of the listing
+
+market-tax = tax
+    .description = See or change the tax of every sale
+    .percent = percent
+    .percent-description = New tax
+
+market-not-enough-items =
+    You don't have that many
+
+market-listed =
+    Listing #{ $id }: { $amount } { $item } for { $price } bios, it expires in { $hours } hours
+
+market-empty =
+    There's nothing on sale
+
+market-listing =
+    { $price } bios, sold by { $seller }, expires { $expires }
+
+market-not-found =
+    That listing isn't on sale
+
+market-own-listing =
+    You can't buy your own listing
+
+market-bought =
+    You bought { $amount } { $item } for { $price } bios from { $seller }, { $tax } bios went to taxes
+
+market-cancelled =
+    { $amount } { $item } are back in your inventory
+
+market-tax-info =
+    The market tax is { $percent }%, { $collected } bios collected so far
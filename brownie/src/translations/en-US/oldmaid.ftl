@@ -0,0 +1,53 @@
+oldmaid = oldmaid
+    .description = Play old maid with up to four players
+    .bios = bios
+    .bios-description = Amount of bios the loser pays to every player
+
+draw-card = Draw a card
+confirm = Confirm
+
+oldmaid-lobby =
+    Old maid, the one left with the joker pays { $amount } bios to every player
+    Players { $players }/{ $max }
+
+oldmaid-full =
+    The table is full
+
+oldmaid-no-bios =
+    You don't have enough bios to pay every player if you lose
+
+oldmaid-hold-failed =
+    Someone no longer has the bios to cover the bet, the game was cancelled
+
+oldmaid-not-enough =
+    At least two players are needed
+
+oldmaid-cancelled =
+    Not enough players joined, the game was cancelled
+
+oldmaid-table =
+    Discarded pairs: { $pairs }
+
+oldmaid-player =
+    { $user }: { $cards } cards
+
+oldmaid-out =
+    { $user }: out of cards
+
+oldmaid-turn =
+    { $user } draws a card from { $rival }
+
+oldmaid-your-hand =
+    Your hand: { $cards }
+
+oldmaid-pick =
+    Pick one of { $user }'s cards and confirm it
+
+oldmaid-drawn =
+    You drew { $card }
+
+oldmaid-end =
+    { $user } is left with the joker and pays { $amount } bios
+
+oldmaid-timeout =
+    { $user } didn't draw in time and pays { $amount } bios
@@ -0,0 +1,25 @@
+quests = quests
+    .description = See today's quests
+
+quests-header =
+    Today's quests, { $streak } days of streak (+{ $bonus }% rewards)
+
+quests-line =
+    **{ $quest }**
+    { $status } | { $points } points, { $bios } bios
+
+quests-done = Completed
+
+quests-empty =
+    There are no quests today
+
+quest-completed =
+    { $user } completed **{ $quest }** and got { $points } points and { $bios } bios
+
+quest-play-games = Play { $steps } games
+quest-play-blackjack = Play { $steps } games of blackjack
+quest-play-roulette = Play { $steps } games of roulette
+quest-play-dices = Play { $steps } games of dices
+quest-work-shifts = Work { $steps } shifts
+quest-bet-bios = Bet { $steps } bios
+quest-eat-food = Eat { $steps } times
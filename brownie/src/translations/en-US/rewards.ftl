@@ -0,0 +1,82 @@
+daily = daily
+    .description = Claim your daily reward
+
+daily-claimed =
+    You claimed your daily reward:
+
+daily-already-claimed =
+    You already claimed your daily reward
+
+daily-bios =
+    { $amount } Bios
+
+daily-points =
+    { $amount } Points
+
+daily-bonus =
+    +{ $percent }% bonus
+
+daily-streak-bonus =
+    +{ $percent }% for your streak
+
+daily-streak = Streak
+
+daily-streak-days =
+    { $days } days in a row
+
+daily-streak-saved =
+    A { $item } kept your streak alive
+
+daily-milestone = Next milestone
+
+daily-next-milestone =
+    +{ $percent }% in { $days } days
+
+daily-max-milestone =
+    You reached the highest milestone
+
+rewards = rewards
+    .description = Configure the daily rewards
+
+rewards-view = view
+    .description = See the reward of every weekday and the streak milestones
+
+rewards-set = set
+    .description = Change the reward of a weekday
+    .day = day
+    .day-description = Day of the week, 1 is monday
+    .bios = bios
+    .bios-description = Bios given
+    .points = points
+    .points-description = Points given
+    .item = item
+    .item-description = Item given
+    .amount = amount
+    .amount-description = How many of the item
+
+rewards-milestone = milestone
+    .description = Add, change or remove (0%) a streak milestone
+    .streak = streak
+    .streak-description = Days of streak needed
+    .percent = percent
+    .percent-description = Extra bios and points
+
+rewards-day =
+    { $bios } bios, { $points } points
+
+rewards-milestone-line =
+    { $days } days: +{ $percent }%
+
+rewards-updated =
+    The reward of { $day } was updated
+
+rewards-unknown-item =
+    That item doesn't exist
+
+monday = Monday
+tuesday = Tuesday
+wednesday = Wednesday
+thursday = Thursday
+friday = Friday
+saturday = Saturday
+sunday = Sunday
@@ -0,0 +1,39 @@
+rooms = rooms
+    .description = Manage the games running in this server
+
+rooms-list = list
+    .description = List the games running in this server
+
+rooms-abort = abort
+    .description = Stop a running game without charging anyone
+    .kind = kind
+    .kind-description = Kind of game to stop
+    .channel = channel
+    .channel-description = Channel of the game, this one by default
+
+Nim = Nim
+AirPoker = Air poker
+
+room-taken =
+    There's already a game of this kind running in this channel
+
+room-expired =
+    This game ended when the bot restarted
+
+room-aborted =
+    This game was stopped by a moderator
+
+rooms-empty =
+    There are no games running
+
+rooms-title =
+    Games running: { $rooms }
+
+rooms-entry =
+    { $kind } in { $channel }, hosted by { $host } with { $players } players, { $since }
+
+rooms-not-found =
+    There's no game of that kind in that channel
+
+rooms-aborted =
+    The { $kind } game in { $channel } was stopped
@@ -0,0 +1,30 @@
+roulette = roulette
+    .description = Open a european roulette table
+
+spin = Spin
+
+green = green
+red = red
+black = black
+
+roulette-table =
+    Place your chips before the wheel spins
+    Bets: number, split 17 18, street 4, corner 1, sixline 1, dozen 2, column 3, red, black, odd, even, low, high
+
+roulette-bet-field =
+    Where do you bet
+
+roulette-no-bets =
+    Nobody placed a bet, the table is closed
+
+roulette-result =
+    The ball lands on { $number } { $color }
+
+roulette-won =
+    { $user } wins { $amount } bios
+
+roulette-lost =
+    { $user } loses { $amount } bios
+
+roulette-even =
+    { $user } breaks even
@@ -0,0 +1,340 @@
+rpg = rpg
+    .description = Play the season of the rpg
+
+rpg-join = join
+    .description = Join the running season
+
+rpg-profile = profile
+    .description = See someone in the running season
+    .user = user
+    .user-description = User to see
+
+rpg-results = results
+    .description = Standings of the running season or the last one
+
+season = season
+    .description = Manage the seasons of the rpg
+
+season-start = start
+    .description = Start a new season
+
+season-nightmare = nightmare
+    .description = Move the running season into its nightmare phase
+
+season-end = end
+    .description = End the running season and archive its results
+
+season-none =
+    There is no season running
+
+season-already-running =
+    A season is already running, end it first
+
+season-already-nightmare =
+    The season is already in its nightmare phase
+
+season-started =
+    Season { $id } has started, join it with /rpg join
+
+season-nightmare-started =
+    Season { $id } entered the nightmare, nobody else can join. Experience { $experience }%, loot { $loot }%, enemies { $power }%
+
+season-ended =
+    Season { $id } ended with { $players } players
+
+season-result-line =
+    { $rank }. { $user } · level { $level }
+
+rpg-join-closed =
+    The season is closed to new players
+
+rpg-already-joined =
+    You are already playing this season
+
+rpg-not-joined =
+    Not playing this season, join with /rpg join
+
+rpg-joined =
+    You joined season { $id }
+
+rpg-profile-title =
+    { $user } · season { $id }
+
+rpg-profile-level =
+    Level { $level }, { $experience }/{ $needed } experience
+
+rpg-outlaw =
+    Outlaw
+
+rpg-phase =
+    Phase
+
+rpg-phase-active =
+    Active
+
+rpg-phase-nightmare =
+    Nightmare
+
+rpg-balance =
+    Balance
+
+rpg-roles =
+    Roles
+
+rpg-no-roles =
+    None
+
+rpg-role-knight =
+    Knight
+
+rpg-role-king =
+    King
+
+rpg-role-coordinator =
+    Coordinator
+
+rpg-results-current =
+    Season { $id }
+
+rpg-results-last =
+    Season { $id }, ended
+
+rpg-equip = equip
+    .description = Wear an item of your season inventory
+    .item = item
+    .item-description = Item to wear
+    .slot = slot
+    .slot-description = Ring or hand to use
+
+rpg-unequip = unequip
+    .description = Take off what is in a slot
+    .slot = slot
+    .slot-description = Slot to empty
+
+rpg-equipment = equipment
+    .description = See what someone wears
+    .user = user
+    .user-description = User to see
+
+equip-not-owned =
+    You don't have that item
+
+equip-not-equippable =
+    That item can't be worn
+
+equip-wrong-slot =
+    That item doesn't go in that slot
+
+equip-empty-slot =
+    There is nothing in that slot
+
+equip-done =
+    You are wearing { $item }
+
+unequip-done =
+    You took off { $item }
+
+equipment-title =
+    Equipment of { $user }
+
+equipment-stats-title =
+    Stats
+
+equipment-stats =
+    Damage { $damage }, defense { $defense }, accuracy { $accuracy }%, life { $life }
+
+slot-head = Head
+slot-chest = Chest
+slot-legs = Legs
+slot-boots = Boots
+slot-first-ring = First ring
+slot-second-ring = Second ring
+slot-neck = Neck
+slot-first-hand = Main hand
+slot-second-hand = Off hand
+
+craft = craft
+    .description = Make an item from its recipe, or see the recipes
+    .item = item
+    .item-description = Item to make
+
+craft-ready =
+    { $item } · ready
+
+craft-missing =
+    { $item }
+
+craft-component =
+    { $item }: { $owned }/{ $amount }
+
+craft-unknown =
+    There is no recipe for that item
+
+craft-not-enough =
+    You don't have every component of the recipe
+
+craft-done =
+    You made { $item } of { $quality } quality
+
+craft-level-up =
+    Your crafting skill went up { $levels } levels
+
+quality-common = common
+quality-normal = normal
+quality-epic = epic
+quality-masterpiece = masterpiece
+
+rpg-skills = skills
+    .description = See your skills and the points put in them
+
+rpg-allocate = allocate
+    .description = Spend points of a skill in one of its stats
+    .skill = skill
+    .skill-description = Skill the points come from
+    .stat = stat
+    .stat-description = Stat to raise
+    .points = points
+    .points-description = How many points, one by default
+
+rpg-reset = reset
+    .description = Take back every point spent in a skill
+    .skill = skill
+    .skill-description = Skill to reset
+
+skill-combat = Combat
+skill-looting = Looting
+skill-crafting = Crafting
+
+stat-accuracy = Accuracy
+stat-damage = Damage
+stat-max-life = Life
+stat-defense = Defense
+stat-quantity = Quantity
+stat-quality = Quality
+
+skill-level =
+    Level { $level }/{ $max }, { $experience }/{ $needed } experience
+
+skill-available =
+    { $points } points to spend
+
+skill-stat-line =
+    { $stat }: { $points } points (+{ $value })
+
+skill-effective =
+    Damage { $damage }, defense { $defense }, accuracy { $accuracy }%, life { $life }
+    Loot quantity +{ $quantity }%, loot quality +{ $quality }
+
+skill-unknown =
+    That skill doesn't exist
+
+skill-wrong-stat =
+    That skill can't raise that stat
+
+skill-invalid-amount =
+    The points have to be more than zero
+
+skill-not-enough-points =
+    You don't have that many points to spend in that skill
+
+skill-nothing-to-reset =
+    There are no points spent in that skill
+
+skill-allocated =
+    You put { $points } points in { $stat }
+
+skill-reset-done =
+    You got { $points } points back
+
+fight = fight
+    .description = Fight a monster of the season, a random one without picking it
+    .monster = monster
+    .monster-description = Monster to fight
+
+fight-level = level { $level }
+
+fight-title =
+    { $monster } · level { $level }
+
+fight-start =
+    A { $monster } blocks your way
+
+fight-attack = Attack
+fight-heavy = Heavy attack
+fight-defend = Defend
+fight-flee = Flee
+
+fight-hit =
+    { $attacker } hits { $target } for { $damage }
+
+fight-critical =
+    { $attacker } lands a critical hit on { $target } for { $damage }
+
+fight-miss =
+    { $attacker } misses
+
+fight-guard =
+    { $fighter } raises the guard
+
+fight-afflicted =
+    { $target } suffers { $status }
+
+fight-tick =
+    { $target } loses { $damage } life to { $status }
+
+fight-stunned =
+    { $target } is stunned and can't act
+
+fight-fled =
+    You ran away
+
+fight-flee-failed =
+    You couldn't get away
+
+fight-timeout =
+    You took too long and ran away
+
+fight-won =
+    You defeated the { $monster }
+
+fight-lost =
+    The { $monster } defeated you
+
+fight-status-poison = poison
+fight-status-stun = stun
+fight-status-weaken = weakness
+
+fight-status-turns =
+    { $status } ({ $turns })
+
+fight-rewards = Rewards
+
+fight-experience =
+    +{ $experience } experience
+
+fight-level-up =
+    You went up { $levels } levels
+
+fight-skill-up =
+    { $skill } went up { $levels } levels
+
+fight-loot =
+    { $amount } { $item }
+
+fight-seed =
+    Seed { $seed }
+
+fight-unknown =
+    That monster doesn't exist
+
+fight-too-strong =
+    That monster is too strong for your level
+
+fight-no-monsters =
+    There are no monsters you can fight
+
+monster-slime = Slime
+monster-wolf = Wolf
+monster-goblin = Goblin
+monster-giant-spider = Giant spider
+monster-troll = Troll
@@ -0,0 +1,59 @@
+rr = rr
+    .description = Play russian roulette with up to six players
+    .bios = bios
+    .bios-description = Amount of bios every player bets
+    .chambers = chambers
+    .chambers-description = Amount of chambers in the revolver
+    .bullets = bullets
+    .bullets-description = Amount of bullets loaded
+    .rule = rule
+    .rule-description = Spin the cylinder before every pull or only once
+
+Spin = Spin
+NoSpin = No spin
+
+pull-trigger = Pull the trigger
+
+rr-lobby =
+    Russian roulette, { $amount } bios each
+    Revolver with { $chambers } chambers and { $bullets } bullets
+    Players { $players }/{ $max }
+
+rr-full =
+    The game is full
+
+rr-no-bios =
+    You don't have enough bios to join
+
+rr-not-enough =
+    At least two players are needed
+
+rr-too-many-bullets =
+    There must be fewer bullets than players
+
+rr-cancelled =
+    Not enough players joined, the game was cancelled
+
+rr-table =
+    Pot: { $pot } bios, bullets left: { $bullets }
+
+rr-alive =
+    { $user }
+
+rr-dead =
+    ~~{ $user }~~ 💀
+
+rr-turn =
+    { $user } holds the revolver
+
+rr-click =
+    { $user } pulls the trigger... *click*
+
+rr-bang =
+    { $user } pulls the trigger... **BANG**
+
+rr-won =
+    { $user } survives and wins { $amount } bios
+
+rr-lost =
+    { $user } loses { $amount } bios
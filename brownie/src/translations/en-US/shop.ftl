@@ -0,0 +1,30 @@
+shop = shop
+    .description = Buy items with your bios
+
+shop-view = view
+    .description = See what's for sale
+
+shop-buy = buy
+    .description = Buy an item from the shop
+    .item = item
+    .item-description = Id of the item to buy
+    .amount = amount
+    .amount-description = How many to buy, 1 by default
+
+product-info =
+    Id { $id }, { $price } bios each
+
+product-info-stock =
+    Id { $id }, { $price } bios each, { $stock } left
+
+shop-not-found =
+    That item isn't for sale
+
+shop-invalid-amount =
+    You have to buy at least one
+
+shop-out-of-stock =
+    There isn't enough stock left
+
+shop-bought =
+    You bought { $amount } { $item } for { $bios } bios
@@ -0,0 +1,24 @@
+stats = stats
+    .description = See how the bot is being used
+
+stats-commands = commands
+    .description = Most used commands, most active members and uses per day
+    .user = user
+    .user-description = Only show the commands of this user
+    .days = days
+    .days-description = Days to look back, 7 by default
+
+stats-top =
+    Top commands, last { $days } days
+
+stats-members =
+    Most active members, last { $days } days
+
+stats-daily =
+    Commands per day, last { $days } days
+
+stats-member =
+    Commands of { $user }, last { $days } days
+
+stats-empty =
+    No commands were used in that time
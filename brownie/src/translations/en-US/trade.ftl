@@ -0,0 +1,63 @@
+trade = trade
+    .description = Swap bios and items with another member
+    .user = user
+    .user-description = Member to trade with
+
+trade-self =
+    You can't trade with yourself or a bot
+
+trade-request =
+    { $author } wants to trade with { $user }
+
+trade-declined =
+    { $user } declined the trade
+
+trade-expired =
+    The trade expired
+
+trade-window =
+    Both members have to confirm, changing an offer asks for confirmation again
+
+trade-offer-bios =
+    { $amount } bios
+
+trade-offer-empty =
+    Nothing
+
+trade-confirmed = (confirmed)
+trade-waiting = (not confirmed)
+
+trade-set-bios = Bios
+trade-set-item = Items
+trade-confirm = Confirm
+trade-cancel = Cancel
+
+trade-bios-title =
+    Bios to offer
+
+trade-bios-question =
+    How many bios
+
+trade-choose-item =
+    Item to offer
+
+trade-no-items =
+    You don't have any items
+
+trade-item-question =
+    How many, 0 takes it off ({ $owned } owned)
+
+trade-invalid-amount =
+    You don't have that many
+
+trade-empty =
+    Nobody offered anything yet
+
+trade-cancelled =
+    The trade was cancelled
+
+trade-done =
+    The trade is done
+
+trade-failed =
+    Someone no longer has what they offered, the trade was cancelled
@@ -0,0 +1,20 @@
+use_item = use
+    .description = Use an item of your inventory
+    .item = item
+    .item-description = Item to use
+
+buff-WorkCooldown = shorter work cooldown
+buff-BetInsurance = insurance on your next lost bet
+buff-DailyBonus = bigger next daily reward
+
+use-not-owned =
+    You don't have that item
+
+use-not-usable =
+    { $item } can't be used
+
+use-buff =
+    You used { $item }: { $buff } ({ $percent }%) for { $time }
+
+use-points =
+    You used { $item } and got { $points } points
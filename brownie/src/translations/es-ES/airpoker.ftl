@@ -0,0 +1,60 @@
+airpoker = airpoker
+    .description = apuesta tu aire en una partida de poker a cinco rondas
+    .user = usuario
+    .user-description = usuario contra el que jugar
+    .bios = bios
+    .bios-description = cantidad de bios a apostar
+
+call = Igualar
+raise = Subir
+fold = Retirarse
+air-hand = Tus cartas
+
+air-expired =
+    nadie aceptó la partida de air poker
+
+air-table =
+    ronda { $round }/{ $rounds }, ciega { $blind }
+
+air-player =
+    { $user }: { $tanks } tanques, { $air }s de aire restante, apuesta { $bet }
+
+air-selecting =
+    esperando a que { $users } elija una carta
+
+air-betting =
+    { $user } debe igualar, subir o retirarse
+
+air-choose-card =
+    elige la carta que jugarás esta ronda
+
+your-cards =
+    tus cartas: { $cards }
+
+inform-all-in =
+    no tienes tanques suficientes para subir, solo puedes igualar
+
+air-modal-title = Subir
+air-modal-question = ¿cuántos tanques apuestas?
+air-modal-placeholder = hasta { $bios } tanques
+
+air-fold =
+    la mano se retiró, { $user } se lleva { $tanks } tanques
+
+air-win =
+    { $user } gana la mano y se lleva { $tanks } tanques
+
+air-showdown =
+    { $user }: { $value } { $cards }
+
+air-draw =
+    ambas manos son iguales, nadie se lleva tanques
+
+air-end =
+    { $winner } termina con más aire y gana { $amount } bios
+
+air-dead =
+    { $loser } se quedó sin aire, { $winner } gana { $amount } bios
+
+air-end-draw =
+    ambos terminan con el mismo aire, nadie gana
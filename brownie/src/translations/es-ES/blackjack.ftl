@@ -0,0 +1,84 @@
+blackjack = blackjack
+    .description = juega blackjack contra el crupier
+    .bios = bios
+    .bios-description = apuesta de bios
+
+bjtable = bjtable
+    .description = abre una mesa de blackjack de hasta cinco jugadores
+    .bios = bios
+    .bios-description = apuesta de bios por ronda
+    .decks = mazos
+    .decks-description = cantidad de mazos en el zapato
+
+hit = Pedir
+stand = Plantarse
+double-down = Doblar
+split = Dividir
+insurance = Seguro
+
+blackjack-dealer =
+    Crupier: { $cards } ({ $value })
+
+blackjack-hand =
+    { $user }: { $cards } ({ $value }) · { $amount } bios
+
+blackjack-insured =
+    { $user } aseguró { $amount } bios
+
+blackjack-natural =
+    ¡{ $user } tiene blackjack! paga 3:2
+
+blackjack-win =
+    { $user } le gana al crupier
+
+blackjack-draw =
+    { $user } empata con el crupier
+
+blackjack-bust =
+    { $user } se pasó de 21
+
+blackjack-lose =
+    { $user } pierde contra el crupier
+
+blackjack-push =
+    nadie gana, recuperas tus bios
+
+blackjack-no-bios =
+    no tienes suficientes bios para eso
+
+blackjack-turn =
+    es el turno de { $user }
+
+blackjack-seat-won =
+    { $user } ganó { $amount } bios
+
+blackjack-seat-lost =
+    { $user } perdió { $amount } bios
+
+blackjack-seat-push =
+    { $user } recupera sus bios
+
+blackjack-lobby =
+    mesa de blackjack con { $decks } mazos, { $seats }/{ $max } asientos ocupados
+    únete con tu propia apuesta antes de que empiece
+
+blackjack-lobby-seat =
+    { $user } · { $amount } bios
+
+blackjack-full =
+    la mesa está llena
+
+blackjack-reshuffle =
+    salió la carta de corte, se barajó el zapato
+
+blackjack-next-round = Siguiente ronda
+
+blackjack-left =
+    { $user } dejó la mesa
+
+blackjack-closed =
+    todos se fueron, la mesa está cerrada
+
+join = Unirse
+start = Empezar
+leave = Salir
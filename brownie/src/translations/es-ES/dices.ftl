@@ -10,4 +10,22 @@ odds = impares
 evens = pares
 
 dices-result =
-    los dados cayeron en { $result }
+    los dados cayeron en { $number }, { $result }
+
+throw-dices = Tirar dados
+
+dices-table =
+    elige par, impar o la suma exacta antes de tirar los dados
+    la paridad paga 1 a 1, un número exacto paga 5 a 1
+
+dices-player =
+    { $user }: { $choice } ({ $amount } bios)
+
+dices-choice-field =
+    par, impar o un número del 2 al 12
+
+dices-won =
+    ¡{ $user } gana { $amount } bios!
+
+dices-lost =
+    { $user } pierde { $amount } bios
@@ -0,0 +1,45 @@
+ecard = ecard
+    .description = juega e-card contra otro usuario
+    .user = usuario
+    .user-description = usuario rival de la apuesta
+    .bios = bios
+    .bios-description = apuesta de bios
+
+emperor = Emperador
+citizen = Ciudadano
+slave = Esclavo
+
+emperor-side = lado del emperador
+slave-side = lado del esclavo
+
+ecard-start =
+    ambos jugadores eligen una carta en secreto
+
+ecard-your-cards =
+    juegas en el { $side }, elige una carta
+
+ecard-selected =
+    { $user } ya eligió una carta
+
+ecard-played =
+    { $user } jugó { $card }
+
+ecard-draw =
+    ciudadano contra ciudadano, ambas cartas se descartan
+
+ecard-round-win =
+    { $user } gana la ronda en el { $side }
+
+ecard-swap =
+    los lados se intercambian
+
+ecard-score =
+    ronda { $round } de { $rounds }
+    { $emperor } (emperador): { $ePoints } puntos
+    { $slave } (esclavo): { $sPoints } puntos
+
+ecard-end =
+    ¡{ $winner } gana la partida y { $amount } bios!
+
+ecard-end-draw =
+    la partida termina en empate, nadie pierde bios
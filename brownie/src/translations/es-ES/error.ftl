@@ -62,3 +62,9 @@ timeout-solo =
     Perdiste la apuesta por no responder a tiempo
 
 
+
+unknown-error =
+    Algo salió mal, inténtalo más tarde
+
+transaction-error =
+    La transacción falló, no se cobró nada
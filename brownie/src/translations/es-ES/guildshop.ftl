@@ -0,0 +1,67 @@
+guildshop = tiendaservidor
+    .description = Tienda con los objetos de este servidor
+
+guildshop-view = ver
+    .description = Mira lo que vende este servidor
+
+guildshop-buy = comprar
+    .description = Compra un objeto de este servidor
+    .item = objeto
+    .item-description = Id del objeto a comprar
+    .amount = cantidad
+    .amount-description = Cuántos comprar, 1 por defecto
+
+guildshop-create = crear
+    .description = Crea un objeto para este servidor
+    .name = nombre
+    .name-description = Nombre del objeto
+    .details = detalles
+    .details-description = De qué trata el objeto
+    .role = rol
+    .role-description = Rol que recibe quien lo compre
+
+guildshop-delete = borrar
+    .description = Borra un objeto de este servidor
+    .name = nombre
+    .name-description = Nombre del objeto
+
+guildshop-stock = vender
+    .description = Pon un objeto a la venta o cambia su precio
+    .name = nombre
+    .name-description = Nombre del objeto
+    .price = precio
+    .price-description = Precio en bios
+    .stock = stock
+    .stock-description = Cuántos se pueden comprar, ilimitado por defecto
+
+guildshop-unstock = retirar
+    .description = Quita un objeto de la tienda
+    .name = nombre
+    .name-description = Nombre del objeto
+
+guildshop-empty =
+    Este servidor todavía no vende nada
+
+guildshop-role-reward =
+    Da el rol { $role }
+
+guildshop-role-given =
+    Recibiste el rol { $role }
+
+guildshop-role-failed =
+    No se pudo dar el rol, avisa a un admin
+
+guildshop-item-exists =
+    Ya hay un objeto con ese nombre
+
+guildshop-created =
+    Creaste { $item }
+
+guildshop-deleted =
+    Borraste { $item }
+
+guildshop-stocked =
+    { $item } está a la venta
+
+guildshop-unstocked =
+    { $item } ya no está a la venta
@@ -0,0 +1,25 @@
+history = historial
+    .description = Mira las últimas partidas de alguien
+    .user = usuario
+    .user-description = Usuario para ver sus partidas
+
+history-empty =
+    { $user } todavía no ha jugado ninguna partida
+
+history-summary =
+    Jugadas { $played }, ganadas { $won } ({ $rate }%), neto { $net } bios
+
+history-won =
+    ganó { $bios } bios
+
+history-lost =
+    perdió { $bios } bios
+
+history-push =
+    recuperó la apuesta
+
+history-versus =
+    { $result } contra { $rival }, { $since }
+
+history-solo =
+    { $result } contra la casa, { $since }
@@ -0,0 +1,62 @@
+leaderboard = clasificacion
+    .description = Mira quién está arriba
+
+leaderboard-global = global
+    .description = Los miembros más ricos y los que tienen más puntos
+
+leaderboard-week = semana
+    .description = Tu liga de esta semana
+
+leaderboard-month = mes
+    .description = Saldo especial ganado en ligas este mes
+
+leaderboard-money =
+    Bios
+
+leaderboard-points =
+    Puntos
+
+leaderboard-bios-line =
+    { $rank }. { $user } · { $bios } bios
+
+leaderboard-points-line =
+    { $rank }. { $user } · { $points } puntos
+
+leaderboard-no-group =
+    Todavía no estás en una liga, juega o trabaja para entrar en una
+
+leaderboard-week-title =
+    Liga { $tier }
+
+leaderboard-week-end =
+    Cierra { $end }, los primeros suben y los últimos bajan
+
+leaderboard-week-line =
+    { $arrow } { $rank }. { $user } · { $points } puntos, { $bios } bios
+
+leaderboard-rewards =
+    Recompensas
+
+leaderboard-reward-line =
+    { $rank }. { $bios } bios y { $special } especial
+
+leaderboard-month-title =
+    Saldo especial este mes
+
+leaderboard-month-empty =
+    Nadie ha ganado saldo especial este mes
+
+leaderboard-special-line =
+    { $rank }. { $user } · { $special } especial
+
+leaderboard-month-own =
+    El tuyo
+
+leaderboard-special =
+    { $special } especial
+
+league-tier-0 = Bronce
+league-tier-1 = Plata
+league-tier-2 = Oro
+league-tier-3 = Platino
+league-tier-4 = Diamante
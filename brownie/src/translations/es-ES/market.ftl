@@ -0,0 +1,60 @@
+market = mercado
+    .description = Compra y vende objetos con otros miembros
+
+market-sell = vender
+    .description = Pon objetos de tu inventario a la venta
+    .item = objeto
+    .item-description = Objeto a vender
+    .amount = cantidad
+    .amount-description = Cuántos vender
+    .price = precio
+    .price-description = Bios por todos ellos
+
+market-view = ver
+    .description = Mira lo que está a la venta
+    .item = objeto
+    .item-description = Solo mostrar este objeto
+    .user = usuario
+    .user-description = Solo mostrar lo que vende este usuario
+
+market-buy = comprar
+    .description = Compra una publicación
+    .id = id
+    .id-description = Número de la publicación
+
+market-cancel = cancelar
+    .description = Quita tu publicación del mercado
+    .id = id
+    .id-description = Número de la publicación
+
+market-tax = impuesto
+    .description = Mira o cambia el impuesto de cada venta
+    .percent = porcentaje
+    .percent-description = Nuevo impuesto
+
+market-not-enough-items =
+    No tienes tantos
+
+market-listed =
+    Publicación #{ $id }: { $amount } { $item } por { $price } bios, expira en { $hours } horas
+
+market-empty =
+    No hay nada a la venta
+
+market-listing =
+    { $price } bios, vendido por { $seller }, expira { $expires }
+
+market-not-found =
+    Esa publicación no está a la venta
+
+market-own-listing =
+    No puedes comprar tu propia publicación
+
+market-bought =
+    Compraste { $amount } { $item } por { $price } bios a { $seller }, { $tax } bios fueron de impuestos
+
+market-cancelled =
+    { $amount } { $item } volvieron a tu inventario
+
+market-tax-info =
+    El impuesto del mercado es { $percent }%, se han recaudado { $collected } bios
@@ -0,0 +1,53 @@
+oldmaid = mona
+    .description = juega a la mona con hasta cuatro jugadores
+    .bios = bios
+    .bios-description = bios que el perdedor paga a cada jugador
+
+draw-card = Robar carta
+confirm = Confirmar
+
+oldmaid-lobby =
+    la mona, quien se quede con el joker paga { $amount } bios a cada jugador
+    jugadores { $players }/{ $max }
+
+oldmaid-full =
+    la mesa está llena
+
+oldmaid-no-bios =
+    no tienes suficientes bios para pagarle a todos si pierdes
+
+oldmaid-hold-failed =
+    alguien ya no tiene bios para cubrir la apuesta, la partida se canceló
+
+oldmaid-not-enough =
+    se necesitan al menos dos jugadores
+
+oldmaid-cancelled =
+    no se unieron suficientes jugadores, la partida se canceló
+
+oldmaid-table =
+    parejas descartadas: { $pairs }
+
+oldmaid-player =
+    { $user }: { $cards } cartas
+
+oldmaid-out =
+    { $user }: sin cartas
+
+oldmaid-turn =
+    { $user } roba una carta de { $rival }
+
+oldmaid-your-hand =
+    tu mano: { $cards }
+
+oldmaid-pick =
+    elige una de las cartas de { $user } y confírmala
+
+oldmaid-drawn =
+    robaste { $card }
+
+oldmaid-end =
+    { $user } se quedó con el joker y paga { $amount } bios
+
+oldmaid-timeout =
+    { $user } no robó a tiempo y paga { $amount } bios
@@ -0,0 +1,25 @@
+quests = misiones
+    .description = Mira las misiones de hoy
+
+quests-header =
+    Misiones de hoy, { $streak } días de racha (+{ $bonus }% de recompensas)
+
+quests-line =
+    **{ $quest }**
+    { $status } | { $points } puntos, { $bios } bios
+
+quests-done = Completada
+
+quests-empty =
+    No hay misiones hoy
+
+quest-completed =
+    { $user } completó **{ $quest }** y ganó { $points } puntos y { $bios } bios
+
+quest-play-games = Juega { $steps } partidas
+quest-play-blackjack = Juega { $steps } partidas de blackjack
+quest-play-roulette = Juega { $steps } partidas de ruleta
+quest-play-dices = Juega { $steps } partidas de dados
+quest-work-shifts = Trabaja { $steps } turnos
+quest-bet-bios = Apuesta { $steps } bios
+quest-eat-food = Come { $steps } veces
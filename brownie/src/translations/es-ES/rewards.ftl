@@ -12,3 +12,71 @@ daily-bios =
 
 daily-points =
     { $amount } Puntos
+
+daily-bonus =
+    +{ $percent }% de bonus
+
+daily-streak-bonus =
+    +{ $percent }% por tu racha
+
+daily-streak = Racha
+
+daily-streak-days =
+    { $days } días seguidos
+
+daily-streak-saved =
+    Un { $item } salvó tu racha
+
+daily-milestone = Siguiente meta
+
+daily-next-milestone =
+    +{ $percent }% en { $days } días
+
+daily-max-milestone =
+    Alcanzaste la meta más alta
+
+rewards = recompensas
+    .description = Configura las recompensas diarias
+
+rewards-view = ver
+    .description = Mira la recompensa de cada día y las metas de racha
+
+rewards-set = cambiar
+    .description = Cambia la recompensa de un día de la semana
+    .day = dia
+    .day-description = Día de la semana, 1 es lunes
+    .bios = bios
+    .bios-description = Bios que se dan
+    .points = puntos
+    .points-description = Puntos que se dan
+    .item = objeto
+    .item-description = Objeto que se da
+    .amount = cantidad
+    .amount-description = Cuántos del objeto
+
+rewards-milestone = meta
+    .description = Añade, cambia o quita (0%) una meta de racha
+    .streak = racha
+    .streak-description = Días de racha necesarios
+    .percent = porcentaje
+    .percent-description = Bios y puntos extra
+
+rewards-day =
+    { $bios } bios, { $points } puntos
+
+rewards-milestone-line =
+    { $days } días: +{ $percent }%
+
+rewards-updated =
+    Se actualizó la recompensa del { $day }
+
+rewards-unknown-item =
+    Ese objeto no existe
+
+monday = Lunes
+tuesday = Martes
+wednesday = Miércoles
+thursday = Jueves
+friday = Viernes
+saturday = Sábado
+sunday = Domingo
@@ -0,0 +1,39 @@
+rooms = salas
+    .description = administra las partidas en curso del servidor
+
+rooms-list = lista
+    .description = muestra las partidas en curso del servidor
+
+rooms-abort = detener
+    .description = detiene una partida sin cobrarle a nadie
+    .kind = tipo
+    .kind-description = tipo de partida a detener
+    .channel = canal
+    .channel-description = canal de la partida, este por defecto
+
+Nim = Nim
+AirPoker = Air poker
+
+room-taken =
+    ya hay una partida de este tipo en este canal
+
+room-expired =
+    esta partida terminó cuando el bot se reinició
+
+room-aborted =
+    un moderador detuvo esta partida
+
+rooms-empty =
+    no hay partidas en curso
+
+rooms-title =
+    partidas en curso: { $rooms }
+
+rooms-entry =
+    { $kind } en { $channel }, creada por { $host } con { $players } jugadores, { $since }
+
+rooms-not-found =
+    no hay una partida de ese tipo en ese canal
+
+rooms-aborted =
+    se detuvo la partida de { $kind } en { $channel }
@@ -0,0 +1,30 @@
+roulette = ruleta
+    .description = abre una mesa de ruleta europea
+
+spin = Girar
+
+green = verde
+red = rojo
+black = negro
+
+roulette-table =
+    pon tus fichas antes de que gire la rueda
+    apuestas: número, split 17 18, street 4, corner 1, sixline 1, dozen 2, column 3, red, black, odd, even, low, high
+
+roulette-bet-field =
+    ¿a qué apuestas?
+
+roulette-no-bets =
+    nadie apostó, la mesa se cerró
+
+roulette-result =
+    la bola cae en el { $number } { $color }
+
+roulette-won =
+    ¡{ $user } gana { $amount } bios!
+
+roulette-lost =
+    { $user } pierde { $amount } bios
+
+roulette-even =
+    { $user } queda igual
@@ -0,0 +1,340 @@
+rpg = rpg
+    .description = Juega la temporada del rpg
+
+rpg-join = unirse
+    .description = Únete a la temporada en curso
+
+rpg-profile = perfil
+    .description = Mira a alguien en la temporada en curso
+    .user = usuario
+    .user-description = Usuario a ver
+
+rpg-results = resultados
+    .description = Clasificación de la temporada en curso o de la última
+
+season = temporada
+    .description = Gestiona las temporadas del rpg
+
+season-start = empezar
+    .description = Empieza una nueva temporada
+
+season-nightmare = pesadilla
+    .description = Pasa la temporada en curso a su fase de pesadilla
+
+season-end = terminar
+    .description = Termina la temporada en curso y archiva sus resultados
+
+season-none =
+    No hay ninguna temporada en curso
+
+season-already-running =
+    Ya hay una temporada en curso, termínala primero
+
+season-already-nightmare =
+    La temporada ya está en su fase de pesadilla
+
+season-started =
+    Ha empezado la temporada { $id }, únete con /rpg unirse
+
+season-nightmare-started =
+    La temporada { $id } entró en la pesadilla, nadie más puede unirse. Experiencia { $experience }%, botín { $loot }%, enemigos { $power }%
+
+season-ended =
+    La temporada { $id } terminó con { $players } jugadores
+
+season-result-line =
+    { $rank }. { $user } · nivel { $level }
+
+rpg-join-closed =
+    La temporada está cerrada a nuevos jugadores
+
+rpg-already-joined =
+    Ya estás jugando esta temporada
+
+rpg-not-joined =
+    No juega esta temporada, únete con /rpg unirse
+
+rpg-joined =
+    Te uniste a la temporada { $id }
+
+rpg-profile-title =
+    { $user } · temporada { $id }
+
+rpg-profile-level =
+    Nivel { $level }, { $experience }/{ $needed } de experiencia
+
+rpg-outlaw =
+    Forajido
+
+rpg-phase =
+    Fase
+
+rpg-phase-active =
+    Activa
+
+rpg-phase-nightmare =
+    Pesadilla
+
+rpg-balance =
+    Saldo
+
+rpg-roles =
+    Roles
+
+rpg-no-roles =
+    Ninguno
+
+rpg-role-knight =
+    Caballero
+
+rpg-role-king =
+    Rey
+
+rpg-role-coordinator =
+    Coordinador
+
+rpg-results-current =
+    Temporada { $id }
+
+rpg-results-last =
+    Temporada { $id }, terminada
+
+rpg-equip = equipar
+    .description = Ponte un objeto de tu inventario de temporada
+    .item = objeto
+    .item-description = Objeto a ponerte
+    .slot = hueco
+    .slot-description = Anillo o mano a usar
+
+rpg-unequip = desequipar
+    .description = Quítate lo que hay en un hueco
+    .slot = hueco
+    .slot-description = Hueco a vaciar
+
+rpg-equipment = equipo
+    .description = Mira lo que lleva alguien
+    .user = usuario
+    .user-description = Usuario a ver
+
+equip-not-owned =
+    No tienes ese objeto
+
+equip-not-equippable =
+    Ese objeto no se puede llevar
+
+equip-wrong-slot =
+    Ese objeto no va en ese hueco
+
+equip-empty-slot =
+    No hay nada en ese hueco
+
+equip-done =
+    Llevas { $item }
+
+unequip-done =
+    Te quitaste { $item }
+
+equipment-title =
+    Equipo de { $user }
+
+equipment-stats-title =
+    Estadísticas
+
+equipment-stats =
+    Daño { $damage }, defensa { $defense }, precisión { $accuracy }%, vida { $life }
+
+slot-head = Cabeza
+slot-chest = Pecho
+slot-legs = Piernas
+slot-boots = Botas
+slot-first-ring = Primer anillo
+slot-second-ring = Segundo anillo
+slot-neck = Cuello
+slot-first-hand = Mano principal
+slot-second-hand = Mano secundaria
+
+craft = fabricar
+    .description = Fabrica un objeto con su receta, o mira las recetas
+    .item = objeto
+    .item-description = Objeto a fabricar
+
+craft-ready =
+    { $item } · listo
+
+craft-missing =
+    { $item }
+
+craft-component =
+    { $item }: { $owned }/{ $amount }
+
+craft-unknown =
+    No hay ninguna receta para ese objeto
+
+craft-not-enough =
+    No tienes todos los componentes de la receta
+
+craft-done =
+    Fabricaste { $item } de calidad { $quality }
+
+craft-level-up =
+    Tu habilidad de fabricación subió { $levels } niveles
+
+quality-common = común
+quality-normal = normal
+quality-epic = épica
+quality-masterpiece = obra maestra
+
+rpg-skills = habilidades
+    .description = Mira tus habilidades y los puntos que tienen
+
+rpg-allocate = asignar
+    .description = Gasta puntos de una habilidad en una de sus estadísticas
+    .skill = habilidad
+    .skill-description = Habilidad de la que salen los puntos
+    .stat = estadistica
+    .stat-description = Estadística a subir
+    .points = puntos
+    .points-description = Cuántos puntos, uno por defecto
+
+rpg-reset = reiniciar
+    .description = Recupera todos los puntos gastados en una habilidad
+    .skill = habilidad
+    .skill-description = Habilidad a reiniciar
+
+skill-combat = Combate
+skill-looting = Saqueo
+skill-crafting = Fabricación
+
+stat-accuracy = Precisión
+stat-damage = Daño
+stat-max-life = Vida
+stat-defense = Defensa
+stat-quantity = Cantidad
+stat-quality = Calidad
+
+skill-level =
+    Nivel { $level }/{ $max }, { $experience }/{ $needed } de experiencia
+
+skill-available =
+    { $points } puntos por gastar
+
+skill-stat-line =
+    { $stat }: { $points } puntos (+{ $value })
+
+skill-effective =
+    Daño { $damage }, defensa { $defense }, precisión { $accuracy }%, vida { $life }
+    Cantidad de botín +{ $quantity }%, calidad de botín +{ $quality }
+
+skill-unknown =
+    Esa habilidad no existe
+
+skill-wrong-stat =
+    Esa habilidad no puede subir esa estadística
+
+skill-invalid-amount =
+    Los puntos tienen que ser más que cero
+
+skill-not-enough-points =
+    No tienes tantos puntos por gastar en esa habilidad
+
+skill-nothing-to-reset =
+    No hay puntos gastados en esa habilidad
+
+skill-allocated =
+    Pusiste { $points } puntos en { $stat }
+
+skill-reset-done =
+    Recuperaste { $points } puntos
+
+fight = pelear
+    .description = Pelea contra un monstruo de la temporada, uno al azar si no eliges
+    .monster = monstruo
+    .monster-description = Monstruo contra el que pelear
+
+fight-level = nivel { $level }
+
+fight-title =
+    { $monster } · nivel { $level }
+
+fight-start =
+    Un { $monster } te bloquea el paso
+
+fight-attack = Atacar
+fight-heavy = Ataque fuerte
+fight-defend = Defender
+fight-flee = Huir
+
+fight-hit =
+    { $attacker } golpea a { $target } por { $damage }
+
+fight-critical =
+    { $attacker } da un golpe crítico a { $target } por { $damage }
+
+fight-miss =
+    { $attacker } falla
+
+fight-guard =
+    { $fighter } se pone en guardia
+
+fight-afflicted =
+    { $target } sufre { $status }
+
+fight-tick =
+    { $target } pierde { $damage } de vida por { $status }
+
+fight-stunned =
+    { $target } está aturdido y no puede actuar
+
+fight-fled =
+    Huiste
+
+fight-flee-failed =
+    No pudiste escapar
+
+fight-timeout =
+    Tardaste demasiado y huiste
+
+fight-won =
+    Derrotaste al { $monster }
+
+fight-lost =
+    El { $monster } te derrotó
+
+fight-status-poison = veneno
+fight-status-stun = aturdimiento
+fight-status-weaken = debilidad
+
+fight-status-turns =
+    { $status } ({ $turns })
+
+fight-rewards = Recompensas
+
+fight-experience =
+    +{ $experience } de experiencia
+
+fight-level-up =
+    Subiste { $levels } niveles
+
+fight-skill-up =
+    { $skill } subió { $levels } niveles
+
+fight-loot =
+    { $amount } { $item }
+
+fight-seed =
+    Semilla { $seed }
+
+fight-unknown =
+    Ese monstruo no existe
+
+fight-too-strong =
+    Ese monstruo es demasiado fuerte para tu nivel
+
+fight-no-monsters =
+    No hay monstruos contra los que puedas pelear
+
+monster-slime = Limo
+monster-wolf = Lobo
+monster-goblin = Goblin
+monster-giant-spider = Araña gigante
+monster-troll = Trol
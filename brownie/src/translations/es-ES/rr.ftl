@@ -0,0 +1,59 @@
+rr = rr
+    .description = juega a la ruleta rusa con hasta seis jugadores
+    .bios = bios
+    .bios-description = apuesta de bios de cada jugador
+    .chambers = recamaras
+    .chambers-description = cantidad de recámaras del revólver
+    .bullets = balas
+    .bullets-description = cantidad de balas cargadas
+    .rule = regla
+    .rule-description = girar el tambor antes de cada disparo o solo una vez
+
+Spin = Girar
+NoSpin = Sin girar
+
+pull-trigger = Apretar el gatillo
+
+rr-lobby =
+    ruleta rusa, { $amount } bios cada uno
+    revólver con { $chambers } recámaras y { $bullets } balas
+    jugadores { $players }/{ $max }
+
+rr-full =
+    la partida está llena
+
+rr-no-bios =
+    no tienes suficientes bios para unirte
+
+rr-not-enough =
+    se necesitan al menos dos jugadores
+
+rr-too-many-bullets =
+    debe haber menos balas que jugadores
+
+rr-cancelled =
+    no se unieron suficientes jugadores, la partida se canceló
+
+rr-table =
+    bote: { $pot } bios, balas restantes: { $bullets }
+
+rr-alive =
+    { $user }
+
+rr-dead =
+    ~~{ $user }~~ 💀
+
+rr-turn =
+    { $user } tiene el revólver
+
+rr-click =
+    { $user } aprieta el gatillo... *click*
+
+rr-bang =
+    { $user } aprieta el gatillo... **BANG**
+
+rr-won =
+    ¡{ $user } sobrevive y gana { $amount } bios!
+
+rr-lost =
+    { $user } pierde { $amount } bios
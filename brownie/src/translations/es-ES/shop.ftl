@@ -0,0 +1,30 @@
+shop = tienda
+    .description = Compra objetos con tus bios
+
+shop-view = ver
+    .description = Mira lo que está a la venta
+
+shop-buy = comprar
+    .description = Compra un objeto de la tienda
+    .item = objeto
+    .item-description = Id del objeto a comprar
+    .amount = cantidad
+    .amount-description = Cuántos comprar, 1 por defecto
+
+product-info =
+    Id { $id }, { $price } bios cada uno
+
+product-info-stock =
+    Id { $id }, { $price } bios cada uno, quedan { $stock }
+
+shop-not-found =
+    Ese objeto no está a la venta
+
+shop-invalid-amount =
+    Tienes que comprar al menos uno
+
+shop-out-of-stock =
+    No queda suficiente stock
+
+shop-bought =
+    Compraste { $amount } { $item } por { $bios } bios
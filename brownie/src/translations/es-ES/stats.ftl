@@ -0,0 +1,24 @@
+stats = estadisticas
+    .description = Mira cómo se está usando el bot
+
+stats-commands = comandos
+    .description = Comandos más usados, miembros más activos y usos por día
+    .user = usuario
+    .user-description = Solo mostrar los comandos de este usuario
+    .days = dias
+    .days-description = Días hacia atrás, 7 por defecto
+
+stats-top =
+    Comandos más usados, últimos { $days } días
+
+stats-members =
+    Miembros más activos, últimos { $days } días
+
+stats-daily =
+    Comandos por día, últimos { $days } días
+
+stats-member =
+    Comandos de { $user }, últimos { $days } días
+
+stats-empty =
+    No se usó ningún comando en ese tiempo
@@ -0,0 +1,63 @@
+trade = intercambiar
+    .description = Intercambia bios y objetos con otro miembro
+    .user = usuario
+    .user-description = Miembro con quien intercambiar
+
+trade-self =
+    No puedes intercambiar contigo mismo ni con un bot
+
+trade-request =
+    { $author } quiere intercambiar con { $user }
+
+trade-declined =
+    { $user } rechazó el intercambio
+
+trade-expired =
+    El intercambio expiró
+
+trade-window =
+    Ambos miembros deben confirmar, cambiar una oferta pide confirmar otra vez
+
+trade-offer-bios =
+    { $amount } bios
+
+trade-offer-empty =
+    Nada
+
+trade-confirmed = (confirmado)
+trade-waiting = (sin confirmar)
+
+trade-set-bios = Bios
+trade-set-item = Objetos
+trade-confirm = Confirmar
+trade-cancel = Cancelar
+
+trade-bios-title =
+    Bios a ofrecer
+
+trade-bios-question =
+    Cuántos bios
+
+trade-choose-item =
+    Objeto a ofrecer
+
+trade-no-items =
+    No tienes objetos
+
+trade-item-question =
+    Cuántos, 0 lo quita ({ $owned } en total)
+
+trade-invalid-amount =
+    No tienes tantos
+
+trade-empty =
+    Nadie ha ofrecido nada aún
+
+trade-cancelled =
+    El intercambio fue cancelado
+
+trade-done =
+    El intercambio se realizó
+
+trade-failed =
+    Alguien ya no tiene lo que ofreció, el intercambio fue cancelado
@@ -0,0 +1,20 @@
+use_item = usar
+    .description = Usa un objeto de tu inventario
+    .item = objeto
+    .item-description = Objeto a usar
+
+buff-WorkCooldown = menos espera para trabajar
+buff-BetInsurance = seguro en tu próxima apuesta perdida
+buff-DailyBonus = mejor próxima recompensa diaria
+
+use-not-owned =
+    No tienes ese objeto
+
+use-not-usable =
+    { $item } no se puede usar
+
+use-buff =
+    Usaste { $item }: { $buff } ({ $percent }%) durante { $time }
+
+use-points =
+    Usaste { $item } y ganaste { $points } puntos
@@ -0,0 +1,10 @@
+-- stakes of running games, a member can only be in one game at a time
+CREATE TABLE IF NOT EXISTS escrow (
+    member bigint PRIMARY KEY REFERENCES member (id) ON DELETE CASCADE,
+    room bigint NOT NULL,
+    game varchar(50) NOT NULL,
+    amount bigint NOT NULL DEFAULT 0 CHECK (amount >= 0),
+    created_at timestamp NOT NULL DEFAULT TIMEZONE('UTC', NOW())
+);
+
+CREATE INDEX escrow_room_idx ON escrow (room);
@@ -0,0 +1,13 @@
+-- the parameters shadowed the columns of gamble_items_log
+CREATE OR REPLACE FUNCTION log_gamble_item (gamble_log int, item int, amount int)
+    RETURNS VOID
+    AS $$
+BEGIN
+    INSERT INTO gamble_items_log (gamble_log, item, amount)
+        VALUES (log_gamble_item.gamble_log, log_gamble_item.item, log_gamble_item.amount)
+    ON CONFLICT ON CONSTRAINT gamble_items_log_pkey
+        DO UPDATE SET
+            amount = gamble_items_log.amount + log_gamble_item.amount;
+END;
+$$
+LANGUAGE plpgsql;
@@ -0,0 +1,22 @@
+-- one row per member, command and day so usage can be followed over time
+ALTER TABLE command_log ADD COLUMN day date NOT NULL DEFAULT (TIMEZONE('UTC', NOW()))::date;
+
+ALTER TABLE command_log DROP CONSTRAINT command_log_pkey;
+
+ALTER TABLE command_log ADD PRIMARY KEY (member, name, day);
+
+CREATE INDEX command_log_day_idx ON command_log (day);
+
+CREATE OR REPLACE FUNCTION log_command (member bigint, name varchar(255))
+    RETURNS VOID
+    AS $$
+BEGIN
+    INSERT INTO command_log (member, name, amount)
+        VALUES (log_command.member, log_command.name, 1)
+    ON CONFLICT ON CONSTRAINT command_log_pkey
+        DO UPDATE SET
+            amount = command_log.amount + 1,
+            used_at = TIMEZONE('UTC', NOW());
+END;
+$$
+LANGUAGE plpgsql;
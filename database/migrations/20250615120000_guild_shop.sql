@@ -0,0 +1,22 @@
+CREATE TABLE guild_item (
+  id SERIAL PRIMARY KEY,
+  guild BIGINT NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+  name VARCHAR(50) NOT NULL,
+  description VARCHAR(255),
+  -- discord role given to whoever buys the item
+  role BIGINT,
+  UNIQUE (guild, name)
+);
+
+CREATE TABLE guild_shop (
+  item INT PRIMARY KEY REFERENCES guild_item(id) ON DELETE CASCADE,
+  price BIGINT NOT NULL CHECK (price > 0),
+  stock INT CHECK (stock >= 0)
+);
+
+CREATE TABLE guild_inventory (
+  item INT REFERENCES guild_item(id) ON DELETE CASCADE,
+  member BIGINT REFERENCES member(id) ON DELETE CASCADE,
+  amount INT NOT NULL DEFAULT 0,
+  PRIMARY KEY (item, member)
+);
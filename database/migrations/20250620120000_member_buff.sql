@@ -0,0 +1,15 @@
+CREATE TYPE buff AS ENUM (
+    'WorkCooldown',
+    'BetInsurance',
+    'DailyBonus'
+);
+
+-- timed effects of used items, using the same item again renews it
+CREATE TABLE member_buff (
+    member bigint REFERENCES member (id) ON DELETE CASCADE,
+    buff buff NOT NULL,
+    -- percent, what it means depends on the buff
+    value int NOT NULL,
+    expires_at timestamp NOT NULL,
+    PRIMARY KEY (member, buff)
+);
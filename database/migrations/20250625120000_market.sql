@@ -0,0 +1,24 @@
+-- items on sale are taken out of the inventory of the seller until they're
+-- sold, cancelled or expire
+CREATE TABLE market_listing (
+    id serial PRIMARY KEY,
+    seller bigint NOT NULL REFERENCES member (id) ON DELETE CASCADE,
+    item int NOT NULL REFERENCES normal_item (id) ON DELETE CASCADE,
+    amount int NOT NULL CHECK (amount > 0),
+    -- for the whole listing
+    price bigint NOT NULL CHECK (price > 0),
+    created_at timestamp NOT NULL DEFAULT TIMEZONE('UTC', NOW()),
+    expires_at timestamp NOT NULL
+);
+
+CREATE INDEX market_listing_expires_idx ON market_listing (expires_at);
+
+-- a single row, the tax is a percent of every sale and what it collects
+-- leaves the economy
+CREATE TABLE market (
+    id int PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    tax int NOT NULL DEFAULT 5 CHECK (tax >= 0 AND tax <= 100),
+    collected bigint NOT NULL DEFAULT 0
+);
+
+INSERT INTO market DEFAULT VALUES;
@@ -0,0 +1,82 @@
+-- game_name narrows game quests to one game, any game counts without it
+ALTER TABLE random_quest ADD COLUMN quest_type quest_type NOT NULL DEFAULT 'Game';
+
+ALTER TABLE member_random_quests ADD COLUMN progress int NOT NULL DEFAULT 0;
+
+-- yn is the bios reward
+INSERT INTO random_quest (name, required_steps, game_name, points, yn, quest_type)
+VALUES
+  ('play-games', 5, NULL, 300, 1000, 'Game'),
+  ('play-blackjack', 3, 'BlackJack', 200, 1500, 'Game'),
+  ('play-roulette', 3, 'Roulette', 200, 1500, 'Game'),
+  ('play-dices', 3, 'Dices', 200, 1000, 'Game'),
+  ('work-shifts', 3, NULL, 250, 2000, 'Work'),
+  ('bet-bios', 10000, NULL, 300, 2500, 'Bet'),
+  ('eat-food', 2, NULL, 150, 500, 'Eat');
+
+-- three quests a day, completed ones count too so finishing them early
+-- doesn't give new ones, only quest types the bot can follow are assigned
+CREATE OR REPLACE FUNCTION generate_random_quests (member_id bigint)
+    RETURNS VOID
+    AS $$
+DECLARE
+    today date := (TIMEZONE('UTC', NOW()))::date;
+    quests_today int;
+BEGIN
+    SELECT
+        COUNT(*) INTO quests_today
+    FROM
+        member_random_quests
+    WHERE
+        member = member_id
+        AND DATE(assigned_at) = today;
+    IF quests_today = 0 THEN
+        DELETE FROM member_random_quests
+        WHERE member = member_id
+            AND completed_at IS NULL
+            AND DATE(assigned_at) < today;
+        INSERT INTO member_random_quests (member, quest)
+        SELECT
+            member_id,
+            id
+        FROM
+            random_quest
+        WHERE
+            quest_type IN ('Game', 'Work', 'Bet', 'Eat')
+        ORDER BY
+            RANDOM()
+        LIMIT 3;
+    END IF;
+END;
+$$
+LANGUAGE plpgsql;
+
+-- days in a row before today with at least one completed quest
+CREATE OR REPLACE FUNCTION random_quest_streak (member_id bigint)
+    RETURNS int
+    AS $$
+DECLARE
+    streak int := 0;
+    today date := (TIMEZONE('UTC', NOW()))::date;
+    quests_completed boolean;
+BEGIN
+    FOR i IN 1..7 LOOP
+        SELECT
+            EXISTS (
+                SELECT
+                    1
+                FROM
+                    member_random_quests
+                WHERE
+                    member = member_id
+                    AND completed_at IS NOT NULL
+                    AND DATE(assigned_at) = today - i) INTO quests_completed;
+        IF NOT quests_completed THEN
+            EXIT;
+        END IF;
+        streak := streak + 1;
+    END LOOP;
+    RETURN streak;
+END;
+$$
+LANGUAGE plpgsql;
@@ -0,0 +1,67 @@
+-- the reward of every weekday, 0 is monday
+CREATE TABLE daily_reward_day (
+    weekday int PRIMARY KEY CHECK (weekday BETWEEN 0 AND 6),
+    bios bigint NOT NULL DEFAULT 0,
+    points int NOT NULL DEFAULT 0,
+    item int REFERENCES normal_item (id) ON DELETE SET NULL,
+    amount int NOT NULL DEFAULT 1
+);
+
+INSERT INTO daily_reward_day (weekday, bios, points, item)
+VALUES
+  (0, 4500, 2500, (SELECT id FROM normal_item WHERE name = 'coffee')),
+  (1, 2500, 1500, (SELECT id FROM normal_item WHERE name = 'kariume')),
+  (2, 2500, 1500, NULL),
+  (3, 2500, 1500, NULL),
+  (4, 4500, 1500, (SELECT id FROM normal_item WHERE name = 'life-insurance')),
+  (5, 2500, 2000, NULL),
+  (6, 2500, 2000, NULL);
+
+-- from this many days of streak on, bios and points are raised by percent
+CREATE TABLE daily_milestone (
+    streak int PRIMARY KEY CHECK (streak > 0),
+    percent int NOT NULL CHECK (percent >= 0)
+);
+
+INSERT INTO daily_milestone (streak, percent)
+VALUES
+  (3, 10),
+  (7, 25),
+  (14, 50),
+  (30, 100);
+
+-- fills a single missed day so the streak isn't lost
+INSERT INTO normal_item (name, usable, item_type, quality)
+VALUES ('streak-saver', false, 'Consumable', 'Epic');
+
+INSERT INTO normal_shop (item, stock, price)
+SELECT id, NULL, 15000 FROM normal_item WHERE name = 'streak-saver';
+
+-- days in a row before today with a claimed reward, in UTC like the claims
+CREATE OR REPLACE FUNCTION daily_streak (member_id bigint)
+    RETURNS int
+    AS $$
+DECLARE
+    streak int := 0;
+    today date := (TIMEZONE('UTC', NOW()))::date;
+    reward_claimed boolean;
+BEGIN
+    FOR i IN 1..365 LOOP
+        SELECT
+            EXISTS (
+                SELECT
+                    1
+                FROM
+                    daily_reward
+                WHERE
+                    member = member_id
+                    AND DATE(claimed_at) = today - i) INTO reward_claimed;
+        IF NOT reward_claimed THEN
+            EXIT;
+        END IF;
+        streak := streak + 1;
+    END LOOP;
+    RETURN streak;
+END;
+$$
+LANGUAGE plpgsql;
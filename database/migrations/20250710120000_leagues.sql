@@ -0,0 +1,134 @@
+-- groups are leagues of a tier, members move between tiers every week
+ALTER TABLE week_group ADD COLUMN tier int NOT NULL DEFAULT 0;
+
+ALTER TABLE week_group ADD COLUMN closed_at timestamp;
+
+ALTER TABLE member ADD COLUMN league_tier int NOT NULL DEFAULT 0;
+
+-- final position once the group is closed
+ALTER TABLE member_week_group ADD COLUMN rank int;
+
+-- one row per member and month, repeated rows are merged first
+CREATE TEMP TABLE month_totals AS
+SELECT member, month_start, SUM(special_balance)::int AS special_balance
+FROM member_month_stats
+GROUP BY member, month_start;
+
+DELETE FROM member_month_stats;
+
+INSERT INTO member_month_stats (member, month_start, special_balance)
+SELECT member, month_start, special_balance FROM month_totals;
+
+DROP TABLE month_totals;
+
+ALTER TABLE member_month_stats ADD PRIMARY KEY (member, month_start);
+
+-- members used to join with their whole balance and points, the groups
+-- still open start counting from here
+UPDATE member_week_group SET balance = 0, points = 0
+FROM week_group
+WHERE member_week_group.group_id = week_group.id AND week_group.is_active;
+
+-- what every position of a closed group gets, multiplied by the tier + 1
+CREATE TABLE league_reward (
+    rank int PRIMARY KEY,
+    bios bigint NOT NULL DEFAULT 0,
+    special int NOT NULL DEFAULT 0
+);
+
+INSERT INTO league_reward (rank, bios, special)
+VALUES
+  (1, 20000, 30),
+  (2, 12000, 20),
+  (3, 8000, 12),
+  (4, 4000, 6),
+  (5, 2000, 3);
+
+-- the stats of a group are what changed while the member was in it, so
+-- members join with zero and only active groups of their tier are used
+CREATE OR REPLACE FUNCTION add_member_to_active_group(p_member_id bigint)
+RETURNS boolean AS $$
+DECLARE
+    v_group_id int;
+    v_tier int;
+    v_member_has_group boolean;
+BEGIN
+    SELECT EXISTS(
+        SELECT 1 FROM member_week_group mwg
+        JOIN week_group wg ON mwg.group_id = wg.id
+        WHERE mwg.member = p_member_id AND wg.is_active
+    ) INTO v_member_has_group;
+
+    IF v_member_has_group THEN
+        RETURN false;
+    END IF;
+
+    SELECT league_tier INTO v_tier FROM member WHERE id = p_member_id;
+
+    SELECT wg.id INTO v_group_id
+    FROM week_group wg
+    LEFT JOIN (
+        SELECT group_id, COUNT(*) as member_count
+        FROM member_week_group
+        GROUP BY group_id
+    ) counts ON wg.id = counts.group_id
+    WHERE wg.is_active = true
+      AND wg.tier = COALESCE(v_tier, 0)
+      AND (counts.member_count IS NULL OR counts.member_count < wg.member_limit)
+    ORDER BY
+        CASE WHEN counts.member_count IS NULL THEN 0 ELSE counts.member_count END DESC
+    LIMIT 1;
+
+    IF v_group_id IS NULL THEN
+        INSERT INTO week_group (created_at, is_active, member_limit, tier)
+        VALUES (TIMEZONE('UTC', NOW()), true, 10, COALESCE(v_tier, 0))
+        RETURNING id INTO v_group_id;
+    END IF;
+
+    INSERT INTO member_week_group (group_id, member, balance, points, gambles)
+    VALUES (v_group_id, p_member_id, 0, 0, 0);
+
+    RETURN true;
+
+EXCEPTION
+    WHEN OTHERS THEN
+        RAISE NOTICE 'Error al añadir miembro % al grupo: %', p_member_id, SQLERRM;
+        RETURN false;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION update_week_stats()
+RETURNS TRIGGER AS $$
+DECLARE
+    points_diff int := NEW.points - OLD.points;
+    balance_diff bigint := NEW.balance - OLD.balance;
+    v_group_id int;
+BEGIN
+    SELECT mwg.group_id INTO v_group_id
+    FROM member_week_group mwg
+    JOIN week_group wg ON mwg.group_id = wg.id
+    WHERE mwg.member = NEW.id AND wg.is_active
+    LIMIT 1;
+
+    IF v_group_id IS NULL THEN
+        PERFORM add_member_to_active_group(NEW.id);
+
+        SELECT mwg.group_id INTO v_group_id
+        FROM member_week_group mwg
+        JOIN week_group wg ON mwg.group_id = wg.id
+        WHERE mwg.member = NEW.id AND wg.is_active
+        LIMIT 1;
+
+        IF v_group_id IS NULL THEN
+            RETURN NEW;
+        END IF;
+    END IF;
+
+    UPDATE member_week_group
+    SET points = points + points_diff,
+        balance = balance + balance_diff
+    WHERE member = NEW.id AND group_id = v_group_id;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,22 @@
+-- a season in nightmare is still running, only one can be running at a time
+DROP INDEX idx_rpg_only_one_active;
+
+CREATE UNIQUE INDEX idx_rpg_only_one_running ON rpg ((1))
+WHERE
+    state <> 'End';
+
+ALTER TABLE rpg ADD COLUMN nightmare_at timestamp;
+
+-- final standings of an ended season, players keep their rows but this is
+-- what gets shown once the season is over
+CREATE TABLE rpg_result (
+    rpg int NOT NULL REFERENCES rpg (id) ON DELETE CASCADE,
+    player bigint NOT NULL REFERENCES member (id) ON DELETE CASCADE,
+    rank int NOT NULL,
+    level int NOT NULL,
+    experience int NOT NULL,
+    balance bigint NOT NULL,
+    outlaw boolean NOT NULL DEFAULT FALSE,
+    role RPG_ROLE[] NOT NULL DEFAULT ARRAY[] ::rpg_role[],
+    PRIMARY KEY (rpg, player)
+);
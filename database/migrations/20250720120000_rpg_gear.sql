@@ -0,0 +1,21 @@
+-- what an item adds to the stats of the player wearing it, before quality
+ALTER TABLE rpg_item ADD COLUMN damage int NOT NULL DEFAULT 0;
+
+ALTER TABLE rpg_item ADD COLUMN defense int NOT NULL DEFAULT 0;
+
+ALTER TABLE rpg_item ADD COLUMN accuracy int NOT NULL DEFAULT 0;
+
+ALTER TABLE rpg_item ADD COLUMN max_life int NOT NULL DEFAULT 0;
+
+INSERT INTO rpg_item (name, tool_type, item_type, armor_type, two_handed, quality, damage, defense, accuracy, max_life)
+VALUES
+  ('wooden-sword', 'Weapon', 'Tool', NULL, false, 'Common', 4, 0, 0, 0),
+  ('iron-sword', 'Weapon', 'Tool', NULL, false, 'Common', 8, 0, 5, 0),
+  ('great-axe', 'Weapon', 'Tool', NULL, true, 'Epic', 15, 0, -5, 0),
+  ('wooden-shield', 'Shield', 'Tool', NULL, false, 'Common', 0, 4, 0, 10),
+  ('leather-cap', NULL, 'Equipment', 'Head', false, 'Common', 0, 2, 0, 5),
+  ('leather-vest', NULL, 'Equipment', 'Chest', false, 'Common', 0, 4, 0, 10),
+  ('leather-pants', NULL, 'Equipment', 'Legs', false, 'Common', 0, 3, 0, 5),
+  ('leather-boots', NULL, 'Equipment', 'Boots', false, 'Common', 0, 1, 3, 0),
+  ('copper-ring', NULL, 'Equipment', 'Ring', false, 'Common', 1, 0, 5, 0),
+  ('bone-necklace', NULL, 'Equipment', 'Neck', false, 'Common', 0, 0, 0, 20);
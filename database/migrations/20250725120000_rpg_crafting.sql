@@ -0,0 +1,42 @@
+-- skills are looked up by name
+ALTER TABLE rpg_skill ADD COLUMN name varchar(50) NOT NULL DEFAULT '';
+
+INSERT INTO rpg_skill (name, skill_type, max_level)
+VALUES
+  ('combat', 'Combat', 100),
+  ('looting', 'Loot', 100),
+  ('crafting', 'Life', 100);
+
+CREATE UNIQUE INDEX idx_rpg_skill_name ON rpg_skill (name);
+
+-- crafted items of a better quality are their own item, one per quality
+CREATE UNIQUE INDEX idx_rpg_item_name_quality ON rpg_item (name, quality);
+
+INSERT INTO rpg_item (name, item_type)
+VALUES
+  ('wood', 'Material'),
+  ('leather', 'Material'),
+  ('copper', 'Material'),
+  ('iron-ore', 'Material'),
+  ('bone', 'Material');
+
+INSERT INTO rpg_craft (craft_item, recipe_item, amount)
+SELECT c.id, r.id, recipe.amount
+FROM (
+  VALUES
+    ('wooden-sword', 'wood', 3),
+    ('wooden-shield', 'wood', 4),
+    ('wooden-shield', 'leather', 1),
+    ('iron-sword', 'iron-ore', 4),
+    ('iron-sword', 'wood', 1),
+    ('great-axe', 'iron-ore', 6),
+    ('great-axe', 'wood', 2),
+    ('leather-cap', 'leather', 2),
+    ('leather-vest', 'leather', 4),
+    ('leather-pants', 'leather', 3),
+    ('leather-boots', 'leather', 2),
+    ('copper-ring', 'copper', 2),
+    ('bone-necklace', 'bone', 3)
+) AS recipe (craft, component, amount)
+JOIN rpg_item c ON c.name = recipe.craft
+JOIN rpg_item r ON r.name = recipe.component;
@@ -0,0 +1,13 @@
+-- percent of the experience a skill needs per level, over the player curve
+ALTER TABLE rpg_skill ADD COLUMN experience_rate int NOT NULL DEFAULT 100;
+
+UPDATE rpg_skill SET experience_rate = 100 WHERE name = 'combat';
+
+UPDATE rpg_skill SET experience_rate = 80 WHERE name = 'looting';
+
+UPDATE rpg_skill SET experience_rate = 120 WHERE name = 'crafting';
+
+-- players start their skills with nothing to spend
+ALTER TABLE player_skill ALTER COLUMN available_points SET DEFAULT 0;
+
+ALTER TABLE stat_points ADD CONSTRAINT stat_points_positive CHECK (points >= 0);
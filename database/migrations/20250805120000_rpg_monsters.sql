@@ -0,0 +1,75 @@
+CREATE TYPE combat_status AS ENUM (
+    'Poison',
+    'Stun',
+    'Weaken'
+);
+
+-- monsters a player can fight, stats are scaled by the enemy power of the season
+CREATE TABLE rpg_monster (
+    id serial PRIMARY KEY,
+    name varchar(50) NOT NULL UNIQUE,
+    level int NOT NULL DEFAULT 1,
+    life int NOT NULL,
+    damage int NOT NULL,
+    defense int NOT NULL DEFAULT 0,
+    accuracy int NOT NULL DEFAULT 70,
+    reaction int NOT NULL DEFAULT 1,
+    precision int NOT NULL DEFAULT 1,
+    experience int NOT NULL,
+    -- what it can leave on the player when it hits
+    status COMBAT_STATUS,
+    status_chance int NOT NULL DEFAULT 0,
+    status_turns int NOT NULL DEFAULT 0,
+    status_power int NOT NULL DEFAULT 0
+);
+
+-- chance is a percent, the amount is rolled between min and max
+CREATE TABLE rpg_monster_loot (
+    monster int NOT NULL REFERENCES rpg_monster (id) ON DELETE CASCADE,
+    item int NOT NULL REFERENCES rpg_item (id) ON DELETE CASCADE,
+    chance int NOT NULL,
+    min_amount int NOT NULL DEFAULT 1,
+    max_amount int NOT NULL DEFAULT 1,
+    PRIMARY KEY (monster, item),
+    CHECK (min_amount > 0 AND max_amount >= min_amount)
+);
+
+INSERT INTO rpg_monster (name, level, life, damage, defense, accuracy, reaction, precision,
+    experience, status, status_chance, status_turns, status_power)
+VALUES
+  ('slime', 1, 40, 6, 0, 60, 1, 1, 20, NULL, 0, 0, 0),
+  ('wolf', 3, 70, 11, 2, 75, 8, 5, 45, NULL, 0, 0, 0),
+  ('goblin', 5, 90, 14, 4, 70, 5, 8, 70, 'Weaken', 25, 2, 30),
+  ('giant-spider', 8, 120, 16, 5, 75, 6, 6, 110, 'Poison', 35, 3, 6),
+  ('troll', 12, 260, 28, 10, 60, 2, 3, 220, 'Stun', 20, 1, 0);
+
+INSERT INTO rpg_monster_loot (monster, item, chance, min_amount, max_amount)
+SELECT m.id, i.id, loot.chance, loot.min_amount, loot.max_amount
+FROM (
+  VALUES
+    ('slime', 'wood', 60, 1, 2),
+    ('wolf', 'leather', 70, 1, 3),
+    ('wolf', 'bone', 30, 1, 1),
+    ('goblin', 'copper', 50, 1, 2),
+    ('goblin', 'wood', 50, 1, 3),
+    ('giant-spider', 'leather', 40, 2, 3),
+    ('giant-spider', 'bone', 60, 1, 3),
+    ('troll', 'iron-ore', 80, 2, 4),
+    ('troll', 'bone', 50, 2, 4)
+) AS loot (monster, item, chance, min_amount, max_amount)
+JOIN rpg_monster m ON m.name = loot.monster
+JOIN rpg_item i ON i.name = loot.item AND i.quality = 'Common';
+
+-- a row per member so fights and kills can be counted with an upsert
+DELETE FROM normal_stats WHERE id IS NULL;
+
+CREATE TEMP TABLE normal_stats_dedup AS
+SELECT DISTINCT ON (id) * FROM normal_stats ORDER BY id, fights DESC NULLS LAST;
+
+DELETE FROM normal_stats;
+
+INSERT INTO normal_stats SELECT * FROM normal_stats_dedup;
+
+DROP TABLE normal_stats_dedup;
+
+ALTER TABLE normal_stats ADD PRIMARY KEY (id);
@@ -0,0 +1,2 @@
+-- items with an effect can be used, the flag has to say so
+UPDATE normal_item SET usable = true WHERE name = 'life-insurance';
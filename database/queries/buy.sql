@@ -1 +1,10 @@
-
+UPDATE
+    normal_shop
+SET
+    stock = stock - $2
+WHERE
+    item = $1
+    AND (stock IS NULL OR stock >= $2)
+RETURNING
+    price,
+    stock;
@@ -0,0 +1,14 @@
+UPDATE
+    guild_shop shop
+SET
+    stock = shop.stock - $3
+FROM
+    guild_item item
+WHERE
+    shop.item = item.id
+    AND item.id = $1
+    AND item.guild = $2
+    AND (shop.stock IS NULL OR shop.stock >= $3)
+RETURNING
+    shop.price,
+    shop.stock;
@@ -1,6 +1,8 @@
 SELECT
     id,
     name,
-    description
-FROM item
-WHERE guild = $1;
+    description,
+    role
+FROM guild_item
+WHERE guild = $1
+ORDER BY id;
@@ -1,10 +1,14 @@
 SELECT
     item.id,
     item.name,
+    item.description,
+    item.role,
     shop.price,
-    shop.description
+    shop.stock
 FROM
-    shop
-    JOIN item ON shop.item = item.id
+    guild_shop shop
+    JOIN guild_item item ON shop.item = item.id
 WHERE
-    shop.guild = $1;
+    item.guild = $1
+ORDER BY
+    item.id;
@@ -0,0 +1,103 @@
+use crate::{Error, PgPool};
+
+#[derive(Clone, Debug)]
+pub struct CommandUsage {
+    pub name: String,
+    pub uses: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct MemberUsage {
+    pub member: i64,
+    pub uses: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct DailyUsage {
+    // formatted as YYYY-MM-DD
+    pub day: String,
+    pub uses: i64,
+}
+
+pub struct CommandLog;
+
+// every query looks at the last `days` days, today included
+impl CommandLog {
+    pub async fn log(pool: &PgPool, member: i64, name: &str) -> Result<(), Error> {
+        sqlx::query!("SELECT log_command($1, $2);", member, name)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn top(pool: &PgPool, days: i32, limit: i64) -> Result<Vec<CommandUsage>, Error> {
+        let records = sqlx::query_as!(
+            CommandUsage,
+            "SELECT name, SUM(amount)::bigint AS \"uses!\" FROM command_log
+            WHERE day > (TIMEZONE('UTC', NOW()))::date - $1::int
+            GROUP BY name ORDER BY 2 DESC LIMIT $2;",
+            days,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    pub async fn top_members(
+        pool: &PgPool,
+        days: i32,
+        limit: i64,
+    ) -> Result<Vec<MemberUsage>, Error> {
+        let records = sqlx::query_as!(
+            MemberUsage,
+            "SELECT member, SUM(amount)::bigint AS \"uses!\" FROM command_log
+            WHERE day > (TIMEZONE('UTC', NOW()))::date - $1::int
+            GROUP BY member ORDER BY 2 DESC LIMIT $2;",
+            days,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    pub async fn member(
+        pool: &PgPool,
+        member: i64,
+        days: i32,
+        limit: i64,
+    ) -> Result<Vec<CommandUsage>, Error> {
+        let records = sqlx::query_as!(
+            CommandUsage,
+            "SELECT name, SUM(amount)::bigint AS \"uses!\" FROM command_log
+            WHERE member = $1 AND day > (TIMEZONE('UTC', NOW()))::date - $2::int
+            GROUP BY name ORDER BY 2 DESC LIMIT $3;",
+            member,
+            days,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    // newest day first, days without any command are left out
+    pub async fn daily(pool: &PgPool, days: i32) -> Result<Vec<DailyUsage>, Error> {
+        let records = sqlx::query_as!(
+            DailyUsage,
+            "SELECT TO_CHAR(day, 'YYYY-MM-DD') AS \"day!\", SUM(amount)::bigint AS \"uses!\"
+            FROM command_log WHERE day > (TIMEZONE('UTC', NOW()))::date - $1::int
+            GROUP BY day ORDER BY day DESC;",
+            days
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+}
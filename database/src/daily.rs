@@ -0,0 +1,138 @@
+use crate::{Error, PgPool};
+
+// item that keeps the streak when a single day was missed
+pub const STREAK_SAVER: &str = "streak-saver";
+
+#[derive(Clone, Debug)]
+pub struct DailyReward {
+    // 0 is monday
+    pub weekday: i32,
+    pub bios: i64,
+    pub points: i32,
+    pub item: Option<i32>,
+    pub item_name: Option<String>,
+    pub amount: i32,
+}
+
+impl DailyReward {
+    // bios and points raised by the percent of a streak milestone
+    pub fn scaled(&self, percent: i32) -> (i64, i32) {
+        let bios = self.bios * (100 + percent as i64) / 100;
+        let points = (self.points as i64 * (100 + percent as i64) / 100) as i32;
+
+        (bios, points)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Milestone {
+    pub streak: i32,
+    pub percent: i32,
+}
+
+// percent of the highest milestone reached with the streak
+pub fn streak_percent(milestones: &[Milestone], streak: i32) -> i32 {
+    milestones
+        .iter()
+        .filter(|milestone| milestone.streak <= streak)
+        .map(|milestone| milestone.percent)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn next_milestone(milestones: &[Milestone], streak: i32) -> Option<&Milestone> {
+    milestones
+        .iter()
+        .filter(|milestone| milestone.streak > streak)
+        .min_by_key(|milestone| milestone.streak)
+}
+
+pub struct Daily;
+
+impl Daily {
+    pub async fn get(pool: &PgPool, weekday: i32) -> Result<Option<DailyReward>, Error> {
+        let reward = sqlx::query_as!(
+            DailyReward,
+            "SELECT d.weekday, d.bios, d.points, d.item, i.name AS \"item_name?\", d.amount
+            FROM daily_reward_day d LEFT JOIN normal_item i ON d.item = i.id
+            WHERE d.weekday = $1;",
+            weekday
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(reward)
+    }
+
+    pub async fn all(pool: &PgPool) -> Result<Vec<DailyReward>, Error> {
+        let rewards = sqlx::query_as!(
+            DailyReward,
+            "SELECT d.weekday, d.bios, d.points, d.item, i.name AS \"item_name?\", d.amount
+            FROM daily_reward_day d LEFT JOIN normal_item i ON d.item = i.id
+            ORDER BY d.weekday;"
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rewards)
+    }
+
+    pub async fn set(
+        pool: &PgPool,
+        weekday: i32,
+        bios: i64,
+        points: i32,
+        item: Option<i32>,
+        amount: i32,
+    ) -> Result<(), Error> {
+        sqlx::query!(
+            "INSERT INTO daily_reward_day (weekday, bios, points, item, amount)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (weekday) DO UPDATE
+            SET bios = $2, points = $3, item = $4, amount = $5;",
+            weekday,
+            bios,
+            points,
+            item,
+            amount
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn milestones(pool: &PgPool) -> Result<Vec<Milestone>, Error> {
+        let milestones = sqlx::query_as!(
+            Milestone,
+            "SELECT streak, percent FROM daily_milestone ORDER BY streak;"
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(milestones)
+    }
+
+    // a percent of zero removes the milestone
+    pub async fn set_milestone(pool: &PgPool, streak: i32, percent: i32) -> Result<(), Error> {
+        match percent {
+            0 => {
+                sqlx::query!("DELETE FROM daily_milestone WHERE streak = $1;", streak)
+                    .execute(pool)
+                    .await?;
+            }
+            _ => {
+                sqlx::query!(
+                    "INSERT INTO daily_milestone (streak, percent) VALUES ($1, $2)
+                    ON CONFLICT (streak) DO UPDATE SET percent = $2;",
+                    streak,
+                    percent
+                )
+                .execute(pool)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::models::Buff;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Effect {
+    // lasts `secs` seconds, value is a percent
+    Buff { buff: Buff, value: i32, secs: i64 },
+    Points(i32),
+}
+
+// what every usable item does, items also need the usable flag to be used
+pub fn effect(item: &str) -> Option<Effect> {
+    let effect = match item {
+        // work cooldown halved for two hours
+        "coffee" => Effect::Buff {
+            buff: Buff::WorkCooldown,
+            value: 50,
+            secs: 2 * 3600,
+        },
+        // half of the next lost bet of the day comes back
+        "life-insurance" => Effect::Buff {
+            buff: Buff::BetInsurance,
+            value: 50,
+            secs: 24 * 3600,
+        },
+        // the next daily reward within two days gives more bios
+        "kariume" => Effect::Buff {
+            buff: Buff::DailyBonus,
+            value: 50,
+            secs: 48 * 3600,
+        },
+        "cake" => Effect::Points(500),
+        _ => return None,
+    };
+
+    Some(effect)
+}
+
+// a buff of the member that hasn't expired yet
+#[derive(Clone, Debug)]
+pub struct ActiveBuff {
+    pub buff: Buff,
+    pub value: i32,
+    // unix seconds
+    pub expires_at: i64,
+}
+
+impl ActiveBuff {
+    pub fn is_active(&self) -> bool {
+        self.expires_at > chrono::Utc::now().timestamp()
+    }
+}
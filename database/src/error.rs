@@ -93,3 +93,285 @@ impl std::error::Error for ClubError {
         }
     }
 }
+
+#[derive(Debug)]
+pub enum EscrowError {
+    AlreadyPlaying,
+    NotPlaying,
+    InsufficientFunds,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for EscrowError {
+    fn from(err: sqlx::Error) -> Self {
+        EscrowError::Database(err)
+    }
+}
+
+impl std::fmt::Display for EscrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EscrowError::AlreadyPlaying => write!(f, "Already playing"),
+            EscrowError::NotPlaying => write!(f, "Not playing"),
+            EscrowError::InsufficientFunds => write!(f, "Insufficient funds"),
+            EscrowError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EscrowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EscrowError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ShopError {
+    ItemNotFound,
+    ItemAlreadyExists,
+    InvalidAmount,
+    OutOfStock,
+    InsufficientFunds,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ShopError {
+    fn from(err: sqlx::Error) -> Self {
+        ShopError::Database(err)
+    }
+}
+
+impl std::fmt::Display for ShopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShopError::ItemNotFound => write!(f, "Item not found"),
+            ShopError::ItemAlreadyExists => write!(f, "Item already exists"),
+            ShopError::InvalidAmount => write!(f, "Invalid amount"),
+            ShopError::OutOfStock => write!(f, "Out of stock"),
+            ShopError::InsufficientFunds => write!(f, "Insufficient funds"),
+            ShopError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ShopError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ShopError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EffectError {
+    ItemNotFound,
+    NotUsable,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for EffectError {
+    fn from(err: sqlx::Error) -> Self {
+        EffectError::Database(err)
+    }
+}
+
+impl std::fmt::Display for EffectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EffectError::ItemNotFound => write!(f, "Item not found"),
+            EffectError::NotUsable => write!(f, "Not usable"),
+            EffectError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EffectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EffectError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SeasonError {
+    NoSeason,
+    AlreadyRunning,
+    AlreadyNightmare,
+    JoinClosed,
+    AlreadyJoined,
+    NotJoined,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for SeasonError {
+    fn from(err: sqlx::Error) -> Self {
+        SeasonError::Database(err)
+    }
+}
+
+impl std::fmt::Display for SeasonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeasonError::NoSeason => write!(f, "No season running"),
+            SeasonError::AlreadyRunning => write!(f, "Season already running"),
+            SeasonError::AlreadyNightmare => write!(f, "Season already in nightmare"),
+            SeasonError::JoinClosed => write!(f, "Season closed to new players"),
+            SeasonError::AlreadyJoined => write!(f, "Already joined"),
+            SeasonError::NotJoined => write!(f, "Not joined"),
+            SeasonError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SeasonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SeasonError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EquipError {
+    NotOwned,
+    NotEquippable,
+    WrongSlot,
+    EmptySlot,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for EquipError {
+    fn from(err: sqlx::Error) -> Self {
+        EquipError::Database(err)
+    }
+}
+
+impl std::fmt::Display for EquipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquipError::NotOwned => write!(f, "Item not owned"),
+            EquipError::NotEquippable => write!(f, "Item not equippable"),
+            EquipError::WrongSlot => write!(f, "Wrong slot"),
+            EquipError::EmptySlot => write!(f, "Empty slot"),
+            EquipError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EquipError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EquipError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CraftError {
+    UnknownRecipe,
+    MissingComponents,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for CraftError {
+    fn from(err: sqlx::Error) -> Self {
+        CraftError::Database(err)
+    }
+}
+
+impl std::fmt::Display for CraftError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CraftError::UnknownRecipe => write!(f, "Unknown recipe"),
+            CraftError::MissingComponents => write!(f, "Missing components"),
+            CraftError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CraftError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CraftError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SkillError {
+    UnknownSkill,
+    WrongStat,
+    InvalidAmount,
+    NotEnoughPoints,
+    NothingToReset,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for SkillError {
+    fn from(err: sqlx::Error) -> Self {
+        SkillError::Database(err)
+    }
+}
+
+impl std::fmt::Display for SkillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkillError::UnknownSkill => write!(f, "Unknown skill"),
+            SkillError::WrongStat => write!(f, "Stat not in skill"),
+            SkillError::InvalidAmount => write!(f, "Invalid amount"),
+            SkillError::NotEnoughPoints => write!(f, "Not enough points"),
+            SkillError::NothingToReset => write!(f, "Nothing to reset"),
+            SkillError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SkillError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SkillError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FightError {
+    UnknownMonster,
+    TooStrong,
+    Database(sqlx::Error),
+}
+
+impl From<sqlx::Error> for FightError {
+    fn from(err: sqlx::Error) -> Self {
+        FightError::Database(err)
+    }
+}
+
+impl std::fmt::Display for FightError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FightError::UnknownMonster => write!(f, "Unknown monster"),
+            FightError::TooStrong => write!(f, "Monster level too high"),
+            FightError::Database(err) => write!(f, "Database error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FightError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FightError::Database(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{error::EscrowError, PgPool};
+use sqlx::PgConnection;
+
+#[derive(Clone, Debug)]
+pub struct Escrow {
+    pub member: i64,
+    pub room: i64,
+    pub game: String,
+    pub amount: i64,
+}
+
+// every stake lives here from the start of a game until it's released or
+// refunded, so a restart never loses bios that were on the table
+impl Escrow {
+    // marks the member as playing, the stake is held later with hold
+    pub async fn open(
+        pool: &PgPool,
+        member: i64,
+        room: i64,
+        game: &str,
+    ) -> Result<(), EscrowError> {
+        let record = sqlx::query!(
+            "INSERT INTO escrow (member, room, game) VALUES ($1, $2, $3)
+            ON CONFLICT (member) DO NOTHING;",
+            member,
+            room,
+            game
+        )
+        .execute(pool)
+        .await?;
+
+        if record.rows_affected() == 0 {
+            return Err(EscrowError::AlreadyPlaying);
+        }
+
+        Ok(())
+    }
+
+    pub async fn get(pool: &PgPool, member: i64) -> Result<Option<Self>, EscrowError> {
+        let record = sqlx::query_as!(
+            Self,
+            "SELECT member, room, game, amount FROM escrow WHERE member = $1;",
+            member
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    pub async fn is_playing(pool: &PgPool, member: i64) -> Result<bool, EscrowError> {
+        Ok(Self::get(pool, member).await?.is_some())
+    }
+
+    // debits the stake of every member at once, nobody is charged if one can't pay
+    pub async fn hold(
+        pool: &PgPool,
+        room: i64,
+        members: &[i64],
+        amount: i64,
+    ) -> Result<(), EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        for member in members {
+            let charged = sqlx::query!(
+                "UPDATE member SET balance = balance - $1 WHERE id = $2 AND balance >= $1;",
+                amount,
+                member
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if charged.rows_affected() == 0 {
+                tx.rollback().await?;
+                return Err(EscrowError::InsufficientFunds);
+            }
+
+            let held = sqlx::query!(
+                "UPDATE escrow SET amount = amount + $1 WHERE member = $2 AND room = $3;",
+                amount,
+                member,
+                room
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            if held.rows_affected() == 0 {
+                tx.rollback().await?;
+                return Err(EscrowError::NotPlaying);
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    // the whole pot of the room goes to the winner, or to the house without one,
+    // returns every stake that was released
+    pub async fn release(
+        pool: &PgPool,
+        room: i64,
+        winner: Option<i64>,
+    ) -> Result<Vec<Self>, EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        let stakes = sqlx::query_as!(
+            Self,
+            "DELETE FROM escrow WHERE room = $1 RETURNING member, room, game, amount;",
+            room
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if let Some(winner) = winner {
+            let pot = stakes.iter().map(|stake| stake.amount).sum::<i64>();
+
+            sqlx::query!(
+                "UPDATE member SET balance = balance + $1 WHERE id = $2;",
+                pot,
+                winner
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(stakes)
+    }
+
+    // pays every member its share of the pot of the room, the house keeps
+    // whatever is left, nothing moves when the shares are over the pot
+    pub async fn split(
+        pool: &PgPool,
+        room: i64,
+        shares: &[(i64, i64)],
+    ) -> Result<Vec<Self>, EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        let stakes = sqlx::query_as!(
+            Self,
+            "DELETE FROM escrow WHERE room = $1 RETURNING member, room, game, amount;",
+            room
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let pot = stakes.iter().map(|stake| stake.amount).sum::<i64>();
+        let paid = shares.iter().map(|(_, amount)| amount).sum::<i64>();
+
+        if paid > pot {
+            tx.rollback().await?;
+            return Err(EscrowError::InsufficientFunds);
+        }
+
+        for (member, amount) in shares {
+            sqlx::query!(
+                "UPDATE member SET balance = balance + $1 WHERE id = $2;",
+                amount,
+                member
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(stakes)
+    }
+
+    // gives the stake back to the member and frees them
+    pub async fn refund(pool: &PgPool, member: i64) -> Result<Option<Self>, EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        let stake = sqlx::query_as!(
+            Self,
+            "DELETE FROM escrow WHERE member = $1 RETURNING member, room, game, amount;",
+            member
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(stake) = &stake {
+            Self::give_back(&mut tx, std::slice::from_ref(stake)).await?;
+        }
+
+        tx.commit().await?;
+        Ok(stake)
+    }
+
+    pub async fn refund_room(pool: &PgPool, room: i64) -> Result<Vec<Self>, EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        let stakes = sqlx::query_as!(
+            Self,
+            "DELETE FROM escrow WHERE room = $1 RETURNING member, room, game, amount;",
+            room
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        Self::give_back(&mut tx, &stakes).await?;
+
+        tx.commit().await?;
+        Ok(stakes)
+    }
+
+    // used on startup, no game survives a restart
+    pub async fn refund_all(pool: &PgPool) -> Result<Vec<Self>, EscrowError> {
+        let mut tx = pool.begin().await?;
+
+        let stakes = sqlx::query_as!(
+            Self,
+            "DELETE FROM escrow RETURNING member, room, game, amount;"
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        Self::give_back(&mut tx, &stakes).await?;
+
+        tx.commit().await?;
+        Ok(stakes)
+    }
+
+    async fn give_back(tx: &mut PgConnection, stakes: &[Self]) -> Result<(), EscrowError> {
+        for stake in stakes {
+            sqlx::query!(
+                "UPDATE member SET balance = balance + $1 WHERE id = $2;",
+                stake.amount,
+                stake.member
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Error, PgPool};
+
+// a settled game, built with versus or solo and written with save
+#[derive(Clone, Debug)]
+pub struct GambleLog {
+    pub name: String,
+    pub rpg: bool,
+    pub first_member: i64,
+    pub second_member: Option<i64>,
+    pub winner: Option<i64>,
+    pub loser: Option<i64>,
+    pub balance: i64,
+    // item id and amount wagered
+    pub items: Vec<(i32, i32)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GambleRecord {
+    pub id: i32,
+    pub name: String,
+    pub solo: bool,
+    pub first_member: i64,
+    pub second_member: Option<i64>,
+    pub winner: Option<i64>,
+    pub loser: Option<i64>,
+    pub balance: i64,
+    // unix seconds
+    pub created_at: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct GambleSummary {
+    pub name: String,
+    pub played: i64,
+    pub won: i64,
+    pub net: i64,
+}
+
+impl GambleRecord {
+    // bios the member won or lost in this game
+    pub fn net(&self, member: i64) -> i64 {
+        if self.winner == Some(member) {
+            self.balance
+        } else if self.loser == Some(member) {
+            -self.balance
+        } else {
+            0
+        }
+    }
+
+    pub fn rival(&self, member: i64) -> Option<i64> {
+        match self.first_member == member {
+            true => self.second_member,
+            false => Some(self.first_member),
+        }
+    }
+}
+
+impl GambleSummary {
+    pub fn win_rate(&self) -> f64 {
+        match self.played {
+            0 => 0.0,
+            played => self.won as f64 / played as f64 * 100.0,
+        }
+    }
+}
+
+impl GambleLog {
+    pub fn versus(name: &str, winner: i64, loser: i64, balance: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            rpg: false,
+            first_member: winner,
+            second_member: Some(loser),
+            winner: Some(winner),
+            loser: Some(loser),
+            balance,
+            items: Vec::new(),
+        }
+    }
+
+    // games against the house, a push has neither winner nor loser
+    pub fn solo(name: &str, member: i64, net: i64) -> Self {
+        let (winner, loser) = match net {
+            0 => (None, None),
+            n if n > 0 => (Some(member), None),
+            _ => (None, Some(member)),
+        };
+
+        Self {
+            name: name.to_string(),
+            rpg: false,
+            first_member: member,
+            second_member: None,
+            winner,
+            loser,
+            balance: net.abs(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn rpg(mut self) -> Self {
+        self.rpg = true;
+        self
+    }
+
+    pub fn item(mut self, item: i32, amount: i32) -> Self {
+        self.items.push((item, amount));
+        self
+    }
+
+    pub async fn save(&self, pool: &PgPool) -> Result<i32, Error> {
+        let mut tx = pool.begin().await?;
+
+        let record = sqlx::query!(
+            "INSERT INTO gamble_log (name, rpg, solo, first_member, second_member, winner, loser, balance)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id;",
+            self.name,
+            self.rpg,
+            self.second_member.is_none(),
+            self.first_member,
+            self.second_member,
+            self.winner,
+            self.loser,
+            self.balance
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for (item, amount) in self.items.iter() {
+            sqlx::query!(
+                "SELECT log_gamble_item($1, $2, $3);",
+                record.id,
+                item,
+                amount
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(record.id)
+    }
+
+    pub async fn history(
+        pool: &PgPool,
+        member: i64,
+        limit: i64,
+    ) -> Result<Vec<GambleRecord>, Error> {
+        let records = sqlx::query_as!(
+            GambleRecord,
+            "SELECT id, name, solo, first_member, second_member, winner, loser, balance,
+            EXTRACT(EPOCH FROM created_at)::bigint AS \"created_at!\"
+            FROM gamble_log WHERE first_member = $1 OR second_member = $1
+            ORDER BY created_at DESC LIMIT $2;",
+            member,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    pub async fn summary(pool: &PgPool, member: i64) -> Result<Vec<GambleSummary>, Error> {
+        let records = sqlx::query_as!(
+            GambleSummary,
+            "SELECT name,
+            COUNT(*) AS \"played!\",
+            COUNT(*) FILTER (WHERE winner = $1) AS \"won!\",
+            COALESCE(SUM(CASE WHEN winner = $1 THEN balance WHEN loser = $1 THEN -balance ELSE 0 END), 0)::bigint AS \"net!\"
+            FROM gamble_log WHERE first_member = $1 OR second_member = $1
+            GROUP BY name ORDER BY COUNT(*) DESC;",
+            member
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+}
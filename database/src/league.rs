@@ -0,0 +1,254 @@
+use crate::{Error, PgPool};
+
+// tiers go from 0 to MAX_TIER
+pub const MAX_TIER: i32 = 4;
+// how many of a closed group move up or down a tier
+pub const PROMOTED: i64 = 3;
+pub const DEMOTED: i64 = 3;
+
+#[derive(Clone, Debug)]
+pub struct Group {
+    pub id: i32,
+    pub tier: i32,
+}
+
+// what a member did while in a group, points decide the position since
+// bios can be given away but points can't
+#[derive(Clone, Debug)]
+pub struct Standing {
+    pub member: i64,
+    pub balance: i64,
+    pub points: i32,
+    pub rank: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct MonthStanding {
+    pub member: i64,
+    pub special_balance: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct LeagueReward {
+    pub rank: i32,
+    pub bios: i64,
+    pub special: i32,
+}
+
+impl LeagueReward {
+    pub fn for_tier(&self, tier: i32) -> (i64, i32) {
+        (self.bios * (tier as i64 + 1), self.special * (tier + 1))
+    }
+}
+
+// a member of a closed group, reward is none when there was nothing to pay
+#[derive(Clone, Debug)]
+pub struct Payout {
+    pub member: i64,
+    pub group: i32,
+    pub rank: i64,
+    pub tier: i32,
+    pub reward: Option<(i64, i32)>,
+}
+
+// the tier a member plays next week from its position in a group of size
+pub fn next_tier(tier: i32, rank: i64, size: i64) -> i32 {
+    if rank <= PROMOTED {
+        return (tier + 1).min(MAX_TIER);
+    }
+
+    // small groups only promote
+    if size > PROMOTED + DEMOTED && rank > size - DEMOTED {
+        return (tier - 1).max(0);
+    }
+
+    tier
+}
+
+pub struct League;
+
+impl League {
+    pub async fn group(pool: &PgPool, member: i64) -> Result<Option<Group>, Error> {
+        let group = sqlx::query_as!(
+            Group,
+            "SELECT wg.id, wg.tier FROM member_week_group mwg
+            JOIN week_group wg ON mwg.group_id = wg.id
+            WHERE mwg.member = $1 AND wg.is_active
+            LIMIT 1;",
+            member
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(group)
+    }
+
+    pub async fn standings(pool: &PgPool, group: i32) -> Result<Vec<Standing>, Error> {
+        let standings = sqlx::query_as!(
+            Standing,
+            "SELECT member, balance, points,
+            ROW_NUMBER() OVER (ORDER BY points DESC, balance DESC, registered_at) AS \"rank!\"
+            FROM member_week_group WHERE group_id = $1
+            ORDER BY 4;",
+            group
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(standings)
+    }
+
+    // special balance earned in leagues during the current month
+    pub async fn month(pool: &PgPool, limit: i64) -> Result<Vec<MonthStanding>, Error> {
+        let standings = sqlx::query_as!(
+            MonthStanding,
+            "SELECT member, special_balance FROM member_month_stats
+            WHERE month_start = DATE_TRUNC('month', TIMEZONE('UTC', NOW()))
+            ORDER BY special_balance DESC LIMIT $1;",
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(standings)
+    }
+
+    pub async fn month_of(pool: &PgPool, member: i64) -> Result<i32, Error> {
+        let record = sqlx::query!(
+            "SELECT special_balance FROM member_month_stats
+            WHERE member = $1 AND month_start = DATE_TRUNC('month', TIMEZONE('UTC', NOW()));",
+            member
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record.map(|record| record.special_balance).unwrap_or(0))
+    }
+
+    pub async fn rewards(pool: &PgPool) -> Result<Vec<LeagueReward>, Error> {
+        let rewards = sqlx::query_as!(
+            LeagueReward,
+            "SELECT rank, bios, special FROM league_reward ORDER BY rank;"
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rewards)
+    }
+
+    // closes the groups of past weeks, members are ranked, moved between
+    // tiers and paid once, the trigger on member puts them in a group of
+    // their new tier as soon as they're paid or play again
+    pub async fn rollover(pool: &PgPool) -> Result<Vec<Payout>, Error> {
+        let mut tx = pool.begin().await?;
+
+        let groups = sqlx::query_as!(
+            Group,
+            "UPDATE week_group SET is_active = false, closed_at = TIMEZONE('UTC', NOW())
+            WHERE is_active AND created_at < DATE_TRUNC('week', TIMEZONE('UTC', NOW()))
+            RETURNING id, tier;"
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let rewards = sqlx::query_as!(
+            LeagueReward,
+            "SELECT rank, bios, special FROM league_reward ORDER BY rank;"
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut payouts = Vec::new();
+
+        for group in groups {
+            let standings = sqlx::query!(
+                "UPDATE member_week_group m SET rank = r.rank
+                FROM (
+                    SELECT member, ROW_NUMBER() OVER (
+                        ORDER BY points DESC, balance DESC, registered_at
+                    )::int AS rank
+                    FROM member_week_group WHERE group_id = $1
+                ) r
+                WHERE m.group_id = $1 AND m.member = r.member
+                RETURNING m.member, r.rank AS \"rank!\", m.points, m.claimed_reward;",
+                group.id
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            let size = standings.len() as i64;
+
+            for standing in standings {
+                let rank = standing.rank as i64;
+
+                // members that didn't play keep their tier and get nothing
+                if standing.points <= 0 {
+                    continue;
+                }
+
+                let tier = next_tier(group.tier, rank, size);
+
+                sqlx::query!(
+                    "UPDATE member SET league_tier = $1 WHERE id = $2;",
+                    tier,
+                    standing.member
+                )
+                .execute(&mut *tx)
+                .await?;
+
+                let reward = match standing.claimed_reward {
+                    true => None,
+                    false => rewards
+                        .iter()
+                        .find(|reward| reward.rank as i64 == rank)
+                        .map(|reward| reward.for_tier(group.tier)),
+                };
+
+                if let Some((bios, special)) = reward {
+                    sqlx::query!(
+                        "UPDATE member_week_group SET claimed_reward = true
+                        WHERE group_id = $1 AND member = $2;",
+                        group.id,
+                        standing.member
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+
+                    sqlx::query!(
+                        "UPDATE member SET balance = balance + $1,
+                        special_balance = special_balance + $2
+                        WHERE id = $3;",
+                        bios,
+                        special,
+                        standing.member
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+
+                    sqlx::query!(
+                        "INSERT INTO member_month_stats (member, month_start, special_balance)
+                        VALUES ($1, DATE_TRUNC('month', TIMEZONE('UTC', NOW())), $2)
+                        ON CONFLICT (member, month_start) DO UPDATE
+                        SET special_balance = member_month_stats.special_balance + $2;",
+                        standing.member,
+                        special
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+
+                payouts.push(Payout {
+                    member: standing.member,
+                    group: group.id,
+                    rank,
+                    tier,
+                    reward,
+                });
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(payouts)
+    }
+}
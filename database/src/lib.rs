@@ -21,6 +21,22 @@ pub mod player;
 
 pub mod error;
 
+pub mod escrow;
+
+pub mod gamble;
+
+pub mod market;
+
+pub mod commands;
+
+pub mod daily;
+
+pub mod league;
+
+pub mod effects;
+
+pub mod quests;
+
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 
 impl From<Guild> for Arc<RwLock<Guild>> {
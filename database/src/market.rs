@@ -0,0 +1,180 @@
+use crate::{Error, PgPool};
+use sqlx::PgConnection;
+
+// how long a listing stays on sale
+pub const LISTING_HOURS: i32 = 72;
+
+#[derive(Clone, Debug)]
+pub struct Listing {
+    pub id: i32,
+    pub seller: i64,
+    pub item: i32,
+    pub name: String,
+    pub amount: i32,
+    pub price: i64,
+    // unix seconds
+    pub expires_at: i64,
+}
+
+pub struct Market;
+
+// the items of a listing are removed from the seller with Member::remove_item
+// in the same transaction that creates it, so a listing is the only place
+// they exist until someone gets them back with Member::add_item
+impl Market {
+    pub async fn create(
+        tx: &mut PgConnection,
+        seller: i64,
+        item: i32,
+        amount: i32,
+        price: i64,
+    ) -> Result<i32, Error> {
+        let record = sqlx::query!(
+            "INSERT INTO market_listing (seller, item, amount, price, expires_at)
+            VALUES ($1, $2, $3, $4, TIMEZONE('UTC', NOW()) + make_interval(hours => $5))
+            RETURNING id;",
+            seller,
+            item,
+            amount,
+            price,
+            LISTING_HOURS
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Ok(record.id)
+    }
+
+    pub async fn get(pool: &PgPool, id: i32) -> Result<Option<Listing>, Error> {
+        let listing = sqlx::query_as!(
+            Listing,
+            "SELECT l.id, l.seller, l.item, i.name, l.amount, l.price,
+            EXTRACT(EPOCH FROM l.expires_at)::bigint AS \"expires_at!\"
+            FROM market_listing l JOIN normal_item i ON l.item = i.id
+            WHERE l.id = $1 AND l.expires_at > TIMEZONE('UTC', NOW());",
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(listing)
+    }
+
+    // listings on sale, cheapest per unit first
+    pub async fn browse(
+        pool: &PgPool,
+        item: Option<i32>,
+        seller: Option<i64>,
+    ) -> Result<Vec<Listing>, Error> {
+        let listings = sqlx::query_as!(
+            Listing,
+            "SELECT l.id, l.seller, l.item, i.name, l.amount, l.price,
+            EXTRACT(EPOCH FROM l.expires_at)::bigint AS \"expires_at!\"
+            FROM market_listing l JOIN normal_item i ON l.item = i.id
+            WHERE l.expires_at > TIMEZONE('UTC', NOW())
+            AND ($1::int IS NULL OR l.item = $1)
+            AND ($2::bigint IS NULL OR l.seller = $2)
+            ORDER BY l.price::float / l.amount, l.id;",
+            item,
+            seller
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(listings)
+    }
+
+    // removes the listing for whoever gets its items, only one buyer can
+    // take it even when several try at once
+    pub async fn take(
+        tx: &mut PgConnection,
+        id: i32,
+        seller: Option<i64>,
+    ) -> Result<Option<Listing>, Error> {
+        let listing = sqlx::query_as!(
+            Listing,
+            "DELETE FROM market_listing l USING normal_item i
+            WHERE l.item = i.id AND l.id = $1
+            AND l.expires_at > TIMEZONE('UTC', NOW())
+            AND ($2::bigint IS NULL OR l.seller = $2)
+            RETURNING l.id, l.seller, l.item, i.name, l.amount, l.price,
+            EXTRACT(EPOCH FROM l.expires_at)::bigint AS \"expires_at!\";",
+            id,
+            seller
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        Ok(listing)
+    }
+
+    // gives the items of expired listings back to their sellers, returns them
+    // so cached sellers can be refreshed
+    pub async fn expire(pool: &PgPool) -> Result<Vec<Listing>, Error> {
+        let mut tx = pool.begin().await?;
+
+        let listings = sqlx::query_as!(
+            Listing,
+            "DELETE FROM market_listing l USING normal_item i
+            WHERE l.item = i.id AND l.expires_at <= TIMEZONE('UTC', NOW())
+            RETURNING l.id, l.seller, l.item, i.name, l.amount, l.price,
+            EXTRACT(EPOCH FROM l.expires_at)::bigint AS \"expires_at!\";"
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for listing in listings.iter() {
+            sqlx::query!(
+                "INSERT INTO normal_inventory (item, member, amount)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (item, member) DO UPDATE
+                SET amount = normal_inventory.amount + $3",
+                listing.item,
+                listing.seller,
+                listing.amount
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(listings)
+    }
+
+    pub async fn get_tax(pool: &PgPool) -> Result<i32, Error> {
+        let record = sqlx::query!("SELECT tax FROM market WHERE id = 1;")
+            .fetch_one(pool)
+            .await?;
+
+        Ok(record.tax)
+    }
+
+    pub async fn set_tax(pool: &PgPool, tax: i32) -> Result<(), Error> {
+        sqlx::query!("UPDATE market SET tax = $1 WHERE id = 1;", tax)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // takes the tax of a sale out of the economy, returns the tax taken
+    pub async fn collect(tx: &mut PgConnection, price: i64) -> Result<i64, Error> {
+        let record = sqlx::query!(
+            "UPDATE market SET collected = collected + $1::bigint * tax / 100
+            WHERE id = 1 RETURNING ($1::bigint * tax / 100) AS \"tax!\";",
+            price
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Ok(record.tax)
+    }
+
+    pub async fn collected(pool: &PgPool) -> Result<i64, Error> {
+        let record = sqlx::query!("SELECT collected FROM market WHERE id = 1;")
+            .fetch_one(pool)
+            .await?;
+
+        Ok(record.collected)
+    }
+}
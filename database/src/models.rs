@@ -28,8 +28,54 @@ pub enum AuthorityId {
 #[derive(Debug, Clone, Type, PartialEq)]
 #[sqlx(type_name = "rpg_role")]
 pub enum RpgRole {
-    King,
     Knight,
+    King,
+    Coordinator,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "rpg_skill_type")]
+pub enum SkillType {
+    Combat,
+    Loot,
+    Life,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "skill_stat_type")]
+pub enum SkillStat {
+    Accuracy,
+    Damage,
+    MaxLife,
+    Defense,
+    Quantity,
+    Quality,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "rpg_state")]
+pub enum RpgState {
+    Active,
+    Nightmare,
+    End,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "combat_status")]
+pub enum CombatStatus {
+    Poison,
+    Stun,
+    Weaken,
+}
+
+impl From<CombatStatus> for types::combat::StatusKind {
+    fn from(status: CombatStatus) -> Self {
+        match status {
+            CombatStatus::Poison => Self::Poison,
+            CombatStatus::Stun => Self::Stun,
+            CombatStatus::Weaken => Self::Weaken,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Type, PartialEq)]
@@ -41,6 +87,28 @@ pub enum ItemType {
     Quest,
     Misc,
     Consumable,
+    Food,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "buff")]
+pub enum Buff {
+    WorkCooldown,
+    BetInsurance,
+    DailyBonus,
+}
+
+#[derive(Debug, Clone, Copy, Type, PartialEq)]
+#[sqlx(type_name = "quest_type")]
+pub enum QuestType {
+    Game,
+    Work,
+    Bet,
+    Kill,
+    Dungeon,
+    Exploration,
+    Eat,
+    Visit,
 }
 
 #[derive(Debug, Clone, Type, PartialEq)]
@@ -88,6 +156,8 @@ pub enum ClubRolePerm {
 pub enum Tool {
     Weapon,
     Shield,
+    // the type was created with this spelling
+    #[sqlx(rename = "Accesory")]
     Accessory,
     Pickaxe,
     Axe,
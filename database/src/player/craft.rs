@@ -0,0 +1,184 @@
+use super::{
+    season::Rules,
+    skills::{point_value, CRAFTING},
+    Player,
+};
+use crate::{
+    error::CraftError,
+    models::{Quality, SkillStat},
+    PgPool,
+};
+use rand::Rng;
+
+// experience of the crafting skill per component used
+const COMPONENT_EXPERIENCE: i32 = 10;
+
+#[derive(Clone, Debug)]
+pub struct Component {
+    pub item: i32,
+    pub name: String,
+    pub amount: i32,
+    pub owned: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Recipe {
+    pub item: i32,
+    pub name: String,
+    pub quality: Quality,
+    pub components: Vec<Component>,
+}
+
+impl Recipe {
+    pub fn can_craft(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| component.owned >= component.amount)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Crafted {
+    pub item: i32,
+    pub name: String,
+    pub quality: Quality,
+    pub levels: i32,
+}
+
+// roll goes from 0 to 99, the bonus comes from skill level and stat points,
+// every step moves the quality one tier up
+pub fn roll_quality(base: Quality, roll: i32, bonus: i32) -> Quality {
+    let steps = match roll + bonus {
+        score if score >= 130 => 2,
+        score if score >= 95 => 1,
+        _ => 0,
+    };
+
+    (0..steps).fold(base, |quality, _| match quality {
+        Quality::Common => Quality::Normal,
+        Quality::Normal => Quality::Epic,
+        _ => Quality::Masterpiece,
+    })
+}
+
+impl Player {
+    // every recipe with what the player has of each component
+    pub async fn recipes(&self, pool: &PgPool) -> Result<Vec<Recipe>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "SELECT c.craft_item, ci.name AS craft_name, ci.quality AS \"quality: Quality\",
+            c.recipe_item, ri.name AS recipe_name, c.amount,
+            COALESCE(p.amount, 0) AS \"owned!\"
+            FROM rpg_craft c
+            JOIN rpg_item ci ON c.craft_item = ci.id
+            JOIN rpg_item ri ON c.recipe_item = ri.id
+            LEFT JOIN player_inventory p
+            ON p.item = c.recipe_item AND p.rpg = $1 AND p.player = $2
+            ORDER BY ci.name, ri.name;",
+            self.rpg,
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut recipes: Vec<Recipe> = Vec::new();
+
+        for row in rows {
+            let component = Component {
+                item: row.recipe_item,
+                name: row.recipe_name,
+                amount: row.amount,
+                owned: row.owned,
+            };
+
+            match recipes
+                .iter_mut()
+                .find(|recipe| recipe.item == row.craft_item)
+            {
+                Some(recipe) => recipe.components.push(component),
+                None => recipes.push(Recipe {
+                    item: row.craft_item,
+                    name: row.craft_name,
+                    quality: row.quality,
+                    components: vec![component],
+                }),
+            }
+        }
+
+        Ok(recipes)
+    }
+
+    // components are taken and the item given in a single transaction
+    pub async fn craft(
+        &self,
+        pool: &PgPool,
+        rules: &Rules,
+        item: i32,
+    ) -> Result<Crafted, CraftError> {
+        let recipe = self
+            .recipes(pool)
+            .await?
+            .into_iter()
+            .find(|recipe| recipe.item == item)
+            .ok_or(CraftError::UnknownRecipe)?;
+
+        let mut tx = pool.begin().await?;
+
+        for component in &recipe.components {
+            if !self
+                .remove_item(&mut *tx, component.item, component.amount)
+                .await?
+            {
+                return Err(CraftError::MissingComponents);
+            }
+        }
+
+        let bonus = self.skill_level(&mut *tx, CRAFTING).await?
+            + self.stat_points(&mut *tx, SkillStat::Quality).await?
+                * point_value(SkillStat::Quality);
+        let roll = rand::thread_rng().gen_range(0..100);
+        let quality = roll_quality(recipe.quality.clone(), roll, bonus);
+
+        // better qualities are their own item, made from the recipe one
+        sqlx::query!(
+            "INSERT INTO rpg_item (name, usable, tool_type, item_type, armor_type, two_handed,
+            quality, damage, defense, accuracy, max_life)
+            SELECT name, usable, tool_type, item_type, armor_type, two_handed,
+            $2, damage, defense, accuracy, max_life
+            FROM rpg_item WHERE id = $1
+            ON CONFLICT (name, quality) DO NOTHING;",
+            recipe.item,
+            quality.clone() as Quality
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let crafted = sqlx::query!(
+            "SELECT id FROM rpg_item WHERE name = $1 AND quality = $2;",
+            recipe.name,
+            quality.clone() as Quality
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        self.add_item(&mut *tx, crafted.id, 1).await?;
+
+        let used = recipe
+            .components
+            .iter()
+            .map(|component| component.amount)
+            .sum::<i32>();
+        let experience = used * COMPONENT_EXPERIENCE * rules.experience / 100;
+        let levels = self
+            .add_skill_experience(&mut tx, CRAFTING, experience)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(Crafted {
+            item: crafted.id,
+            name: recipe.name,
+            quality,
+            levels,
+        })
+    }
+}
@@ -0,0 +1,378 @@
+use super::Player;
+use crate::{
+    error::EquipError,
+    models::{ArmorType, ItemType, Quality, Tool},
+    PgPool,
+};
+use sqlx::PgConnection;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slot {
+    Head,
+    Chest,
+    Legs,
+    Boots,
+    FirstRing,
+    SecondRing,
+    Neck,
+    FirstHand,
+    SecondHand,
+}
+
+impl Slot {
+    pub const ALL: [Slot; 9] = [
+        Slot::Head,
+        Slot::Chest,
+        Slot::Legs,
+        Slot::Boots,
+        Slot::FirstRing,
+        Slot::SecondRing,
+        Slot::Neck,
+        Slot::FirstHand,
+        Slot::SecondHand,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Slot::Head => "slot-head",
+            Slot::Chest => "slot-chest",
+            Slot::Legs => "slot-legs",
+            Slot::Boots => "slot-boots",
+            Slot::FirstRing => "slot-first-ring",
+            Slot::SecondRing => "slot-second-ring",
+            Slot::Neck => "slot-neck",
+            Slot::FirstHand => "slot-first-hand",
+            Slot::SecondHand => "slot-second-hand",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Slot::ALL.iter().position(|slot| slot == self).unwrap_or(0)
+    }
+}
+
+// an equippable item with what it adds to the stats
+#[derive(Clone, Debug)]
+pub struct Gear {
+    pub id: i32,
+    pub name: String,
+    pub item_type: ItemType,
+    pub tool: Option<Tool>,
+    pub armor_type: Option<ArmorType>,
+    pub two_handed: bool,
+    pub quality: Quality,
+    pub damage: i32,
+    pub defense: i32,
+    pub accuracy: i32,
+    pub max_life: i32,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub damage: i32,
+    pub defense: i32,
+    pub accuracy: i32,
+    pub max_life: i32,
+    // percents over the normal loot, only raised with stat points
+    pub quantity: i32,
+    pub quality: i32,
+}
+
+impl Stats {
+    // what a player has without any gear
+    pub fn base(level: i32) -> Self {
+        Self {
+            damage: 5 + level,
+            defense: level / 2,
+            accuracy: 70,
+            max_life: 100 + 10 * (level - 1),
+            quantity: 0,
+            quality: 0,
+        }
+    }
+
+    pub fn add(&mut self, other: Stats) {
+        self.damage += other.damage;
+        self.defense += other.defense;
+        self.accuracy += other.accuracy;
+        self.max_life += other.max_life;
+        self.quantity += other.quantity;
+        self.quality += other.quality;
+    }
+}
+
+impl Gear {
+    // better qualities raise every stat of the item
+    pub fn stats(&self) -> Stats {
+        let percent = match self.quality {
+            Quality::Common => 100,
+            Quality::Normal => 115,
+            Quality::Epic => 135,
+            Quality::Masterpiece => 160,
+        };
+
+        Stats {
+            damage: self.damage * percent / 100,
+            defense: self.defense * percent / 100,
+            accuracy: self.accuracy * percent / 100,
+            max_life: self.max_life * percent / 100,
+            ..Default::default()
+        }
+    }
+
+    fn hands(&self) -> bool {
+        self.item_type == ItemType::Tool && self.tool.is_some()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Equipment {
+    slots: [Option<Gear>; 9],
+}
+
+impl Equipment {
+    pub fn get(&self, slot: Slot) -> Option<&Gear> {
+        self.slots[slot.index()].as_ref()
+    }
+
+    fn set(&mut self, slot: Slot, gear: Option<Gear>) {
+        self.slots[slot.index()] = gear;
+    }
+
+    // the slots the item takes, wanted picks between rings or hands
+    pub fn slots_for(&self, gear: &Gear, wanted: Option<Slot>) -> Result<Vec<Slot>, EquipError> {
+        if gear.hands() {
+            if gear.two_handed {
+                return Ok(vec![Slot::FirstHand, Slot::SecondHand]);
+            }
+
+            return match wanted {
+                Some(slot @ (Slot::FirstHand | Slot::SecondHand)) => Ok(vec![slot]),
+                Some(_) => Err(EquipError::WrongSlot),
+                None => Ok(vec![self.free_or_first(Slot::FirstHand, Slot::SecondHand)]),
+            };
+        }
+
+        let slot = match (&gear.item_type, &gear.armor_type) {
+            (ItemType::Equipment, Some(ArmorType::Ring)) => {
+                return match wanted {
+                    Some(slot @ (Slot::FirstRing | Slot::SecondRing)) => Ok(vec![slot]),
+                    Some(_) => Err(EquipError::WrongSlot),
+                    None => Ok(vec![self.free_or_first(Slot::FirstRing, Slot::SecondRing)]),
+                };
+            }
+            (ItemType::Equipment, Some(ArmorType::Head)) => Slot::Head,
+            (ItemType::Equipment, Some(ArmorType::Chest)) => Slot::Chest,
+            (ItemType::Equipment, Some(ArmorType::Legs)) => Slot::Legs,
+            (ItemType::Equipment, Some(ArmorType::Boots)) => Slot::Boots,
+            (ItemType::Equipment, Some(ArmorType::Neck)) => Slot::Neck,
+            _ => return Err(EquipError::NotEquippable),
+        };
+
+        match wanted {
+            Some(wanted) if wanted != slot => Err(EquipError::WrongSlot),
+            _ => Ok(vec![slot]),
+        }
+    }
+
+    fn free_or_first(&self, first: Slot, second: Slot) -> Slot {
+        match (self.get(first), self.get(second)) {
+            (Some(_), None) => second,
+            _ => first,
+        }
+    }
+
+    // takes out what is in the slot, a two-handed item leaves both hands
+    fn take(&mut self, slot: Slot) -> Option<Gear> {
+        let gear = self.slots[slot.index()].take()?;
+
+        if gear.two_handed {
+            for hand in [Slot::FirstHand, Slot::SecondHand] {
+                if self.get(hand).is_some_and(|other| other.id == gear.id) {
+                    self.set(hand, None);
+                }
+            }
+        }
+
+        Some(gear)
+    }
+
+    // puts the gear in its slots and returns what was taken out
+    pub fn equip(&mut self, gear: Gear, slots: &[Slot]) -> Vec<Gear> {
+        let removed = slots
+            .iter()
+            .filter_map(|slot| self.take(*slot))
+            .collect::<Vec<Gear>>();
+
+        for slot in slots {
+            self.set(*slot, Some(gear.clone()));
+        }
+
+        removed
+    }
+
+    // every worn item once, a two-handed weapon fills both hands
+    pub fn worn(&self) -> Vec<&Gear> {
+        Slot::ALL
+            .iter()
+            .filter(|slot| {
+                **slot != Slot::SecondHand
+                    || !self
+                        .get(Slot::SecondHand)
+                        .is_some_and(|gear| gear.two_handed)
+            })
+            .filter_map(|slot| self.get(*slot))
+            .collect()
+    }
+
+    pub fn stats(&self, level: i32) -> Stats {
+        let mut stats = Stats::base(level);
+
+        for gear in self.worn() {
+            stats.add(gear.stats());
+        }
+
+        stats
+    }
+}
+
+impl Player {
+    pub async fn gear(pool: &PgPool, item: i32) -> Result<Option<Gear>, sqlx::Error> {
+        sqlx::query_as!(
+            Gear,
+            "SELECT id, name, item_type AS \"item_type: ItemType\", tool_type AS \"tool: Tool\",
+            armor_type AS \"armor_type: ArmorType\", two_handed, quality AS \"quality: Quality\",
+            damage, defense, accuracy, max_life
+            FROM rpg_item WHERE id = $1;",
+            item
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn equipment(&self, pool: &PgPool) -> Result<Equipment, sqlx::Error> {
+        let mut conn = pool.acquire().await?;
+        self.load_equipment(&mut conn).await
+    }
+
+    async fn load_equipment(&self, conn: &mut PgConnection) -> Result<Equipment, sqlx::Error> {
+        let row = sqlx::query!(
+            "SELECT head, chest, legs, boots, first_ring, second_ring, neck, first_hand, second_hand
+            FROM player_equipment WHERE rpg = $1 AND player = $2 FOR UPDATE;",
+            self.rpg,
+            self.id
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let mut equipment = Equipment::default();
+
+        let row = match row {
+            Some(row) => row,
+            None => return Ok(equipment),
+        };
+
+        let ids = [
+            row.head,
+            row.chest,
+            row.legs,
+            row.boots,
+            row.first_ring,
+            row.second_ring,
+            row.neck,
+            row.first_hand,
+            row.second_hand,
+        ];
+
+        let gear = sqlx::query_as!(
+            Gear,
+            "SELECT id, name, item_type AS \"item_type: ItemType\", tool_type AS \"tool: Tool\",
+            armor_type AS \"armor_type: ArmorType\", two_handed, quality AS \"quality: Quality\",
+            damage, defense, accuracy, max_life
+            FROM rpg_item WHERE id = ANY($1);",
+            &ids.iter().flatten().copied().collect::<Vec<i32>>()
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        for (slot, id) in Slot::ALL.iter().zip(ids) {
+            let worn = id.and_then(|id| gear.iter().find(|gear| gear.id == id).cloned());
+            equipment.set(*slot, worn);
+        }
+
+        Ok(equipment)
+    }
+
+    async fn save_equipment(
+        &self,
+        conn: &mut PgConnection,
+        equipment: &Equipment,
+    ) -> Result<(), sqlx::Error> {
+        let id = |slot: Slot| equipment.get(slot).map(|gear| gear.id);
+
+        sqlx::query!(
+            "INSERT INTO player_equipment
+            (rpg, player, head, chest, legs, boots, first_ring, second_ring, neck, first_hand, second_hand)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (rpg, player) DO UPDATE
+            SET head = $3, chest = $4, legs = $5, boots = $6, first_ring = $7,
+            second_ring = $8, neck = $9, first_hand = $10, second_hand = $11;",
+            self.rpg,
+            self.id,
+            id(Slot::Head),
+            id(Slot::Chest),
+            id(Slot::Legs),
+            id(Slot::Boots),
+            id(Slot::FirstRing),
+            id(Slot::SecondRing),
+            id(Slot::Neck),
+            id(Slot::FirstHand),
+            id(Slot::SecondHand)
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    // moves one item from the inventory to its slots, whatever was there
+    // goes back to the inventory
+    pub async fn equip(
+        &self,
+        pool: &PgPool,
+        item: i32,
+        wanted: Option<Slot>,
+    ) -> Result<Equipment, EquipError> {
+        let gear = Self::gear(pool, item).await?.ok_or(EquipError::NotOwned)?;
+        let mut tx = pool.begin().await?;
+
+        if !self.remove_item(&mut *tx, item, 1).await? {
+            return Err(EquipError::NotOwned);
+        }
+
+        let mut equipment = self.load_equipment(&mut tx).await?;
+        let slots = equipment.slots_for(&gear, wanted)?;
+
+        for removed in equipment.equip(gear, &slots) {
+            self.add_item(&mut *tx, removed.id, 1).await?;
+        }
+
+        self.save_equipment(&mut tx, &equipment).await?;
+        tx.commit().await?;
+
+        Ok(equipment)
+    }
+
+    pub async fn unequip(&self, pool: &PgPool, slot: Slot) -> Result<Gear, EquipError> {
+        let mut tx = pool.begin().await?;
+        let mut equipment = self.load_equipment(&mut tx).await?;
+
+        let gear = equipment.take(slot).ok_or(EquipError::EmptySlot)?;
+        self.add_item(&mut *tx, gear.id, 1).await?;
+
+        self.save_equipment(&mut tx, &equipment).await?;
+        tx.commit().await?;
+
+        Ok(gear)
+    }
+}
@@ -0,0 +1,243 @@
+use super::{
+    season::Rules,
+    skills::{COMBAT, LOOTING},
+    Player,
+};
+use crate::{error::FightError, models::CombatStatus, PgPool};
+use rand::Rng;
+use types::combat::{roll_loot, Affliction, Fighter, LootEntry, Status};
+
+// monsters can be this many levels over the player
+pub const LEVEL_RANGE: i32 = 2;
+
+// experience of the looting skill per item looted
+const LOOT_EXPERIENCE: i32 = 5;
+
+#[derive(Clone, Debug)]
+pub struct Monster {
+    pub id: i32,
+    pub name: String,
+    pub level: i32,
+    pub life: i32,
+    pub damage: i32,
+    pub defense: i32,
+    pub accuracy: i32,
+    pub reaction: i32,
+    pub precision: i32,
+    pub experience: i32,
+    pub status: Option<CombatStatus>,
+    pub status_chance: i32,
+    pub status_turns: i32,
+    pub status_power: i32,
+}
+
+impl Monster {
+    // power is the enemy power of the season, a percent over life, damage and defense
+    pub fn fighter(&self, power: i32) -> Fighter {
+        let scale = |value: i32| value * power / 100;
+
+        let fighter = Fighter::new(
+            &self.name,
+            scale(self.life).max(1),
+            scale(self.damage),
+            scale(self.defense),
+            self.accuracy,
+        )
+        .with_reflexes(self.reaction, self.precision);
+
+        match self.status {
+            Some(status) => fighter.with_affliction(Affliction {
+                status: Status {
+                    kind: status.into(),
+                    turns: self.status_turns.max(0) as u32,
+                    power: self.status_power,
+                },
+                chance: self.status_chance.clamp(0, 100) as u32,
+            }),
+            None => fighter,
+        }
+    }
+
+    pub async fn get(pool: &PgPool, id: i32) -> Result<Option<Self>, sqlx::Error> {
+        let monster = sqlx::query_as!(
+            Monster,
+            "SELECT id, name, level, life, damage, defense, accuracy, reaction, precision,
+            experience, status AS \"status: CombatStatus\", status_chance, status_turns,
+            status_power
+            FROM rpg_monster WHERE id = $1;",
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(monster)
+    }
+
+    pub async fn loot(&self, pool: &PgPool) -> Result<Vec<LootEntry>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "SELECT item, chance, min_amount, max_amount FROM rpg_monster_loot
+            WHERE monster = $1 ORDER BY item;",
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let table = rows
+            .into_iter()
+            .map(|row| LootEntry {
+                item: row.item,
+                chance: row.chance.max(0) as u32,
+                min: row.min_amount.max(1) as u32,
+                max: row.max_amount.max(1) as u32,
+            })
+            .collect();
+
+        Ok(table)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FightReward {
+    pub experience: i32,
+    pub levels: i32,
+    pub combat_levels: i32,
+    pub looting_levels: i32,
+    pub loot: Vec<(String, i32)>,
+}
+
+impl Player {
+    // the monsters the player can fight, weakest first
+    pub async fn monsters(&self, pool: &PgPool) -> Result<Vec<Monster>, sqlx::Error> {
+        let monsters = sqlx::query_as!(
+            Monster,
+            "SELECT id, name, level, life, damage, defense, accuracy, reaction, precision,
+            experience, status AS \"status: CombatStatus\", status_chance, status_turns,
+            status_power
+            FROM rpg_monster WHERE level <= $1 ORDER BY level, name;",
+            self.level + LEVEL_RANGE
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(monsters)
+    }
+
+    pub async fn monster(&self, pool: &PgPool, id: i32) -> Result<Monster, FightError> {
+        let monster = Monster::get(pool, id)
+            .await?
+            .ok_or(FightError::UnknownMonster)?;
+
+        if monster.level > self.level + LEVEL_RANGE {
+            return Err(FightError::TooStrong);
+        }
+
+        Ok(monster)
+    }
+
+    // gear and skill points, strength and endurance of the member add damage and life
+    pub async fn fighter(&self, pool: &PgPool, name: &str) -> Result<Fighter, sqlx::Error> {
+        let stats = self.stats(pool).await?;
+
+        let normal = sqlx::query!(
+            "SELECT COALESCE(strength, 1) AS \"strength!\", COALESCE(endurance, 1) AS \"endurance!\",
+            COALESCE(reaction, 1) AS \"reaction!\", COALESCE(precision, 1) AS \"precision!\"
+            FROM normal_stats WHERE id = $1;",
+            self.id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        let (strength, endurance, reaction, precision) = normal
+            .map(|row| (row.strength, row.endurance, row.reaction, row.precision))
+            .unwrap_or((1, 1, 1, 1));
+
+        let fighter = Fighter::new(
+            name,
+            stats.max_life + 5 * endurance,
+            stats.damage + strength,
+            stats.defense,
+            stats.accuracy,
+        )
+        .with_reflexes(reaction, precision);
+
+        Ok(fighter)
+    }
+
+    // loot, experience and the fight count in a single transaction, the rng
+    // should be the one of the combat so a seed decides the whole encounter
+    pub async fn finish_fight<R: Rng>(
+        &mut self,
+        pool: &PgPool,
+        rules: &Rules,
+        monster: &Monster,
+        won: bool,
+        rng: &mut R,
+    ) -> Result<FightReward, FightError> {
+        let mut reward = FightReward::default();
+
+        // a lost fight still teaches something
+        let experience = match won {
+            true => monster.experience,
+            false => monster.experience / 4,
+        };
+        reward.experience = experience * rules.experience / 100;
+
+        let looted = match won {
+            true => {
+                let stats = self.stats(pool).await?;
+                let table = monster.loot(pool).await?;
+
+                roll_loot(
+                    rng,
+                    &table,
+                    stats.quality + rules.loot - 100,
+                    stats.quantity,
+                )
+            }
+            false => Vec::new(),
+        };
+
+        let mut tx = pool.begin().await?;
+
+        for (item, amount) in &looted {
+            self.add_item(&mut *tx, *item, *amount as i32).await?;
+
+            let name = sqlx::query!("SELECT name FROM rpg_item WHERE id = $1;", item)
+                .fetch_one(&mut *tx)
+                .await?
+                .name;
+
+            reward.loot.push((name, *amount as i32));
+        }
+
+        let items = looted.iter().map(|(_, amount)| *amount as i32).sum::<i32>();
+
+        reward.combat_levels = self
+            .add_skill_experience(&mut tx, COMBAT, reward.experience)
+            .await?;
+        reward.looting_levels = self
+            .add_skill_experience(
+                &mut tx,
+                LOOTING,
+                items * LOOT_EXPERIENCE * rules.experience / 100,
+            )
+            .await?;
+
+        reward.levels = self.add_experience(&mut tx, rules, experience).await?;
+
+        sqlx::query!(
+            "INSERT INTO normal_stats (id, fights, kills) VALUES ($1, 1, $2)
+            ON CONFLICT (id) DO UPDATE
+            SET fights = COALESCE(normal_stats.fights, 0) + 1,
+            kills = COALESCE(normal_stats.kills, 0) + $2;",
+            self.id,
+            won as i32
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(reward)
+    }
+}
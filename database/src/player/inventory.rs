@@ -0,0 +1,88 @@
+use super::Player;
+use crate::{
+    models::{ArmorType, ItemType, Quality, RpgItemInventory, Tool},
+    PgPool,
+};
+
+impl Player {
+    pub async fn inventory(&self, pool: &PgPool) -> Result<Vec<RpgItemInventory>, sqlx::Error> {
+        sqlx::query_as!(
+            RpgItemInventory,
+            "SELECT i.id, i.name, p.amount, i.usable, i.tool_type AS \"tool: Tool\",
+            i.item_type AS \"item_type: ItemType\", i.armor_type AS \"armor_type: ArmorType\",
+            i.two_handed, i.quality AS \"quality: Quality\"
+            FROM player_inventory p JOIN rpg_item i ON p.item = i.id
+            WHERE p.rpg = $1 AND p.player = $2 AND p.amount > 0
+            ORDER BY i.name;",
+            self.rpg,
+            self.id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn item_amount<'a, E>(&self, executor: E, item: i32) -> Result<i32, sqlx::Error>
+    where
+        E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+    {
+        let record = sqlx::query!(
+            "SELECT amount FROM player_inventory WHERE rpg = $1 AND player = $2 AND item = $3;",
+            self.rpg,
+            self.id,
+            item
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(record.map(|record| record.amount).unwrap_or(0))
+    }
+
+    pub async fn add_item<'a, E>(
+        &self,
+        executor: E,
+        item: i32,
+        amount: i32,
+    ) -> Result<(), sqlx::Error>
+    where
+        E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+    {
+        sqlx::query!(
+            "INSERT INTO player_inventory (rpg, player, item, amount)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (rpg, player, item) DO UPDATE
+            SET amount = player_inventory.amount + $4;",
+            self.rpg,
+            self.id,
+            item,
+            amount
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    // false when the player doesn't have that many
+    pub async fn remove_item<'a, E>(
+        &self,
+        executor: E,
+        item: i32,
+        amount: i32,
+    ) -> Result<bool, sqlx::Error>
+    where
+        E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+    {
+        let removed = sqlx::query!(
+            "UPDATE player_inventory SET amount = amount - $4
+            WHERE rpg = $1 AND player = $2 AND item = $3 AND amount >= $4;",
+            self.rpg,
+            self.id,
+            item,
+            amount
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(removed.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    error::SeasonError,
+    models::{RpgRole, RpgState},
+    PgPool,
+};
+use sqlx::PgConnection;
+
+mod craft;
+mod equipment;
+mod fight;
+mod inventory;
+mod season;
+mod skills;
+pub use craft::{roll_quality, Component, Crafted, Recipe};
+pub use equipment::{Equipment, Gear, Slot, Stats};
+pub use fight::{FightReward, Monster, LEVEL_RANGE};
+pub use season::{Rules, Season, SeasonResult};
+pub use skills::{
+    point_value, skill_experience, skill_level_up, skill_stats, Skill, COMBAT, CRAFTING, LOOTING,
+    STATS,
+};
+
+// what every player starts a season with
+pub const STARTER_ITEMS: [(&str, i32); 2] = [("wooden-sword", 1), ("leather-cap", 1)];
+
+pub const MAX_LEVEL: i32 = 100;
+
+// experience needed to go from level to the next one
+pub fn level_experience(level: i32) -> i32 {
+    100 * level
+}
+
+// level and experience left after earning amount, experience is kept per level
+pub fn level_up(level: i32, experience: i32, amount: i32) -> (i32, i32) {
+    grow(MAX_LEVEL, level, experience, amount, level_experience)
+}
+
+fn grow(
+    max: i32,
+    level: i32,
+    experience: i32,
+    amount: i32,
+    needed: impl Fn(i32) -> i32,
+) -> (i32, i32) {
+    let mut level = level;
+    let mut experience = experience.saturating_add(amount);
+
+    while level < max && experience >= needed(level) {
+        experience -= needed(level);
+        level += 1;
+    }
+
+    if level >= max {
+        experience = 0;
+    }
+
+    (level, experience)
+}
+
+// a member playing a season
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub rpg: i32,
+    pub id: i64,
+    pub playing: bool,
+    pub outlaw: bool,
+    pub balance: i64,
+    pub level: i32,
+    pub experience: i32,
+    pub roles: Vec<RpgRole>,
+    // unix seconds
+    pub start_at: i64,
+}
+
+impl Player {
+    pub async fn get(pool: &PgPool, rpg: i32, id: i64) -> Result<Option<Self>, SeasonError> {
+        let player = sqlx::query_as!(
+            Player,
+            "SELECT rpg AS \"rpg!\", player AS \"id!\",
+            COALESCE(playing, true) AS \"playing!\", COALESCE(outlaw, false) AS \"outlaw!\",
+            balance, level, experience, role AS \"roles: Vec<RpgRole>\",
+            EXTRACT(EPOCH FROM start_at)::bigint AS \"start_at!\"
+            FROM player WHERE rpg = $1 AND player = $2;",
+            rpg,
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(player)
+    }
+
+    // the player of the running season
+    pub async fn current(pool: &PgPool, id: i64) -> Result<(Season, Self), SeasonError> {
+        let season = Season::running(pool).await?;
+
+        match Self::get(pool, season.id, id).await? {
+            Some(player) => Ok((season, player)),
+            None => Err(SeasonError::NotJoined),
+        }
+    }
+
+    pub async fn join(pool: &PgPool, season: &Season, id: i64) -> Result<Self, SeasonError> {
+        if !season.rules().can_join || season.state == RpgState::End {
+            return Err(SeasonError::JoinClosed);
+        }
+
+        let inserted = sqlx::query!(
+            "INSERT INTO player (rpg, player) VALUES ($1, $2) ON CONFLICT DO NOTHING;",
+            season.id,
+            id
+        )
+        .execute(pool)
+        .await?;
+
+        if inserted.rows_affected() == 0 {
+            return Err(SeasonError::AlreadyJoined);
+        }
+
+        for (name, amount) in STARTER_ITEMS {
+            sqlx::query!(
+                "INSERT INTO player_inventory (rpg, player, item, amount)
+                SELECT $1, $2, id, $3 FROM rpg_item WHERE name = $4
+                ORDER BY quality LIMIT 1
+                ON CONFLICT (rpg, player, item) DO NOTHING;",
+                season.id,
+                id,
+                amount,
+                name
+            )
+            .execute(pool)
+            .await?;
+        }
+
+        Self::get(pool, season.id, id)
+            .await?
+            .ok_or(SeasonError::NotJoined)
+    }
+
+    // players of a season, the best first
+    pub async fn ranking(pool: &PgPool, rpg: i32, limit: i64) -> Result<Vec<Self>, SeasonError> {
+        let players = sqlx::query_as!(
+            Player,
+            "SELECT rpg AS \"rpg!\", player AS \"id!\",
+            COALESCE(playing, true) AS \"playing!\", COALESCE(outlaw, false) AS \"outlaw!\",
+            balance, level, experience, role AS \"roles: Vec<RpgRole>\",
+            EXTRACT(EPOCH FROM start_at)::bigint AS \"start_at!\"
+            FROM player WHERE rpg = $1
+            ORDER BY level DESC, experience DESC, balance DESC, start_at
+            LIMIT $2;",
+            rpg,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(players)
+    }
+
+    // experience is scaled by the rules of the season, returns the levels gained
+    pub async fn add_experience(
+        &mut self,
+        conn: &mut PgConnection,
+        rules: &Rules,
+        amount: i32,
+    ) -> Result<i32, sqlx::Error> {
+        let amount = (amount as i64 * rules.experience as i64 / 100) as i32;
+        let (level, experience) = level_up(self.level, self.experience, amount);
+
+        sqlx::query!(
+            "UPDATE player SET level = $1, experience = $2 WHERE rpg = $3 AND player = $4;",
+            level,
+            experience,
+            self.rpg,
+            self.id
+        )
+        .execute(conn)
+        .await?;
+
+        let gained = level - self.level;
+        self.level = level;
+        self.experience = experience;
+
+        Ok(gained)
+    }
+}
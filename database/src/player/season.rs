@@ -0,0 +1,201 @@
+use crate::{error::SeasonError, models::RpgRole, models::RpgState, PgPool};
+
+// what changes between the phases of a season, percents over the normal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rules {
+    pub can_join: bool,
+    pub experience: i32,
+    pub loot: i32,
+    pub enemy_power: i32,
+}
+
+impl Rules {
+    pub fn of(state: RpgState) -> Self {
+        match state {
+            RpgState::Active => Self {
+                can_join: true,
+                experience: 100,
+                loot: 100,
+                enemy_power: 100,
+            },
+            // nobody new gets in, everything hits harder and gives more
+            RpgState::Nightmare => Self {
+                can_join: false,
+                experience: 200,
+                loot: 150,
+                enemy_power: 150,
+            },
+            RpgState::End => Self {
+                can_join: false,
+                experience: 0,
+                loot: 0,
+                enemy_power: 100,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Season {
+    pub id: i32,
+    pub state: RpgState,
+    // unix seconds
+    pub started_at: i64,
+    pub nightmare_at: Option<i64>,
+    pub ended_at: Option<i64>,
+}
+
+// archived position of a player once the season ended
+#[derive(Clone, Debug)]
+pub struct SeasonResult {
+    pub player: i64,
+    pub rank: i32,
+    pub level: i32,
+    pub experience: i32,
+    pub balance: i64,
+    pub outlaw: bool,
+    pub roles: Vec<RpgRole>,
+}
+
+impl Season {
+    pub fn rules(&self) -> Rules {
+        Rules::of(self.state)
+    }
+
+    // the season being played, in its active or nightmare phase
+    pub async fn current(pool: &PgPool) -> Result<Option<Self>, SeasonError> {
+        let season = sqlx::query_as!(
+            Season,
+            "SELECT id, state AS \"state: RpgState\",
+            EXTRACT(EPOCH FROM started_at)::bigint AS \"started_at!\",
+            EXTRACT(EPOCH FROM nightmare_at)::bigint AS nightmare_at,
+            EXTRACT(EPOCH FROM ended_at)::bigint AS ended_at
+            FROM rpg WHERE state <> 'End';"
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(season)
+    }
+
+    pub async fn running(pool: &PgPool) -> Result<Self, SeasonError> {
+        Self::current(pool).await?.ok_or(SeasonError::NoSeason)
+    }
+
+    pub async fn last_ended(pool: &PgPool) -> Result<Option<Self>, SeasonError> {
+        let season = sqlx::query_as!(
+            Season,
+            "SELECT id, state AS \"state: RpgState\",
+            EXTRACT(EPOCH FROM started_at)::bigint AS \"started_at!\",
+            EXTRACT(EPOCH FROM nightmare_at)::bigint AS nightmare_at,
+            EXTRACT(EPOCH FROM ended_at)::bigint AS ended_at
+            FROM rpg WHERE state = 'End'
+            ORDER BY ended_at DESC LIMIT 1;"
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(season)
+    }
+
+    pub async fn start(pool: &PgPool) -> Result<Self, SeasonError> {
+        if Self::current(pool).await?.is_some() {
+            return Err(SeasonError::AlreadyRunning);
+        }
+
+        sqlx::query!("INSERT INTO rpg DEFAULT VALUES;")
+            .execute(pool)
+            .await?;
+
+        Self::running(pool).await
+    }
+
+    pub async fn nightmare(pool: &PgPool) -> Result<Self, SeasonError> {
+        let season = Self::running(pool).await?;
+
+        if season.state == RpgState::Nightmare {
+            return Err(SeasonError::AlreadyNightmare);
+        }
+
+        sqlx::query!(
+            "UPDATE rpg SET state = 'Nightmare', nightmare_at = TIMEZONE('UTC', NOW())
+            WHERE id = $1;",
+            season.id
+        )
+        .execute(pool)
+        .await?;
+
+        Self::running(pool).await
+    }
+
+    // closes the season and archives the standings of its players
+    pub async fn end(pool: &PgPool) -> Result<(Self, Vec<SeasonResult>), SeasonError> {
+        let season = Self::running(pool).await?;
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!(
+            "UPDATE rpg SET state = 'End', ended_at = TIMEZONE('UTC', NOW()) WHERE id = $1;",
+            season.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO rpg_result (rpg, player, rank, level, experience, balance, outlaw, role)
+            SELECT rpg, player,
+            ROW_NUMBER() OVER (ORDER BY level DESC, experience DESC, balance DESC, start_at)::int,
+            level, experience, balance, COALESCE(outlaw, false), role
+            FROM player WHERE rpg = $1;",
+            season.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE player SET playing = false, end_at = TIMEZONE('UTC', NOW())
+            WHERE rpg = $1 AND end_at IS NULL;",
+            season.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        let results = Self::results(pool, season.id).await?;
+        let season = Self::get(pool, season.id)
+            .await?
+            .ok_or(SeasonError::NoSeason)?;
+
+        Ok((season, results))
+    }
+
+    pub async fn get(pool: &PgPool, id: i32) -> Result<Option<Self>, SeasonError> {
+        let season = sqlx::query_as!(
+            Season,
+            "SELECT id, state AS \"state: RpgState\",
+            EXTRACT(EPOCH FROM started_at)::bigint AS \"started_at!\",
+            EXTRACT(EPOCH FROM nightmare_at)::bigint AS nightmare_at,
+            EXTRACT(EPOCH FROM ended_at)::bigint AS ended_at
+            FROM rpg WHERE id = $1;",
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(season)
+    }
+
+    pub async fn results(pool: &PgPool, id: i32) -> Result<Vec<SeasonResult>, SeasonError> {
+        let results = sqlx::query_as!(
+            SeasonResult,
+            "SELECT player, rank, level, experience, balance, outlaw,
+            role AS \"roles: Vec<RpgRole>\"
+            FROM rpg_result WHERE rpg = $1 ORDER BY rank;",
+            id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(results)
+    }
+}
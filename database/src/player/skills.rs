@@ -0,0 +1,335 @@
+use super::{grow, level_experience, Player, Stats};
+use crate::{
+    error::SkillError,
+    models::{SkillStat, SkillType},
+    PgPool,
+};
+use sqlx::PgConnection;
+
+// names of the skills in rpg_skill
+pub const COMBAT: &str = "combat";
+pub const LOOTING: &str = "looting";
+pub const CRAFTING: &str = "crafting";
+
+pub const STATS: [SkillStat; 6] = [
+    SkillStat::Accuracy,
+    SkillStat::Damage,
+    SkillStat::MaxLife,
+    SkillStat::Defense,
+    SkillStat::Quantity,
+    SkillStat::Quality,
+];
+
+// the stats the points of a skill can go to
+pub fn skill_stats(skill_type: SkillType) -> &'static [SkillStat] {
+    match skill_type {
+        SkillType::Combat => &[SkillStat::Accuracy, SkillStat::Damage, SkillStat::Defense],
+        SkillType::Loot => &[SkillStat::Quantity, SkillStat::Quality],
+        SkillType::Life => &[SkillStat::MaxLife, SkillStat::Quality],
+    }
+}
+
+// what a single point adds to the stat
+pub fn point_value(stat: SkillStat) -> i32 {
+    match stat {
+        SkillStat::Accuracy => 1,
+        SkillStat::Damage => 2,
+        SkillStat::MaxLife => 10,
+        SkillStat::Defense => 1,
+        SkillStat::Quantity => 5,
+        SkillStat::Quality => 2,
+    }
+}
+
+// experience needed for the next level of a skill, rate is a percent
+pub fn skill_experience(rate: i32, level: i32) -> i32 {
+    (level_experience(level) as i64 * rate as i64 / 100).max(1) as i32
+}
+
+pub fn skill_level_up(rate: i32, max: i32, level: i32, experience: i32, amount: i32) -> (i32, i32) {
+    grow(max, level, experience, amount, |level| {
+        skill_experience(rate, level)
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct Skill {
+    pub id: i32,
+    pub name: String,
+    pub skill_type: SkillType,
+    pub max_level: i32,
+    pub experience_rate: i32,
+    pub level: i32,
+    pub experience: i32,
+    pub available_points: i32,
+    pub points: Vec<(SkillStat, i32)>,
+}
+
+impl Skill {
+    pub fn spent(&self) -> i32 {
+        self.points.iter().map(|(_, points)| points).sum()
+    }
+}
+
+impl Player {
+    // every skill, the ones never trained at level 1
+    pub async fn skills(&self, pool: &PgPool) -> Result<Vec<Skill>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "SELECT s.id, s.name, s.skill_type AS \"skill_type: SkillType\", s.max_level,
+            s.experience_rate, COALESCE(ps.level, 1) AS \"level!\",
+            COALESCE(ps.experience, 0) AS \"experience!\",
+            COALESCE(ps.available_points, 0) AS \"available_points!\"
+            FROM rpg_skill s
+            LEFT JOIN player_skill ps ON ps.skill = s.id AND ps.rpg = $1 AND ps.player = $2
+            ORDER BY s.id;",
+            self.rpg,
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let points = sqlx::query!(
+            "SELECT skill, stat AS \"stat: SkillStat\", points FROM stat_points
+            WHERE rpg = $1 AND player = $2 AND points > 0;",
+            self.rpg,
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let skills = rows
+            .into_iter()
+            .map(|row| Skill {
+                id: row.id,
+                name: row.name,
+                skill_type: row.skill_type,
+                max_level: row.max_level,
+                experience_rate: row.experience_rate,
+                level: row.level,
+                experience: row.experience,
+                available_points: row.available_points,
+                points: points
+                    .iter()
+                    .filter(|points| points.skill == row.id)
+                    .map(|points| (points.stat, points.points))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(skills)
+    }
+
+    // points put in a stat across every skill
+    pub async fn stat_points<'a, E>(&self, executor: E, stat: SkillStat) -> Result<i32, sqlx::Error>
+    where
+        E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+    {
+        let record = sqlx::query!(
+            "SELECT COALESCE(SUM(points), 0)::int AS \"points!\" FROM stat_points
+            WHERE rpg = $1 AND player = $2 AND stat = $3;",
+            self.rpg,
+            self.id,
+            stat as SkillStat
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(record.points)
+    }
+
+    pub async fn skill_level<'a, E>(&self, executor: E, skill: &str) -> Result<i32, sqlx::Error>
+    where
+        E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+    {
+        let record = sqlx::query!(
+            "SELECT ps.level FROM player_skill ps JOIN rpg_skill s ON ps.skill = s.id
+            WHERE ps.rpg = $1 AND ps.player = $2 AND s.name = $3;",
+            self.rpg,
+            self.id,
+            skill
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(record.map(|record| record.level).unwrap_or(1))
+    }
+
+    // every level gained gives a point to spend in the stats of the skill
+    pub async fn add_skill_experience(
+        &self,
+        conn: &mut PgConnection,
+        skill: &str,
+        amount: i32,
+    ) -> Result<i32, sqlx::Error> {
+        let current = sqlx::query!(
+            "INSERT INTO player_skill (rpg, player, skill)
+            SELECT $1, $2, id FROM rpg_skill WHERE name = $3
+            ON CONFLICT (rpg, player, skill) DO UPDATE SET level = player_skill.level
+            RETURNING skill, level, experience,
+            (SELECT max_level FROM rpg_skill WHERE name = $3) AS \"max_level!\",
+            (SELECT experience_rate FROM rpg_skill WHERE name = $3) AS \"experience_rate!\";",
+            self.rpg,
+            self.id,
+            skill
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let current = match current {
+            Some(current) => current,
+            None => return Ok(0),
+        };
+
+        let (level, experience) = skill_level_up(
+            current.experience_rate,
+            current.max_level,
+            current.level,
+            current.experience,
+            amount,
+        );
+        let gained = level - current.level;
+
+        sqlx::query!(
+            "UPDATE player_skill
+            SET level = $1, experience = $2, available_points = available_points + $3
+            WHERE rpg = $4 AND player = $5 AND skill = $6;",
+            level,
+            experience,
+            gained,
+            self.rpg,
+            self.id,
+            current.skill
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(gained)
+    }
+
+    // moves available points of a skill into one of its stats
+    pub async fn allocate(
+        &self,
+        pool: &PgPool,
+        skill: i32,
+        stat: SkillStat,
+        points: i32,
+    ) -> Result<(), SkillError> {
+        if points <= 0 {
+            return Err(SkillError::InvalidAmount);
+        }
+
+        let skill_type = sqlx::query!(
+            "SELECT skill_type AS \"skill_type: SkillType\" FROM rpg_skill WHERE id = $1;",
+            skill
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(SkillError::UnknownSkill)?
+        .skill_type;
+
+        if !skill_stats(skill_type).contains(&stat) {
+            return Err(SkillError::WrongStat);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        let spent = sqlx::query!(
+            "UPDATE player_skill SET available_points = available_points - $4
+            WHERE rpg = $1 AND player = $2 AND skill = $3 AND available_points >= $4;",
+            self.rpg,
+            self.id,
+            skill,
+            points
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if spent.rows_affected() == 0 {
+            return Err(SkillError::NotEnoughPoints);
+        }
+
+        sqlx::query!(
+            "INSERT INTO stat_points (rpg, player, skill, stat, points)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (rpg, player, skill, stat) DO UPDATE
+            SET points = stat_points.points + $5;",
+            self.rpg,
+            self.id,
+            skill,
+            stat as SkillStat,
+            points
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // every point of the skill goes back to be spent again, returns how many
+    pub async fn reset_skill(&self, pool: &PgPool, skill: i32) -> Result<i32, SkillError> {
+        let mut tx = pool.begin().await?;
+
+        let refunded = sqlx::query!(
+            "DELETE FROM stat_points WHERE rpg = $1 AND player = $2 AND skill = $3
+            RETURNING points;",
+            self.rpg,
+            self.id,
+            skill
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .iter()
+        .map(|row| row.points)
+        .sum::<i32>();
+
+        if refunded == 0 {
+            return Err(SkillError::NothingToReset);
+        }
+
+        sqlx::query!(
+            "UPDATE player_skill SET available_points = available_points + $4
+            WHERE rpg = $1 AND player = $2 AND skill = $3;",
+            self.rpg,
+            self.id,
+            skill,
+            refunded
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(refunded)
+    }
+
+    // what combat and gathering use, the gear plus the points of every skill
+    pub async fn stats(&self, pool: &PgPool) -> Result<Stats, sqlx::Error> {
+        let mut stats = self.equipment(pool).await?.stats(self.level);
+
+        let points = sqlx::query!(
+            "SELECT stat AS \"stat: SkillStat\", SUM(points)::int AS \"points!\"
+            FROM stat_points WHERE rpg = $1 AND player = $2 GROUP BY stat;",
+            self.rpg,
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        for row in points {
+            let value = row.points * point_value(row.stat);
+
+            match row.stat {
+                SkillStat::Accuracy => stats.accuracy += value,
+                SkillStat::Damage => stats.damage += value,
+                SkillStat::MaxLife => stats.max_life += value,
+                SkillStat::Defense => stats.defense += value,
+                SkillStat::Quantity => stats.quantity += value,
+                SkillStat::Quality => stats.quality += value,
+            }
+        }
+
+        Ok(stats)
+    }
+}
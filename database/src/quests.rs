@@ -0,0 +1,76 @@
+use crate::models::QuestType;
+
+// extra reward per day of streak, a week of quests gives 70% more
+pub const STREAK_BONUS: i32 = 10;
+
+// what a member did, every hook point in the bot reports one of these
+#[derive(Clone, Debug)]
+pub enum QuestEvent {
+    // a finished game, by its name in the gamble log
+    Game(String),
+    Work,
+    // bios bet
+    Bet(i64),
+    Eat,
+}
+
+impl QuestEvent {
+    pub fn quest_type(&self) -> QuestType {
+        match self {
+            QuestEvent::Game(_) => QuestType::Game,
+            QuestEvent::Work => QuestType::Work,
+            QuestEvent::Bet(_) => QuestType::Bet,
+            QuestEvent::Eat => QuestType::Eat,
+        }
+    }
+
+    pub fn game(&self) -> Option<&str> {
+        match self {
+            QuestEvent::Game(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    // how far it moves the quests it counts for
+    pub fn steps(&self) -> i64 {
+        match self {
+            QuestEvent::Bet(bios) => (*bios).clamp(0, i32::MAX as i64),
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Quest {
+    pub id: i32,
+    pub name: String,
+    pub quest_type: QuestType,
+    pub game: Option<String>,
+    pub progress: i32,
+    pub required: i32,
+    pub points: i32,
+    pub bios: i32,
+    pub completed: bool,
+}
+
+impl Quest {
+    pub fn percent(&self) -> i32 {
+        match self.required {
+            0 => 100,
+            required => (self.progress as i64 * 100 / required as i64) as i32,
+        }
+    }
+}
+
+// rewards of a quest completed just now, streak bonus included
+#[derive(Clone, Debug)]
+pub struct CompletedQuest {
+    pub name: String,
+    pub required: i32,
+    pub points: i32,
+    pub bios: i64,
+}
+
+pub fn streak_reward(reward: i64, streak: i32) -> i64 {
+    reward * (100 + streak as i64 * STREAK_BONUS as i64) / 100
+}
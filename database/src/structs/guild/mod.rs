@@ -5,15 +5,35 @@ use greet::Greeting;
 
 mod messages;
 
+pub mod shop;
+use shop::{GuildItem, GuildProduct};
+
 pub struct Guild {
     pub id: i64,
     pub greeting: Greeting,
+    pub items: Vec<GuildItem>,
+    pub shop: Vec<GuildProduct>,
 }
 
 impl Guild {
     pub async fn build(pool: &PgPool, id: i64) -> Result<Self, Error> {
+        sqlx::query!(
+            "INSERT INTO guild (id) VALUES ($1) ON CONFLICT (id) DO NOTHING;",
+            id
+        )
+        .execute(pool)
+        .await?;
+
         let greeting = Greeting::build(pool, id).await?;
-        Ok(Self { id, greeting })
+        let items = Self::load_items(pool, id).await?;
+        let shop = Self::load_shop(pool, id).await?;
+
+        Ok(Self {
+            id,
+            greeting,
+            items,
+            shop,
+        })
     }
 
     pub async fn toggle_greeting(&mut self, pool: &PgPool) -> Result<bool, Error> {
@@ -0,0 +1,228 @@
+use super::{Guild, PgPool};
+use crate::{error::ShopError, structs::member::shop::Purchase};
+
+#[derive(Clone, Debug)]
+pub struct GuildItem {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub role: Option<i64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GuildProduct {
+    pub item: GuildItem,
+    pub price: i64,
+    pub stock: Option<i32>,
+}
+
+// items defined by the admins of a guild, kept apart from normal_item so they
+// can't be bought in other guilds or with the global shop
+impl Guild {
+    pub(super) async fn load_items(pool: &PgPool, id: i64) -> Result<Vec<GuildItem>, sqlx::Error> {
+        sqlx::query_file_as!(GuildItem, "queries/guild_items.sql", id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub(super) async fn load_shop(
+        pool: &PgPool,
+        id: i64,
+    ) -> Result<Vec<GuildProduct>, sqlx::Error> {
+        let records = sqlx::query_file!("queries/guild_shop.sql", id)
+            .fetch_all(pool)
+            .await?;
+
+        let shop = records
+            .into_iter()
+            .map(|record| GuildProduct {
+                item: GuildItem {
+                    id: record.id,
+                    name: record.name,
+                    description: record.description,
+                    role: record.role,
+                },
+                price: record.price,
+                stock: record.stock,
+            })
+            .collect();
+
+        Ok(shop)
+    }
+
+    pub fn get_item(&self, name: &str) -> Option<&GuildItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+
+    pub fn get_product(&self, item: i32) -> Option<&GuildProduct> {
+        self.shop.iter().find(|product| product.item.id == item)
+    }
+
+    pub async fn create_item(
+        &mut self,
+        pool: &PgPool,
+        name: &str,
+        description: Option<String>,
+        role: Option<i64>,
+    ) -> Result<GuildItem, ShopError> {
+        if self.get_item(name).is_some() {
+            return Err(ShopError::ItemAlreadyExists);
+        }
+
+        let record = sqlx::query!(
+            "INSERT INTO guild_item (guild, name, description, role)
+            VALUES ($1, $2, $3, $4) RETURNING id;",
+            self.id,
+            name,
+            description,
+            role
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let item = GuildItem {
+            id: record.id,
+            name: name.to_string(),
+            description,
+            role,
+        };
+
+        self.items.push(item.clone());
+        Ok(item)
+    }
+
+    // the item also leaves the shop and every inventory
+    pub async fn delete_item(&mut self, pool: &PgPool, name: &str) -> Result<(), ShopError> {
+        let item = self.get_item(name).ok_or(ShopError::ItemNotFound)?.id;
+
+        sqlx::query!("DELETE FROM guild_item WHERE id = $1;", item)
+            .execute(pool)
+            .await?;
+
+        self.items.retain(|entry| entry.id != item);
+        self.shop.retain(|product| product.item.id != item);
+
+        Ok(())
+    }
+
+    // puts the item for sale or changes its price and stock, None is unlimited
+    pub async fn stock_item(
+        &mut self,
+        pool: &PgPool,
+        name: &str,
+        price: i64,
+        stock: Option<i32>,
+    ) -> Result<(), ShopError> {
+        if price < 1 || stock.is_some_and(|stock| stock < 0) {
+            return Err(ShopError::InvalidAmount);
+        }
+
+        let item = self.get_item(name).ok_or(ShopError::ItemNotFound)?.clone();
+
+        sqlx::query!(
+            "INSERT INTO guild_shop (item, price, stock) VALUES ($1, $2, $3)
+            ON CONFLICT (item) DO UPDATE SET price = $2, stock = $3;",
+            item.id,
+            price,
+            stock
+        )
+        .execute(pool)
+        .await?;
+
+        match self
+            .shop
+            .iter_mut()
+            .find(|product| product.item.id == item.id)
+        {
+            Some(product) => {
+                product.price = price;
+                product.stock = stock;
+            }
+            None => self.shop.push(GuildProduct { item, price, stock }),
+        }
+
+        Ok(())
+    }
+
+    pub async fn unstock_item(&mut self, pool: &PgPool, name: &str) -> Result<(), ShopError> {
+        let item = self.get_item(name).ok_or(ShopError::ItemNotFound)?.id;
+
+        sqlx::query!("DELETE FROM guild_shop WHERE item = $1;", item)
+            .execute(pool)
+            .await?;
+
+        self.shop.retain(|product| product.item.id != item);
+
+        Ok(())
+    }
+
+    // debits the bios, takes the stock and adds the item in one transaction,
+    // the cached member has to be updated by the caller with the total
+    pub async fn buy(
+        &mut self,
+        pool: &PgPool,
+        member: i64,
+        item: i32,
+        amount: i32,
+    ) -> Result<Purchase, ShopError> {
+        if amount < 1 {
+            return Err(ShopError::InvalidAmount);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        let product = sqlx::query_file!("queries/guild_buy.sql", item, self.id, amount)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        let product = match product {
+            Some(product) => product,
+            None => {
+                tx.rollback().await?;
+
+                return match self.get_product(item) {
+                    Some(_) => Err(ShopError::OutOfStock),
+                    None => Err(ShopError::ItemNotFound),
+                };
+            }
+        };
+
+        let total = product.price * amount as i64;
+
+        let charged = sqlx::query!(
+            "UPDATE member SET balance = balance - $1 WHERE id = $2 AND balance >= $1;",
+            total,
+            member
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if charged.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Err(ShopError::InsufficientFunds);
+        }
+
+        sqlx::query!(
+            "INSERT INTO guild_inventory (item, member, amount)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (item, member) DO UPDATE
+            SET amount = guild_inventory.amount + $3",
+            item,
+            member,
+            amount
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        if let Some(cached) = self.shop.iter_mut().find(|entry| entry.item.id == item) {
+            cached.stock = product.stock;
+        }
+
+        Ok(Purchase {
+            total,
+            stock: product.stock,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use sqlx::PgPool;
 
 use super::{Error, Member};
+use crate::daily::STREAK_SAVER;
 
 impl Member {
     pub fn get_bios(&self) -> i64 {
@@ -51,4 +52,54 @@ impl Member {
         self.state.can_claim_daily = false;
         Ok(())
     }
+
+    // days in a row before today with a claimed reward
+    pub async fn daily_streak(&self, pool: &PgPool) -> Result<i32, Error> {
+        let record = sqlx::query!("SELECT daily_streak($1) AS \"streak!\";", self.id)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(record.streak)
+    }
+
+    // a streak saver claims yesterday for the member when it's the only day
+    // missed, true when one was used
+    pub async fn save_streak(&mut self, pool: &PgPool) -> Result<bool, Error> {
+        let saver = match self.get_item(String::from(STREAK_SAVER)) {
+            Ok(saver) => saver,
+            Err(_) => return Ok(false),
+        };
+
+        let record = sqlx::query!(
+            "SELECT
+            EXISTS (SELECT 1 FROM daily_reward WHERE member = $1
+                AND DATE(claimed_at) = (TIMEZONE('UTC', NOW()))::date - 1) AS \"yesterday!\",
+            EXISTS (SELECT 1 FROM daily_reward WHERE member = $1
+                AND DATE(claimed_at) = (TIMEZONE('UTC', NOW()))::date - 2) AS \"before!\";",
+            self.id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        if record.yesterday || !record.before {
+            return Ok(false);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        self.remove_item(&mut *tx, saver.info.id.unwrap_or_default(), 1)
+            .await?;
+
+        sqlx::query!(
+            "INSERT INTO daily_reward (member, claimed_at)
+            VALUES ($1, TIMEZONE('UTC', NOW()) - INTERVAL '1 day');",
+            self.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
 }
@@ -0,0 +1,155 @@
+use super::{Error, Member, PgPool};
+use crate::{
+    effects::{self, ActiveBuff, Effect},
+    error::EffectError,
+    models::Buff,
+};
+
+impl Member {
+    pub(super) async fn load_buffs(pool: &PgPool, id: i64) -> Result<Vec<ActiveBuff>, Error> {
+        let buffs = sqlx::query_as!(
+            ActiveBuff,
+            "SELECT buff AS \"buff: Buff\", value,
+            EXTRACT(EPOCH FROM expires_at)::bigint AS \"expires_at!\"
+            FROM member_buff
+            WHERE member = $1 AND expires_at > TIMEZONE('UTC', NOW());",
+            id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(buffs)
+    }
+
+    // percent of the buff while it lasts
+    pub fn get_buff(&self, buff: Buff) -> Option<i32> {
+        self.buffs
+            .iter()
+            .find(|active| active.buff == buff && active.is_active())
+            .map(|active| active.value)
+    }
+
+    // for one-shot buffs, returns the percent when it was still active
+    pub async fn consume_buff(&mut self, pool: &PgPool, buff: Buff) -> Result<Option<i32>, Error> {
+        let value = self.get_buff(buff);
+
+        if value.is_some() {
+            sqlx::query!(
+                "DELETE FROM member_buff WHERE member = $1 AND buff = $2;",
+                self.id,
+                buff as Buff
+            )
+            .execute(pool)
+            .await?;
+        }
+
+        self.buffs.retain(|active| active.buff != buff);
+
+        Ok(value)
+    }
+
+    // takes one of the item and applies its effect in one transaction
+    pub async fn use_item(&mut self, pool: &PgPool, item_id: i32) -> Result<Effect, EffectError> {
+        let item = self
+            .get_item_by_id(item_id)
+            .ok_or(EffectError::ItemNotFound)?;
+
+        if !item.info.usable {
+            return Err(EffectError::NotUsable);
+        }
+
+        let effect = effects::effect(&item.info.name).ok_or(EffectError::NotUsable)?;
+
+        let mut tx = pool.begin().await?;
+
+        let used = sqlx::query!(
+            "UPDATE normal_inventory SET amount = amount - 1
+            WHERE item = $1 AND member = $2 AND amount > 0
+            RETURNING amount;",
+            item_id,
+            self.id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let left = match used {
+            Some(record) => record.amount,
+            None => {
+                tx.rollback().await?;
+                return Err(EffectError::ItemNotFound);
+            }
+        };
+
+        if left == 0 {
+            sqlx::query!(
+                "DELETE FROM normal_inventory WHERE item = $1 AND member = $2;",
+                item_id,
+                self.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let buff = match effect {
+            Effect::Buff { buff, value, secs } => {
+                let record = sqlx::query!(
+                    "INSERT INTO member_buff (member, buff, value, expires_at)
+                    VALUES ($1, $2, $3, TIMEZONE('UTC', NOW()) + make_interval(secs => $4))
+                    ON CONFLICT (member, buff) DO UPDATE
+                    SET value = $3, expires_at = EXCLUDED.expires_at
+                    RETURNING EXTRACT(EPOCH FROM expires_at)::bigint AS \"expires_at!\";",
+                    self.id,
+                    buff as Buff,
+                    value,
+                    secs as f64
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+
+                Some(ActiveBuff {
+                    buff,
+                    value,
+                    expires_at: record.expires_at,
+                })
+            }
+            Effect::Points(points) => {
+                sqlx::query!(
+                    "UPDATE member SET points = points + $1 WHERE id = $2;",
+                    points,
+                    self.id
+                )
+                .execute(&mut *tx)
+                .await?;
+
+                None
+            }
+        };
+
+        tx.commit().await?;
+
+        match left {
+            0 => {
+                self.inventory.remove(&item_id);
+            }
+            left => {
+                if let Some(owned) = self.inventory.get_mut(&item_id) {
+                    owned.amount = left;
+                }
+            }
+        }
+
+        match buff {
+            Some(buff) => {
+                self.buffs.retain(|active| active.buff != buff.buff);
+                self.buffs.push(buff);
+            }
+            None => {
+                if let Effect::Points(points) = effect {
+                    self.balance.points += points;
+                }
+            }
+        }
+
+        Ok(effect)
+    }
+}
@@ -6,9 +6,12 @@ use tokio::try_join;
 use types::cards::poker::Card;
 
 pub mod balance;
+pub mod buffs;
 pub mod items;
 pub mod points;
+pub mod quests;
 pub mod roles;
+pub mod shop;
 pub mod stats;
 pub mod work;
 
@@ -21,12 +24,13 @@ impl Member {
         .execute(pool)
         .await?;
 
-        let (balance, state, job, inventory, roles) = try_join!(
+        let (balance, state, job, inventory, roles, buffs) = try_join!(
             NormalBalance::load(pool, id),
             MemberState::load(pool, id),
             JobModel::load(pool, id),
             normal_inventory(pool, id),
             roles(pool, id),
+            Self::load_buffs(pool, id),
         )?;
 
         let club = sqlx::query!("SELECT club AS id FROM club_member WHERE member = $1;", id)
@@ -42,6 +46,7 @@ impl Member {
             state,
             deck: Card::standart_deck(),
             club_id: club.map(|club| club.id),
+            buffs,
         })
     }
 
@@ -123,7 +128,6 @@ impl MemberState {
 
         Ok(Self {
             can_claim_daily: can_claim_daily.can_claim_daily_reward.unwrap(),
-            in_gamble: false,
         })
     }
 }
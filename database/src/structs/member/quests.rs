@@ -0,0 +1,110 @@
+use super::{Error, Member, PgPool};
+use crate::{
+    models::QuestType,
+    quests::{self, CompletedQuest, Quest, QuestEvent},
+};
+
+impl Member {
+    // today's quests, they are assigned the first time they're needed
+    pub async fn load_quests(&self, pool: &PgPool) -> Result<Vec<Quest>, Error> {
+        sqlx::query!("SELECT generate_random_quests($1);", self.id)
+            .execute(pool)
+            .await?;
+
+        let quests = sqlx::query_as!(
+            Quest,
+            "SELECT mq.id, q.name, q.quest_type AS \"quest_type: QuestType\",
+            q.game_name AS game, mq.progress, q.required_steps AS required,
+            COALESCE(q.points, 0) AS \"points!\", COALESCE(q.yn, 0) AS \"bios!\",
+            mq.completed_at IS NOT NULL AS \"completed!\"
+            FROM member_random_quests mq JOIN random_quest q ON mq.quest = q.id
+            WHERE mq.member = $1 AND DATE(mq.assigned_at) = (TIMEZONE('UTC', NOW()))::date
+            ORDER BY mq.id;",
+            self.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(quests)
+    }
+
+    pub async fn quest_streak(&self, pool: &PgPool) -> Result<i32, Error> {
+        let record = sqlx::query!("SELECT random_quest_streak($1) AS \"streak!\";", self.id)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(record.streak)
+    }
+
+    // moves the quests the event counts for and pays the ones it completes
+    pub async fn progress_quests(
+        &mut self,
+        pool: &PgPool,
+        event: &QuestEvent,
+    ) -> Result<Vec<CompletedQuest>, Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("SELECT generate_random_quests($1);", self.id)
+            .execute(&mut *tx)
+            .await?;
+
+        let records = sqlx::query!(
+            "UPDATE member_random_quests mq
+            SET progress = LEAST(mq.progress::bigint + $3, q.required_steps)::int,
+            completed_at = CASE WHEN mq.progress::bigint + $3 >= q.required_steps
+                THEN TIMEZONE('UTC', NOW()) END
+            FROM random_quest q
+            WHERE mq.quest = q.id AND mq.member = $1 AND mq.completed_at IS NULL
+            AND DATE(mq.assigned_at) = (TIMEZONE('UTC', NOW()))::date
+            AND q.quest_type = $2 AND (q.game_name IS NULL OR q.game_name = $4)
+            RETURNING q.name, q.required_steps, COALESCE(q.points, 0) AS \"points!\",
+            COALESCE(q.yn, 0) AS \"bios!\", mq.completed_at IS NOT NULL AS \"completed!\";",
+            self.id,
+            event.quest_type() as QuestType,
+            event.steps(),
+            event.game()
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if !records.iter().any(|record| record.completed) {
+            tx.commit().await?;
+            return Ok(Vec::new());
+        }
+
+        let streak = sqlx::query!("SELECT random_quest_streak($1) AS \"streak!\";", self.id)
+            .fetch_one(&mut *tx)
+            .await?
+            .streak;
+
+        let completed = records
+            .into_iter()
+            .filter(|record| record.completed)
+            .map(|record| CompletedQuest {
+                name: record.name,
+                required: record.required_steps,
+                points: quests::streak_reward(record.points as i64, streak) as i32,
+                bios: quests::streak_reward(record.bios as i64, streak),
+            })
+            .collect::<Vec<CompletedQuest>>();
+
+        let points = completed.iter().map(|quest| quest.points).sum::<i32>();
+        let bios = completed.iter().map(|quest| quest.bios).sum::<i64>();
+
+        sqlx::query!(
+            "UPDATE member SET points = points + $1, balance = balance + $2 WHERE id = $3;",
+            points,
+            bios,
+            self.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        self.balance.points += points;
+        self.balance.bios += bios;
+
+        Ok(completed)
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Item, ItemAmount, Member, PgPool};
+use crate::error::ShopError;
+
+pub struct Purchase {
+    pub total: i64,
+    // what is left after the purchase, None when the stock is unlimited
+    pub stock: Option<i32>,
+}
+
+impl Member {
+    // debits the bios, takes the stock and adds the item in one transaction
+    pub async fn buy(
+        &mut self,
+        pool: &PgPool,
+        item: Item,
+        amount: i32,
+    ) -> Result<Purchase, ShopError> {
+        if amount < 1 {
+            return Err(ShopError::InvalidAmount);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        let product = sqlx::query_file!("queries/buy.sql", item.id, amount)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        let (price, stock) = match product {
+            Some(product) => match product.price {
+                Some(price) => (price, product.stock),
+                None => {
+                    tx.rollback().await?;
+                    return Err(ShopError::ItemNotFound);
+                }
+            },
+            None => {
+                let listed = sqlx::query!("SELECT item FROM normal_shop WHERE item = $1;", item.id)
+                    .fetch_optional(&mut *tx)
+                    .await?;
+
+                tx.rollback().await?;
+
+                return match listed {
+                    Some(_) => Err(ShopError::OutOfStock),
+                    None => Err(ShopError::ItemNotFound),
+                };
+            }
+        };
+
+        let total = price as i64 * amount as i64;
+
+        let charged = sqlx::query!(
+            "UPDATE member SET balance = balance - $1 WHERE id = $2 AND balance >= $1;",
+            total,
+            self.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if charged.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Err(ShopError::InsufficientFunds);
+        }
+
+        sqlx::query!(
+            "INSERT INTO normal_inventory (item, member, amount)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (item, member) DO UPDATE
+            SET amount = normal_inventory.amount + $3",
+            item.id,
+            self.id,
+            amount
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        // the cache only changes once the purchase went through
+        self.balance.bios -= total;
+        self.inventory
+            .entry(item.id.unwrap())
+            .and_modify(|owned| owned.amount += amount)
+            .or_insert(ItemAmount { info: item, amount });
+
+        Ok(Purchase { total, stock })
+    }
+}
@@ -1,14 +1,20 @@
 use super::{Error, Member};
 use crate::{
     error::WorkError,
-    models::{JobModel, Role},
+    models::{Buff, JobModel, Role},
 };
 use rand::Rng;
 use sqlx::PgPool;
 
 impl Member {
+    // shortened while a work cooldown buff lasts
     pub fn get_work_cooldown(&self) -> i32 {
-        self.job.clone().unwrap_or_default().cooldown
+        let cooldown = self.job.clone().unwrap_or_default().cooldown;
+
+        match self.get_buff(Buff::WorkCooldown) {
+            Some(percent) => cooldown * (100 - percent) / 100,
+            None => cooldown,
+        }
     }
 
     pub fn is_employed(&self) -> bool {
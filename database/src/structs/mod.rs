@@ -4,7 +4,10 @@ pub mod member;
 pub mod system;
 pub use system::System;
 
-use crate::models::{AgentRelation, AuthorityId, ClubItemType, ItemType, JobModel, Quality, Role};
+use crate::{
+    effects::ActiveBuff,
+    models::{AgentRelation, AuthorityId, ClubItemType, ItemType, JobModel, Quality, Role},
+};
 use sqlx::{FromRow, PgPool};
 use std::collections::HashMap;
 use types::cards::poker::Card;
@@ -37,7 +40,6 @@ pub struct Product {
 #[derive(Clone, Debug)]
 pub struct MemberState {
     pub can_claim_daily: bool,
-    pub in_gamble: bool,
 }
 
 #[derive(Clone, Debug, FromRow)]
@@ -83,6 +85,7 @@ pub struct Member {
     pub state: MemberState,
     pub deck: Vec<Card>,
     pub club_id: Option<i64>,
+    pub buffs: Vec<ActiveBuff>,
 }
 
 impl From<ItemAmount> for Item {
@@ -41,15 +41,31 @@ impl System {
             "SELECT id, name, salary, required_role AS \"required_role: Role \", required_points, cooldown FROM job;"
         ).fetch_all(pool).await.unwrap();
 
-        // select id, name, price, description from store table
+        let shop = Self::load_shop(pool).await.unwrap();
+
+        Self {
+            top_money,
+            top_level,
+            shop,
+            jobs,
+        }
+    }
+
+    // stock changes with every purchase of a limited product
+    pub async fn refresh_shop(&mut self, pool: &PgPool) -> Result<(), Error> {
+        self.shop = Self::load_shop(pool).await?;
+        Ok(())
+    }
+
+    async fn load_shop(pool: &PgPool) -> Result<Vec<Product>, Error> {
         let shop = sqlx::query!(
-            "SELECT i.id, i.name, i.usable, i.quality AS \"quality: Quality\", item_type AS \"item_type: ItemType\", s.price
+            "SELECT i.id, i.name, i.usable, i.quality AS \"quality: Quality\", item_type AS \"item_type: ItemType\", s.price, s.stock
             FROM normal_item i JOIN
-            normal_shop s ON i.id = s.item;"
+            normal_shop s ON i.id = s.item
+            ORDER BY i.id;"
         )
         .fetch_all(pool)
-        .await
-        .unwrap();
+        .await?;
 
         let shop = shop
             .iter()
@@ -64,17 +80,18 @@ impl System {
                     two_handed: false,
                 },
                 price: item.price.unwrap_or_default(),
-                stock: None,
+                stock: item.stock,
                 description: None,
             })
             .collect::<Vec<Product>>();
 
-        Self {
-            top_money,
-            top_level,
-            shop,
-            jobs,
-        }
+        Ok(shop)
+    }
+
+    pub fn get_product(&self, item: i32) -> Option<&Product> {
+        self.shop
+            .iter()
+            .find(|product| product.item.id == Some(item))
     }
 
     pub async fn get_job_names(&self) -> Vec<String> {
@@ -117,6 +134,15 @@ impl System {
         Ok(item)
     }
 
+    // id and name of every item, for choices that aren't limited to an inventory
+    pub async fn get_item_names(&self, pool: &PgPool) -> Result<Vec<(i32, String)>, Error> {
+        let items = sqlx::query!("SELECT id, name FROM normal_item ORDER BY id;")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(items.into_iter().map(|item| (item.id, item.name)).collect())
+    }
+
     pub fn paginate_shop(&self, page_size: usize) -> Vec<Vec<Product>> {
         self.shop
             .chunks(page_size)
@@ -9,3 +9,894 @@ async fn create_member(pool: PgPool) {
 
     assert_eq!(res.id, 12)
 }
+
+#[sqlx::test]
+async fn escrow_release_and_refund(pool: PgPool) {
+    use crate::escrow::Escrow;
+
+    sqlx::query!("INSERT INTO member (id, balance) VALUES (1, 1000), (2, 1000), (3, 100);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    Escrow::open(&pool, 1, 10, "ecard").await.unwrap();
+    Escrow::open(&pool, 2, 10, "ecard").await.unwrap();
+    assert!(Escrow::open(&pool, 1, 11, "nim").await.is_err());
+
+    Escrow::hold(&pool, 10, &[1, 2], 500).await.unwrap();
+    Escrow::release(&pool, 10, Some(1)).await.unwrap();
+
+    let balances = sqlx::query!("SELECT id, balance FROM member WHERE id IN (1, 2) ORDER BY id;")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(balances[0].balance, 1500);
+    assert_eq!(balances[1].balance, 500);
+    assert!(!Escrow::is_playing(&pool, 1).await.unwrap());
+
+    // nobody is charged when one of the players can't pay
+    Escrow::open(&pool, 2, 12, "ecard").await.unwrap();
+    Escrow::open(&pool, 3, 12, "ecard").await.unwrap();
+    assert!(Escrow::hold(&pool, 12, &[2, 3], 200).await.is_err());
+
+    Escrow::hold(&pool, 12, &[2], 200).await.unwrap();
+    let refunded = Escrow::refund_all(&pool).await.unwrap();
+
+    let balance = sqlx::query!("SELECT balance FROM member WHERE id = 2;")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(refunded.len(), 2);
+    assert_eq!(balance.balance, 500);
+
+    // the loser of a room with three players pays both winners
+    for member in [1, 2, 3] {
+        Escrow::open(&pool, member, 13, "oldmaid").await.unwrap();
+    }
+    Escrow::hold(&pool, 13, &[1, 2, 3], 100).await.unwrap();
+    assert!(Escrow::split(&pool, 13, &[(1, 200), (2, 200)])
+        .await
+        .is_err());
+    Escrow::split(&pool, 13, &[(1, 150), (2, 150)])
+        .await
+        .unwrap();
+
+    let balances =
+        sqlx::query!("SELECT id, balance FROM member WHERE id IN (1, 2, 3) ORDER BY id;")
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .iter()
+            .map(|member| member.balance)
+            .collect::<Vec<i64>>();
+
+    assert_eq!(balances, vec![1550, 550, 0]);
+    assert!(!Escrow::is_playing(&pool, 3).await.unwrap());
+}
+
+#[sqlx::test]
+async fn gamble_log_history_and_summary(pool: PgPool) {
+    use crate::gamble::GambleLog;
+
+    GambleLog::versus("Contradict", 1, 2, 500)
+        .item(7, 1)
+        .item(7, 2)
+        .save(&pool)
+        .await
+        .unwrap();
+    GambleLog::versus("Contradict", 2, 1, 200)
+        .save(&pool)
+        .await
+        .unwrap();
+    GambleLog::solo("BlackJack", 1, -100)
+        .save(&pool)
+        .await
+        .unwrap();
+    GambleLog::solo("BlackJack", 1, 0)
+        .save(&pool)
+        .await
+        .unwrap();
+
+    let items = sqlx::query!("SELECT amount FROM gamble_items_log WHERE item = 7;")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(items.amount, 3);
+
+    let history = GambleLog::history(&pool, 1, 10).await.unwrap();
+    assert_eq!(history.len(), 4);
+    assert_eq!(history.iter().map(|game| game.net(1)).sum::<i64>(), 200);
+
+    let summary = GambleLog::summary(&pool, 1).await.unwrap();
+    let contradict = summary
+        .iter()
+        .find(|game| game.name == "Contradict")
+        .unwrap();
+    let blackjack = summary
+        .iter()
+        .find(|game| game.name == "BlackJack")
+        .unwrap();
+
+    assert_eq!(
+        (contradict.played, contradict.won, contradict.net),
+        (2, 1, 300)
+    );
+    assert_eq!(
+        (blackjack.played, blackjack.won, blackjack.net),
+        (2, 0, -100)
+    );
+}
+
+#[sqlx::test]
+async fn command_log_usage(pool: PgPool) {
+    use crate::commands::CommandLog;
+
+    sqlx::query!("INSERT INTO member (id) VALUES (1), (2);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    CommandLog::log(&pool, 1, "nim").await.unwrap();
+    CommandLog::log(&pool, 1, "nim").await.unwrap();
+    CommandLog::log(&pool, 2, "nim").await.unwrap();
+    CommandLog::log(&pool, 2, "club info").await.unwrap();
+
+    let top = CommandLog::top(&pool, 7, 10).await.unwrap();
+    assert_eq!((top[0].name.as_str(), top[0].uses), ("nim", 3));
+
+    let member = CommandLog::member(&pool, 2, 7, 10).await.unwrap();
+    assert_eq!(member.len(), 2);
+
+    let daily = CommandLog::daily(&pool, 7).await.unwrap();
+    assert_eq!(daily.len(), 1);
+    assert_eq!(daily[0].uses, 4);
+}
+
+#[sqlx::test]
+async fn shop_buy_with_stock(pool: PgPool) {
+    use crate::{
+        error::ShopError,
+        structs::{Member, System},
+    };
+
+    sqlx::query!("INSERT INTO member (id, balance) VALUES (1, 250);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO normal_shop (item, stock, price) VALUES (1, 2, 100), (2, NULL, 100);"
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let system = System::new(&pool).await;
+    let limited = system.get_product(1).unwrap().item.clone();
+    let unlimited = system.get_product(2).unwrap().item.clone();
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+
+    let res = member.buy(&pool, limited.clone(), 3).await;
+    assert!(matches!(res, Err(ShopError::OutOfStock)));
+
+    let purchase = member.buy(&pool, limited.clone(), 2).await.unwrap();
+    assert_eq!((purchase.total, purchase.stock), (200, Some(0)));
+    assert_eq!(member.get_bios(), 50);
+    assert_eq!(member.get_item_by_id(1).unwrap().amount, 2);
+
+    let res = member.buy(&pool, unlimited, 1).await;
+    assert!(matches!(res, Err(ShopError::InsufficientFunds)));
+
+    let balance = sqlx::query!("SELECT balance FROM member WHERE id = 1;")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(balance.balance, 50);
+}
+
+#[sqlx::test]
+async fn guild_shop_buy(pool: PgPool) {
+    use crate::{error::ShopError, structs::guild::Guild};
+
+    sqlx::query!("INSERT INTO member (id, balance) VALUES (1, 1000);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut guild = Guild::build(&pool, 10).await.unwrap();
+
+    let item = guild
+        .create_item(&pool, "badge", None, Some(20))
+        .await
+        .unwrap();
+
+    let res = guild.create_item(&pool, "badge", None, None).await;
+    assert!(matches!(res, Err(ShopError::ItemAlreadyExists)));
+
+    let res = guild.buy(&pool, 1, item.id, 1).await;
+    assert!(matches!(res, Err(ShopError::ItemNotFound)));
+
+    guild
+        .stock_item(&pool, "badge", 300, Some(3))
+        .await
+        .unwrap();
+
+    let purchase = guild.buy(&pool, 1, item.id, 3).await.unwrap();
+    assert_eq!((purchase.total, purchase.stock), (900, Some(0)));
+
+    let res = guild.buy(&pool, 1, item.id, 1).await;
+    assert!(matches!(res, Err(ShopError::OutOfStock)));
+
+    // a fresh load sees the same shop as the cached guild
+    let loaded = Guild::build(&pool, 10).await.unwrap();
+    assert_eq!(loaded.get_product(item.id).unwrap().stock, Some(0));
+    assert_eq!(loaded.items.len(), 1);
+}
+
+#[sqlx::test]
+async fn use_item_effects(pool: PgPool) {
+    use crate::{effects::Effect, error::EffectError, models::Buff, structs::Member};
+
+    sqlx::query!("INSERT INTO member (id) VALUES (1);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let items = sqlx::query!(
+        "SELECT id, name FROM normal_item WHERE name IN ('coffee', 'cake', 'diamond');"
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+
+    let id = |name: &str| items.iter().find(|item| item.name == name).unwrap().id;
+
+    sqlx::query!(
+        "INSERT INTO normal_inventory (item, member, amount) VALUES ($1, 1, 2), ($2, 1, 1), ($3, 1, 1);",
+        id("coffee"),
+        id("cake"),
+        id("diamond")
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+    let cooldown = member.get_work_cooldown();
+
+    let effect = member.use_item(&pool, id("coffee")).await.unwrap();
+    assert!(matches!(
+        effect,
+        Effect::Buff {
+            buff: Buff::WorkCooldown,
+            ..
+        }
+    ));
+    assert_eq!(member.get_item_by_id(id("coffee")).unwrap().amount, 1);
+    assert_eq!(member.get_work_cooldown(), cooldown / 2);
+
+    member.use_item(&pool, id("cake")).await.unwrap();
+    assert!(member.get_item_by_id(id("cake")).is_none());
+    assert_eq!(member.get_points(), 500);
+
+    let res = member.use_item(&pool, id("diamond")).await;
+    assert!(matches!(res, Err(EffectError::NotUsable)));
+
+    // buffs survive a reload of the member
+    let mut member = Member::build(&pool, 1).await.unwrap();
+    assert_eq!(member.get_buff(Buff::WorkCooldown), Some(50));
+    assert_eq!(
+        member
+            .consume_buff(&pool, Buff::WorkCooldown)
+            .await
+            .unwrap(),
+        Some(50)
+    );
+    assert_eq!(member.get_buff(Buff::WorkCooldown), None);
+
+    // an effect isn't enough when the item isn't flagged as usable
+    sqlx::query!("UPDATE normal_item SET usable = false WHERE name = 'coffee';")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+    let res = member.use_item(&pool, id("coffee")).await;
+    assert!(matches!(res, Err(EffectError::NotUsable)));
+}
+
+#[sqlx::test]
+async fn market_take_expire_and_tax(pool: PgPool) {
+    use crate::market::Market;
+
+    sqlx::query!("INSERT INTO member (id) VALUES (1);")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    let id = Market::create(&mut tx, 1, 1, 2, 1000).await.unwrap();
+    let tax = Market::collect(&mut tx, 1000).await.unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(tax, 50);
+    assert_eq!(Market::collected(&pool).await.unwrap(), 50);
+    assert_eq!(Market::browse(&pool, Some(1), None).await.unwrap().len(), 1);
+
+    // only the first one to take a listing gets it
+    let mut tx = pool.begin().await.unwrap();
+    assert!(Market::take(&mut tx, id, Some(2)).await.unwrap().is_none());
+    assert!(Market::take(&mut tx, id, None).await.unwrap().is_some());
+    assert!(Market::take(&mut tx, id, None).await.unwrap().is_none());
+    tx.rollback().await.unwrap();
+
+    sqlx::query!(
+        "UPDATE market_listing SET expires_at = TIMEZONE('UTC', NOW()) WHERE id = $1;",
+        id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    assert!(Market::get(&pool, id).await.unwrap().is_none());
+    assert_eq!(Market::expire(&pool).await.unwrap().len(), 1);
+
+    let inventory =
+        sqlx::query!("SELECT amount FROM normal_inventory WHERE member = 1 AND item = 1;")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+    assert_eq!(inventory.amount, 2);
+}
+
+#[sqlx::test]
+async fn quests_progress_and_rewards(pool: PgPool) {
+    use crate::{quests::QuestEvent, structs::Member};
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+    assert_eq!(member.load_quests(&pool).await.unwrap().len(), 3);
+
+    // swap the random quests for known ones
+    sqlx::query!("DELETE FROM member_random_quests WHERE member = 1;")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query!(
+        "INSERT INTO member_random_quests (member, quest)
+        SELECT 1, id FROM random_quest
+        WHERE name IN ('work-shifts', 'play-blackjack', 'bet-bios');"
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let bios = member.get_bios();
+
+    for _ in 0..2 {
+        let completed = member
+            .progress_quests(&pool, &QuestEvent::Work)
+            .await
+            .unwrap();
+        assert!(completed.is_empty());
+    }
+
+    let completed = member
+        .progress_quests(&pool, &QuestEvent::Work)
+        .await
+        .unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(member.get_bios(), bios + 2000);
+    assert_eq!(member.get_points(), 250);
+
+    // completed quests don't move anymore
+    let completed = member
+        .progress_quests(&pool, &QuestEvent::Work)
+        .await
+        .unwrap();
+    assert!(completed.is_empty());
+
+    let event = QuestEvent::Game(String::from("Roulette"));
+    member.progress_quests(&pool, &event).await.unwrap();
+    member
+        .progress_quests(&pool, &QuestEvent::Bet(i64::MAX))
+        .await
+        .unwrap();
+
+    let quests = member.load_quests(&pool).await.unwrap();
+    let quest = |name: &str| quests.iter().find(|quest| quest.name == name).unwrap();
+
+    assert!(quest("work-shifts").completed);
+    assert_eq!(quest("play-blackjack").progress, 0);
+    assert!(quest("bet-bios").completed);
+    assert_eq!(quest("bet-bios").progress, 10000);
+    assert_eq!(member.quest_streak(&pool).await.unwrap(), 0);
+}
+
+#[sqlx::test]
+async fn daily_streak_and_saver(pool: PgPool) {
+    use crate::{
+        daily::{self, Daily, STREAK_SAVER},
+        structs::Member,
+    };
+
+    let monday = Daily::get(&pool, 0).await.unwrap().unwrap();
+    assert_eq!(monday.item_name.as_deref(), Some("coffee"));
+
+    let milestones = Daily::milestones(&pool).await.unwrap();
+    assert_eq!(daily::streak_percent(&milestones, 2), 0);
+    assert_eq!(daily::streak_percent(&milestones, 8), 25);
+    assert_eq!(daily::next_milestone(&milestones, 8).unwrap().streak, 14);
+    assert_eq!(monday.scaled(10), (4950, 2750));
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+
+    // claimed two days ago but not yesterday
+    sqlx::query!(
+        "INSERT INTO daily_reward (member, claimed_at)
+        VALUES (1, TIMEZONE('UTC', NOW()) - INTERVAL '2 days');"
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(member.daily_streak(&pool).await.unwrap(), 0);
+    assert!(!member.save_streak(&pool).await.unwrap());
+
+    sqlx::query!(
+        "INSERT INTO normal_inventory (item, member, amount)
+        SELECT id, 1, 1 FROM normal_item WHERE name = $1;",
+        STREAK_SAVER
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let mut member = Member::build(&pool, 1).await.unwrap();
+    assert!(member.save_streak(&pool).await.unwrap());
+    assert!(member.get_item(String::from(STREAK_SAVER)).is_err());
+    assert_eq!(member.daily_streak(&pool).await.unwrap(), 2);
+
+    // yesterday is already claimed now
+    assert!(!member.save_streak(&pool).await.unwrap());
+}
+
+#[sqlx::test]
+async fn league_rollover_pays_and_promotes(pool: PgPool) {
+    use crate::{
+        league::{self, League},
+        structs::Member,
+    };
+
+    assert_eq!(league::next_tier(0, 1, 8), 1);
+    assert_eq!(league::next_tier(4, 2, 8), 4);
+    assert_eq!(league::next_tier(2, 8, 8), 1);
+    // small groups don't demote
+    assert_eq!(league::next_tier(2, 5, 5), 2);
+
+    for id in 1..=8 {
+        let mut member = Member::build(&pool, id).await.unwrap();
+        member.increase_points(&pool, id as i32 * 10).await.unwrap();
+    }
+
+    let group = League::group(&pool, 1).await.unwrap().unwrap();
+    assert_eq!(group.tier, 0);
+
+    let standings = League::standings(&pool, group.id).await.unwrap();
+    assert_eq!(standings.len(), 8);
+    assert_eq!(standings[0].member, 8);
+
+    // nothing to close during the same week
+    assert!(League::rollover(&pool).await.unwrap().is_empty());
+
+    sqlx::query!(
+        "UPDATE week_group SET created_at = created_at - INTERVAL '8 days' WHERE id = $1;",
+        group.id
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let payouts = League::rollover(&pool).await.unwrap();
+    assert_eq!(payouts.len(), 8);
+
+    let first = payouts.iter().find(|payout| payout.member == 8).unwrap();
+    assert_eq!(first.rank, 1);
+    assert_eq!(first.tier, 1);
+    assert_eq!(first.reward, Some((20000, 30)));
+
+    let last = payouts.iter().find(|payout| payout.member == 1).unwrap();
+    assert_eq!(last.reward, None);
+
+    // the payout moved the winner into a group of its new tier
+    let group = League::group(&pool, 8).await.unwrap().unwrap();
+    assert_eq!(group.tier, 1);
+    assert_eq!(League::month_of(&pool, 8).await.unwrap(), 30);
+
+    // groups are paid once
+    assert!(League::rollover(&pool).await.unwrap().is_empty());
+}
+
+#[sqlx::test]
+async fn season_lifecycle(pool: PgPool) {
+    use crate::{
+        error::SeasonError,
+        models::RpgState,
+        player::{self, Player, Season},
+        structs::Member,
+    };
+
+    assert_eq!(player::level_up(1, 0, 250), (2, 150));
+    assert_eq!(player::level_up(1, 0, 300), (3, 0));
+    assert_eq!(
+        player::level_up(player::MAX_LEVEL, 0, 500).0,
+        player::MAX_LEVEL
+    );
+
+    for id in 1..=2 {
+        Member::build(&pool, id).await.unwrap();
+    }
+
+    assert!(matches!(
+        Season::running(&pool).await,
+        Err(SeasonError::NoSeason)
+    ));
+
+    let season = Season::start(&pool).await.unwrap();
+    assert!(matches!(
+        Season::start(&pool).await,
+        Err(SeasonError::AlreadyRunning)
+    ));
+
+    let mut first = Player::join(&pool, &season, 1).await.unwrap();
+    assert!(matches!(
+        Player::join(&pool, &season, 1).await,
+        Err(SeasonError::AlreadyJoined)
+    ));
+    assert_eq!(
+        first
+            .add_experience(&mut pool.acquire().await.unwrap(), &season.rules(), 100)
+            .await
+            .unwrap(),
+        1
+    );
+
+    let season = Season::nightmare(&pool).await.unwrap();
+    assert_eq!(season.state, RpgState::Nightmare);
+    assert!(matches!(
+        Player::join(&pool, &season, 2).await,
+        Err(SeasonError::JoinClosed)
+    ));
+
+    // experience doubles in the nightmare
+    first
+        .add_experience(&mut pool.acquire().await.unwrap(), &season.rules(), 100)
+        .await
+        .unwrap();
+    assert_eq!((first.level, first.experience), (3, 0));
+
+    let (season, results) = Season::end(&pool).await.unwrap();
+    assert_eq!(season.state, RpgState::End);
+    assert_eq!(results.len(), 1);
+    assert_eq!((results[0].player, results[0].level), (1, 3));
+
+    let player = Player::get(&pool, season.id, 1).await.unwrap().unwrap();
+    assert!(!player.playing);
+    assert!(Season::current(&pool).await.unwrap().is_none());
+
+    // a new season can start once the last one ended
+    Season::start(&pool).await.unwrap();
+}
+
+#[sqlx::test]
+async fn equipment_slots_and_stats(pool: PgPool) {
+    use crate::{
+        error::EquipError,
+        player::{Player, Season, Slot, Stats},
+        structs::Member,
+    };
+
+    Member::build(&pool, 1).await.unwrap();
+    let season = Season::start(&pool).await.unwrap();
+    let player = Player::join(&pool, &season, 1).await.unwrap();
+
+    let id = |name: &'static str| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query!("SELECT id FROM rpg_item WHERE name = $1;", name)
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+                .id
+        }
+    };
+
+    let sword = id("wooden-sword").await;
+    let cap = id("leather-cap").await;
+    let axe = id("great-axe").await;
+    let shield = id("wooden-shield").await;
+    let ring = id("copper-ring").await;
+
+    for item in [axe, shield, ring, ring] {
+        player.add_item(&pool, item, 1).await.unwrap();
+    }
+
+    assert!(matches!(
+        player.equip(&pool, cap, Some(Slot::Chest)).await,
+        Err(EquipError::WrongSlot)
+    ));
+    player.equip(&pool, cap, None).await.unwrap();
+    player.equip(&pool, sword, None).await.unwrap();
+    player.equip(&pool, ring, None).await.unwrap();
+
+    // the second ring goes to the free slot
+    let equipment = player.equip(&pool, ring, None).await.unwrap();
+    assert_eq!(equipment.get(Slot::SecondRing).unwrap().id, ring);
+
+    // the axe takes both hands and the sword goes back
+    let equipment = player.equip(&pool, axe, None).await.unwrap();
+    assert_eq!(equipment.get(Slot::SecondHand).unwrap().id, axe);
+    assert_eq!(player.item_amount(&pool, sword).await.unwrap(), 1);
+
+    // base 6/0/70/100, cap +2 def +5 life, rings +1 dmg +5 acc each,
+    // epic axe 15 * 1.35 dmg and -5 * 1.35 acc
+    let stats = equipment.stats(player.level);
+    assert_eq!(
+        stats,
+        Stats {
+            damage: 6 + 2 + 20,
+            defense: 2,
+            accuracy: 70 + 10 - 6,
+            max_life: 105,
+            ..Default::default()
+        }
+    );
+
+    // a shield in a hand leaves the axe out of both
+    let equipment = player
+        .equip(&pool, shield, Some(Slot::SecondHand))
+        .await
+        .unwrap();
+    assert!(equipment.get(Slot::FirstHand).is_none());
+    assert_eq!(player.item_amount(&pool, axe).await.unwrap(), 1);
+
+    assert!(matches!(
+        player.unequip(&pool, Slot::FirstHand).await,
+        Err(EquipError::EmptySlot)
+    ));
+    player.unequip(&pool, Slot::SecondHand).await.unwrap();
+    assert_eq!(player.item_amount(&pool, shield).await.unwrap(), 1);
+}
+
+#[sqlx::test]
+async fn craft_consumes_components(pool: PgPool) {
+    use crate::{
+        error::CraftError,
+        models::Quality,
+        player::{self, Player, Season, CRAFTING},
+        structs::Member,
+    };
+
+    assert_eq!(
+        player::roll_quality(Quality::Common, 10, 0),
+        Quality::Common
+    );
+    assert_eq!(
+        player::roll_quality(Quality::Common, 90, 10),
+        Quality::Normal
+    );
+    assert_eq!(player::roll_quality(Quality::Common, 99, 40), Quality::Epic);
+    assert_eq!(
+        player::roll_quality(Quality::Normal, 99, 40),
+        Quality::Masterpiece
+    );
+    assert_eq!(
+        player::roll_quality(Quality::Epic, 99, 0),
+        Quality::Masterpiece
+    );
+
+    Member::build(&pool, 1).await.unwrap();
+    let season = Season::start(&pool).await.unwrap();
+    let player = Player::join(&pool, &season, 1).await.unwrap();
+
+    let wood = sqlx::query!("SELECT id FROM rpg_item WHERE name = 'wood';")
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .id;
+
+    let recipe = player
+        .recipes(&pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|recipe| recipe.name == "wooden-sword")
+        .unwrap();
+    assert!(!recipe.can_craft());
+
+    assert!(matches!(
+        player.craft(&pool, &season.rules(), recipe.item).await,
+        Err(CraftError::MissingComponents)
+    ));
+
+    player.add_item(&pool, wood, 20).await.unwrap();
+
+    for _ in 0..4 {
+        player
+            .craft(&pool, &season.rules(), recipe.item)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(player.item_amount(&pool, wood).await.unwrap(), 8);
+
+    // the starter sword plus the crafted ones, whatever their quality
+    let swords = player
+        .inventory(&pool)
+        .await
+        .unwrap()
+        .iter()
+        .filter(|item| item.name == "wooden-sword")
+        .map(|item| item.amount)
+        .sum::<i32>();
+    assert_eq!(swords, 5);
+
+    // 4 crafts of 3 components give 120 experience, crafting needs 120 for level 2
+    let mut conn = pool.acquire().await.unwrap();
+    assert_eq!(player.skill_level(&mut *conn, CRAFTING).await.unwrap(), 2);
+}
+
+#[sqlx::test]
+async fn skill_points_and_stats(pool: PgPool) {
+    use crate::{
+        error::SkillError,
+        models::SkillStat,
+        player::{self, Player, Season, COMBAT},
+        structs::Member,
+    };
+
+    // looting needs 80% of the experience of the player curve
+    assert_eq!(player::skill_experience(80, 2), 160);
+    assert_eq!(player::skill_level_up(80, 100, 1, 0, 250), (3, 10));
+    assert_eq!(player::skill_level_up(100, 2, 1, 0, 1000), (2, 0));
+
+    Member::build(&pool, 1).await.unwrap();
+    let season = Season::start(&pool).await.unwrap();
+    let player = Player::join(&pool, &season, 1).await.unwrap();
+
+    let combat = player
+        .skills(&pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|skill| skill.name == COMBAT)
+        .unwrap();
+    assert_eq!((combat.level, combat.available_points), (1, 0));
+
+    let base = player.stats(&pool).await.unwrap();
+
+    let mut conn = pool.acquire().await.unwrap();
+    let gained = player
+        .add_skill_experience(&mut conn, COMBAT, 300)
+        .await
+        .unwrap();
+    assert_eq!(gained, 2);
+
+    assert!(matches!(
+        player
+            .allocate(&pool, combat.id, SkillStat::Quantity, 1)
+            .await,
+        Err(SkillError::WrongStat)
+    ));
+    assert!(matches!(
+        player
+            .allocate(&pool, combat.id, SkillStat::Damage, 3)
+            .await,
+        Err(SkillError::NotEnoughPoints)
+    ));
+
+    player
+        .allocate(&pool, combat.id, SkillStat::Damage, 2)
+        .await
+        .unwrap();
+
+    let stats = player.stats(&pool).await.unwrap();
+    assert_eq!(
+        stats.damage,
+        base.damage + 2 * player::point_value(SkillStat::Damage)
+    );
+
+    assert_eq!(player.reset_skill(&pool, combat.id).await.unwrap(), 2);
+    assert!(matches!(
+        player.reset_skill(&pool, combat.id).await,
+        Err(SkillError::NothingToReset)
+    ));
+
+    let combat = player
+        .skills(&pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|skill| skill.name == COMBAT)
+        .unwrap();
+    assert_eq!((combat.level, combat.available_points), (3, 2));
+    assert_eq!(player.stats(&pool).await.unwrap(), base);
+}
+
+#[sqlx::test]
+async fn fight_rewards_and_counts(pool: PgPool) {
+    use crate::{
+        error::FightError,
+        player::{Player, Season},
+        structs::Member,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    Member::build(&pool, 1).await.unwrap();
+    let season = Season::start(&pool).await.unwrap();
+    let mut player = Player::join(&pool, &season, 1).await.unwrap();
+
+    // a level 1 player only sees monsters up to level 3
+    let names = player
+        .monsters(&pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|monster| monster.name)
+        .collect::<Vec<String>>();
+    assert_eq!(names, vec!["slime", "wolf"]);
+
+    let troll = sqlx::query!("SELECT id FROM rpg_monster WHERE name = 'troll';")
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .id;
+    assert!(matches!(
+        player.monster(&pool, troll).await,
+        Err(FightError::TooStrong)
+    ));
+    assert!(matches!(
+        player.monster(&pool, -1).await,
+        Err(FightError::UnknownMonster)
+    ));
+
+    let slime = player.monsters(&pool).await.unwrap().remove(0);
+    assert_eq!(slime.fighter(150).max_life, 60);
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let reward = player
+        .finish_fight(&pool, &season.rules(), &slime, true, &mut rng)
+        .await
+        .unwrap();
+    assert_eq!(reward.experience, 20);
+
+    let wood = sqlx::query!("SELECT id FROM rpg_item WHERE name = 'wood';")
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .id;
+    let looted = reward.loot.iter().map(|(_, amount)| *amount).sum::<i32>();
+    assert_eq!(player.item_amount(&pool, wood).await.unwrap(), looted);
+
+    // a lost fight gives a quarter of the experience and nothing else
+    let reward = player
+        .finish_fight(&pool, &season.rules(), &slime, false, &mut rng)
+        .await
+        .unwrap();
+    assert_eq!((reward.experience, reward.loot.len()), (5, 0));
+
+    let stats = sqlx::query!("SELECT fights, kills FROM normal_stats WHERE id = 1;")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!((stats.fights, stats.kills), (Some(2), Some(1)));
+
+    let player = Player::get(&pool, season.id, 1).await.unwrap().unwrap();
+    assert_eq!((player.level, player.experience), (1, 25));
+}
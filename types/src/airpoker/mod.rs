@@ -5,6 +5,31 @@ pub use player::{AirBios, Player};
 use poise::serenity_prelude::UserId;
 use std::sync::atomic::AtomicU8;
 
+// one round per steel card in hand
+pub const ROUNDS: u8 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Selecting,
+    Betting,
+    Finished,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BetAction {
+    // matches the rival bet, a check when they are already even
+    Call,
+    Raise(u8),
+    Fold,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoundResult {
+    Win { winner: UserId, tanks: u8 },
+    Fold { winner: UserId, tanks: u8 },
+    Draw,
+}
+
 #[derive(Debug)]
 pub struct AirPoker {
     // progresive blind
@@ -15,6 +40,13 @@ pub struct AirPoker {
     pub bet_timeout: Option<AtomicU8>,
     // select card round timeout
     pub select_card_timeout: Option<AtomicU8>,
+    pub round: u8,
+    pub phase: Phase,
+    // index of the player that has to bet
+    pub turn: usize,
+    // bet actions since the last raise
+    pub actions: u8,
+    pub folded: Option<UserId>,
 }
 
 impl AirPoker {
@@ -24,6 +56,11 @@ impl AirPoker {
             players: vec![a, b],
             bet_timeout: None,
             select_card_timeout: None,
+            round: 1,
+            phase: Phase::Selecting,
+            turn: 0,
+            actions: 0,
+            folded: None,
         }
     }
 
@@ -162,4 +199,217 @@ impl AirPoker {
             .find(|player| player.selected_card.is_none())
             .ok_or_else(|| "Player not found".into())
     }
+
+    pub fn get_rival(&self, id: UserId) -> Result<&Player, Error> {
+        self.players
+            .iter()
+            .find(|player| player.id != id)
+            .ok_or_else(|| "Player not found".into())
+    }
+
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.turn]
+    }
+
+    // new cards are only dealt once, every round uses one of them
+    pub fn start_round(&mut self) {
+        self.reset_selected_cards();
+        self.set_players_blind();
+        self.delete_bet_timeout();
+        self.set_select_card_timeout();
+        self.folded = None;
+        self.actions = 0;
+        self.phase = Phase::Selecting;
+    }
+
+    pub fn select(&mut self, id: UserId, index: usize) -> Result<(), Error> {
+        if self.phase != Phase::Selecting {
+            return Err("Cannot select a card now".into());
+        }
+
+        let player = self.get_mut_player(id)?;
+
+        if player.selected_card.is_some() {
+            return Err("Card already selected".into());
+        }
+
+        if index >= player.hand.len() {
+            return Err("Invalid card".into());
+        }
+
+        player.select_card(index);
+        Ok(())
+    }
+
+    // the card round ran out, missing cards are picked at random
+    pub fn select_missing(&mut self) {
+        while let Ok(player) = self.find_not_selected() {
+            player.select_random_card();
+        }
+    }
+
+    // players take turns opening the betting each round
+    pub fn start_betting(&mut self) {
+        self.delete_select_card_timeout();
+        self.set_bet_timeout();
+        self.turn = (self.round as usize + 1) % self.players.len();
+        self.phase = Phase::Betting;
+    }
+
+    // returns true once the betting is closed
+    pub fn bet(&mut self, id: UserId, action: BetAction) -> Result<bool, Error> {
+        if self.phase != Phase::Betting {
+            return Err("Cannot bet now".into());
+        }
+
+        if self.current_player().id != id {
+            return Err("Not your turn".into());
+        }
+
+        let rival_bet = self.get_rival(id)?.bet;
+        let player = &mut self.players[self.turn];
+        let betable = player.get_betable_air_bios() as u8;
+
+        match action {
+            BetAction::Call => {
+                player.bet = rival_bet.min(betable).max(player.bet);
+                self.actions += 1;
+            }
+            BetAction::Raise(amount) => {
+                if amount <= rival_bet || amount > betable {
+                    return Err("Invalid raise".into());
+                }
+
+                player.bet = amount;
+                self.actions = 1;
+            }
+            BetAction::Fold => {
+                self.folded = Some(id);
+            }
+        }
+
+        if self.is_betting_over() {
+            self.delete_bet_timeout();
+            return Ok(true);
+        }
+
+        self.turn = (self.turn + 1) % self.players.len();
+        self.set_bet_timeout();
+
+        Ok(false)
+    }
+
+    fn is_betting_over(&self) -> bool {
+        if self.folded.is_some() {
+            return true;
+        }
+
+        let (a, b) = (&self.players[0], &self.players[1]);
+        let matched = a.bet == b.bet || a.is_all_in() || b.is_all_in();
+
+        self.actions >= 2 && matched
+    }
+
+    // the loser hands over the tanks they bet, capped by what the winner risked
+    pub fn showdown(&mut self) -> RoundResult {
+        let result = match self.folded {
+            Some(folded) => {
+                let winner = self.get_rival(folded).unwrap().id;
+                let tanks = self.get_player(folded).unwrap().bet;
+
+                RoundResult::Fold { winner, tanks }
+            }
+            None => {
+                let a = self.players[0].selected_card.clone().unwrap().hand;
+                let b = self.players[1].selected_card.clone().unwrap().hand;
+
+                let winner = match compare_hands(&a, &b) {
+                    std::cmp::Ordering::Greater => Some(0),
+                    std::cmp::Ordering::Less => Some(1),
+                    std::cmp::Ordering::Equal => None,
+                };
+
+                match winner {
+                    Some(index) => {
+                        let tanks = self.players[0].bet.min(self.players[1].bet);
+                        RoundResult::Win {
+                            winner: self.players[index].id,
+                            tanks,
+                        }
+                    }
+                    None => RoundResult::Draw,
+                }
+            }
+        };
+
+        if let RoundResult::Win { winner, tanks } | RoundResult::Fold { winner, tanks } = result {
+            let loser = self.get_rival(winner).unwrap().id;
+
+            self.get_mut_player(loser)
+                .unwrap()
+                .remove_air_bios(tanks as usize);
+            self.get_mut_player(winner)
+                .unwrap()
+                .add_air_bios(tanks as usize);
+        }
+
+        self.next_round();
+        result
+    }
+
+    fn next_round(&mut self) {
+        if self.round == ROUNDS || self.empty_hands() {
+            self.finish();
+            return;
+        }
+
+        self.round += 1;
+        self.blind += 1;
+        self.start_round();
+    }
+
+    // every second the active tanks drain, returns false when someone ran out of air
+    pub fn breathe(&mut self) -> bool {
+        let mut alive = true;
+
+        for player in self.players.iter_mut() {
+            if !player.consume_air_bio() {
+                alive = false;
+            }
+        }
+
+        if !alive {
+            self.finish();
+        }
+
+        alive
+    }
+
+    fn finish(&mut self) {
+        self.delete_bet_timeout();
+        self.delete_select_card_timeout();
+        self.phase = Phase::Finished;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.phase == Phase::Finished
+    }
+
+    // a dead player always loses, otherwise the one with more air wins
+    pub fn winner(&self) -> Option<&Player> {
+        let (a, b) = (&self.players[0], &self.players[1]);
+
+        match (a.is_alive, b.is_alive) {
+            (true, false) => return Some(a),
+            (false, true) => return Some(b),
+            (false, false) => return None,
+            _ => {}
+        }
+
+        match a.air_bios.len().cmp(&b.air_bios.len()) {
+            std::cmp::Ordering::Greater => Some(a),
+            std::cmp::Ordering::Less => Some(b),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
 }
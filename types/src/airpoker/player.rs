@@ -177,6 +177,20 @@ impl Player {
         });
     }
 
+    // tanks won in a showdown arrive full
+    pub fn add_air_bios(&mut self, amount: usize) {
+        for _ in 0..amount {
+            self.air_bios.push(AirBios {
+                duration: AtomicU8::new(30),
+                is_active: false,
+            });
+        }
+    }
+
+    pub fn is_all_in(&self) -> bool {
+        self.bet as usize >= self.get_betable_air_bios()
+    }
+
     // delete used air bios without duration
     pub fn clean_air_bios(&mut self) {
         // this will conserve the air bios with duration
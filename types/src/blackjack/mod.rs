@@ -1,10 +1,28 @@
-use std::sync::atomic::AtomicU8;
-
 pub mod player;
 pub use player::{Dealer, Player, State};
 
-use crate::cards::poker::Card;
-use poise::serenity_prelude::User;
+pub mod seat;
+pub use seat::Seat;
+
+pub mod shoe;
+pub use shoe::Shoe;
+
+use crate::{cards::poker::Card, Error};
+use poise::serenity_prelude::{User, UserId};
+
+pub const MAX_HANDS: usize = 4;
+pub const MAX_SEATS: usize = 5;
+
+// cards are drawn from a member deck or a table shoe
+pub trait Deck {
+    fn draw(&mut self) -> Card;
+}
+
+impl Deck for Vec<Card> {
+    fn draw(&mut self) -> Card {
+        self.pop().unwrap()
+    }
+}
 
 pub enum RoundResult {
     Draw,
@@ -12,31 +30,84 @@ pub enum RoundResult {
     Lose { bust: bool },
 }
 
+impl RoundResult {
+    // balance change of a hand, blackjack pays 3:2
+    pub fn payout(&self, bet: i64) -> i64 {
+        match self {
+            RoundResult::Draw => 0,
+            RoundResult::Win {
+                state: State::Blackjack,
+            } => bet * 3 / 2,
+            RoundResult::Win { .. } => bet,
+            RoundResult::Lose { .. } => -bet,
+        }
+    }
+}
+
 pub struct Blackjack {
-    pub player: Player,
+    pub seats: Vec<Seat>,
+    pub turn: usize,
     pub dealer: Dealer,
-    pub timeout: Option<AtomicU8>,
+    pub peeked: bool,
 }
 
 impl Blackjack {
     pub fn new(user: User, bet: i64) -> Self {
-        let player = Player::new(user, bet);
-
         Self {
-            player,
+            seats: vec![Seat::new(user, bet)],
+            turn: 0,
             dealer: Dealer::default(),
-            timeout: None,
+            peeked: false,
+        }
+    }
+
+    pub fn add_seat(&mut self, user: User, bet: i64) -> Result<(), Error> {
+        if self.seats.len() == MAX_SEATS {
+            return Err("Table is full".into());
         }
+
+        if self.get_seat(user.id).is_some() {
+            return Err("Already seated".into());
+        }
+
+        self.seats.push(Seat::new(user, bet));
+        Ok(())
+    }
+
+    pub fn remove_seat(&mut self, id: UserId) {
+        self.seats.retain(|seat| seat.id != id);
     }
 
-    pub fn player_wins(&self) -> bool {
-        match self.player.state {
+    pub fn get_seat(&self, id: UserId) -> Option<&Seat> {
+        self.seats.iter().find(|seat| seat.id == id)
+    }
+
+    pub fn get_seat_mut(&mut self, id: UserId) -> Option<&mut Seat> {
+        self.seats.iter_mut().find(|seat| seat.id == id)
+    }
+
+    // seat whose turn it is
+    pub fn seat(&self) -> &Seat {
+        &self.seats[self.turn]
+    }
+
+    pub fn seat_mut(&mut self) -> &mut Seat {
+        &mut self.seats[self.turn]
+    }
+
+    // hand that is being played
+    pub fn player(&self) -> &Player {
+        self.seat().player()
+    }
+
+    pub fn player_wins(&self, hand: &Player) -> bool {
+        match hand.state {
             State::Bust => false, // Jugador pierde si se pasa
             _ => {
                 if self.dealer.is_bust() {
                     true // Jugador gana si el dealer se pasa
                 } else {
-                    let player_value = self.player.hand_value();
+                    let player_value = hand.hand_value();
                     let dealer_value = self.dealer.hand_value(false);
 
                     player_value > dealer_value // Jugador gana si su puntaje es mayor
@@ -45,75 +116,207 @@ impl Blackjack {
         }
     }
 
-    pub fn round_result(&mut self) -> RoundResult {
-        if self.player.is_bust() {
+    pub fn round_result(&self, hand: &Player) -> RoundResult {
+        if hand.is_bust() {
             return RoundResult::Lose { bust: true };
         }
 
-        if self.player_wins() {
+        // naturals are settled before comparing values
+        match (hand.is_blackjack(), self.dealer.is_blackjack()) {
+            (true, true) => return RoundResult::Draw,
+            (true, false) => {
+                return RoundResult::Win {
+                    state: State::Blackjack,
+                }
+            }
+            (false, true) => return RoundResult::Lose { bust: false },
+            _ => {}
+        }
+
+        if self.player_wins(hand) {
             return RoundResult::Win {
-                state: self.player.state.clone(),
+                state: hand.state.clone(),
             };
         }
 
-        if self.dealer.is_bust() || self.dealer.hand_value(false) == self.player.hand_value() {
+        if self.dealer.hand_value(false) == hand.hand_value() {
             return RoundResult::Draw;
         }
 
         RoundResult::Lose { bust: false }
     }
 
-    pub fn set_timeout(&mut self) {
-        self.timeout = Some(AtomicU8::new(45));
+    pub fn round_results(&self, seat: &Seat) -> Vec<RoundResult> {
+        seat.hands
+            .iter()
+            .map(|hand| self.round_result(hand))
+            .collect()
     }
 
-    pub fn decrement_timeout(&self) {
-        use std::sync::atomic::Ordering;
-
-        if let Some(timeout) = self.timeout.as_ref() {
-            timeout.fetch_sub(1, Ordering::Relaxed);
+    // insurance pays 2:1 when the dealer has blackjack
+    pub fn insurance_payout(&self, seat: &Seat) -> i64 {
+        if seat.insurance == 0 {
+            0
+        } else if self.dealer.is_blackjack() {
+            seat.insurance * 2
+        } else {
+            -seat.insurance
         }
     }
 
-    pub fn is_timeout(&self) -> bool {
-        use std::sync::atomic::Ordering;
+    // net balance change of a seat for the whole round
+    pub fn settle(&self, seat: &Seat) -> i64 {
+        let hands = seat
+            .hands
+            .iter()
+            .map(|hand| self.round_result(hand).payout(hand.bet))
+            .sum::<i64>();
 
-        self.timeout
-            .as_ref()
-            .map(|timeout| timeout.load(Ordering::Relaxed) == 0)
-            .unwrap_or(false)
+        hands + self.insurance_payout(seat)
     }
 
     pub fn clear_hands(&mut self) {
-        self.player.hand.clear();
-        self.player.state = State::None;
+        for seat in self.seats.iter_mut() {
+            seat.clear_hands();
+        }
+
+        self.turn = 0;
+        self.peeked = false;
         self.dealer.hand.clear();
     }
 
-    pub fn deal_cards(&mut self, deck: &mut Vec<Card>) {
-        self.player.hand.push(deck.pop().unwrap());
-        self.dealer.hand.push(deck.pop().unwrap());
+    pub fn deal_cards(&mut self, deck: &mut impl Deck) {
+        for _ in 0..2 {
+            for seat in self.seats.iter_mut() {
+                seat.hands[0].hand.push(deck.draw());
+            }
+
+            self.dealer.hand.push(deck.draw());
+        }
+
+        for seat in self.seats.iter_mut() {
+            seat.hands[0].is_blackjack_and_set();
+        }
+
+        self.turn = 0;
+        self.seat_mut().set_timeout();
+        self.next_turn();
+    }
+
+    // insurance is offered before the dealer checks the hole card
+    pub fn insurance_open(&self) -> bool {
+        !self.peeked && self.dealer.shows_ace()
+    }
 
-        self.player.hand.push(deck.pop().unwrap());
-        self.dealer.hand.push(deck.pop().unwrap());
+    pub fn can_insure(&self, id: UserId) -> bool {
+        self.insurance_open()
+            && self
+                .get_seat(id)
+                .map(|seat| seat.can_insure())
+                .unwrap_or(false)
+    }
 
-        if self.player.is_blackjack() {
-            self.player.state = State::Blackjack;
+    pub fn take_insurance(&mut self, id: UserId) {
+        if let Some(seat) = self.get_seat_mut(id) {
+            seat.take_insurance();
         }
     }
 
-    pub fn player_hit(&mut self, deck: &mut Vec<Card>) {
-        let card = deck.pop().unwrap();
-        self.player.hand.push(card);
+    // dealer checks the hole card, returns true on dealer blackjack
+    pub fn peek(&mut self) -> bool {
+        self.peeked = true;
+        self.dealer.is_blackjack()
+    }
+
+    pub fn can_split(&self) -> bool {
+        self.seat().can_split()
+    }
+
+    pub fn players_done(&self) -> bool {
+        self.seats.iter().all(|seat| seat.is_done())
+    }
+
+    // round finishes early when the dealer peeked a blackjack
+    pub fn is_round_over(&self) -> bool {
+        self.players_done() || (self.peeked && self.dealer.is_blackjack())
+    }
 
-        if self.player.hand_value() > 21 {
-            self.player.state = State::Bust;
+    // turn goes to the first seat with hands left to play
+    fn next_turn(&mut self) {
+        if !self.seat().is_done() {
+            return;
+        }
+
+        self.seat_mut().clear_timeout();
+
+        if let Some(index) = self.seats.iter().position(|seat| !seat.is_done()) {
+            self.turn = index;
+            self.seat_mut().set_timeout();
         }
     }
 
-    pub fn dealer_hit(&mut self, deck: &mut Vec<Card>) {
+    pub fn decrement_timeout(&self) {
+        self.seat().decrement_timeout();
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.seat().is_timeout()
+    }
+
+    // seat out of time stands its remaining hands
+    pub fn seat_timeout(&mut self) {
+        let seat = self.seat_mut();
+        seat.stand_all();
+        seat.timed_out = true;
+
+        self.next_turn();
+    }
+
+    pub fn player_hit(&mut self, deck: &mut impl Deck) {
+        self.seat_mut().hit(deck);
+        self.next_turn();
+    }
+
+    pub fn player_stand(&mut self) {
+        self.seat_mut().stand();
+        self.next_turn();
+    }
+
+    pub fn double_down(&mut self, deck: &mut impl Deck) {
+        self.seat_mut().double_down(deck);
+        self.next_turn();
+    }
+
+    pub fn split(&mut self, deck: &mut impl Deck) {
+        self.seat_mut().split(deck);
+        self.next_turn();
+    }
+
+    pub fn stand_all(&mut self) {
+        for seat in self.seats.iter_mut() {
+            seat.stand_all();
+        }
+    }
+
+    pub fn dealer_hit(&mut self, deck: &mut impl Deck) {
         if self.dealer.hand_value(false) < 17 {
-            self.dealer.hand.push(deck.pop().unwrap());
+            self.dealer.hand.push(deck.draw());
+        }
+    }
+
+    // dealer draws to 17 unless every hand is already decided
+    pub fn dealer_play(&mut self, deck: &mut impl Deck) {
+        if self
+            .seats
+            .iter()
+            .flat_map(|seat| seat.hands.iter())
+            .all(|hand| hand.is_bust() || hand.is_blackjack())
+        {
+            return;
+        }
+
+        while self.dealer.hand_value(false) < 17 {
+            self.dealer_hit(deck);
         }
     }
 }
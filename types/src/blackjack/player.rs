@@ -17,6 +17,7 @@ pub struct Player {
     pub bet: i64,
     pub hand: Vec<Card>,
     pub state: State,
+    pub split: bool,
 }
 
 #[derive(Default)]
@@ -53,6 +54,15 @@ impl Dealer {
         self.hand_value(false) > 21
     }
 
+    pub fn is_blackjack(&self) -> bool {
+        self.hand.len() == 2 && self.hand_value(false) == 21
+    }
+
+    // first card is the one shown to the players
+    pub fn shows_ace(&self) -> bool {
+        self.hand.first().map(|card| card.is_ace()).unwrap_or(false)
+    }
+
     pub fn dbg_hand(&self, hidden: bool) -> String {
         let mut hand = self
             .hand
@@ -79,6 +89,7 @@ impl Player {
             bet,
             hand: Vec::new(),
             state: State::None,
+            split: false,
         }
     }
 
@@ -105,7 +116,7 @@ impl Player {
     }
 
     pub fn is_blackjack_and_set(&mut self) -> bool {
-        if self.hand.len() == 2 && self.hand_value() == 21 {
+        if !self.split && self.hand.len() == 2 && self.hand_value() == 21 {
             self.state = State::Blackjack;
             true
         } else {
@@ -123,6 +134,26 @@ impl Player {
         !self.is_blackjack_and_set() && !self.is_bust()
     }
 
+    // only undecided hands with two cards can double or split
+    pub fn can_double(&self) -> bool {
+        self.state == State::None && self.hand.len() == 2
+    }
+
+    pub fn can_split(&self) -> bool {
+        self.can_double() && self.hand[0].key_name() == self.hand[1].key_name()
+    }
+
+    // stand automatically on 21 and bust over it
+    pub fn check_hand(&mut self) {
+        let value = self.hand_value();
+
+        if value > 21 {
+            self.state = State::Bust;
+        } else if value == 21 {
+            self.state = State::Stand;
+        }
+    }
+
     pub fn hand_value(&self) -> i32 {
         let mut total = 0;
         let mut aces = 0;
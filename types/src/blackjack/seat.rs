@@ -0,0 +1,175 @@
+use std::sync::atomic::AtomicU8;
+
+use super::{Deck, Player, State, MAX_HANDS};
+use poise::serenity_prelude::{User, UserId};
+
+pub struct Seat {
+    pub id: UserId,
+    pub bet: i64,
+    pub hands: Vec<Player>,
+    pub current: usize,
+    pub insurance: i64,
+    pub timeout: Option<AtomicU8>,
+    pub timed_out: bool,
+}
+
+impl Seat {
+    pub fn new(user: User, bet: i64) -> Self {
+        Self {
+            id: user.id,
+            bet,
+            hands: vec![Player::new(user, bet)],
+            current: 0,
+            insurance: 0,
+            timeout: None,
+            timed_out: false,
+        }
+    }
+
+    // hand that is being played
+    pub fn player(&self) -> &Player {
+        &self.hands[self.current]
+    }
+
+    pub fn player_mut(&mut self) -> &mut Player {
+        &mut self.hands[self.current]
+    }
+
+    pub fn name(&self) -> &str {
+        &self.hands[0].name
+    }
+
+    // bios at risk, including insurance
+    pub fn total_bet(&self) -> i64 {
+        self.hands.iter().map(|hand| hand.bet).sum::<i64>() + self.insurance
+    }
+
+    // all hands stood, busted or got blackjack
+    pub fn is_done(&self) -> bool {
+        self.hands.iter().all(|hand| hand.state != State::None)
+    }
+
+    pub fn can_insure(&self) -> bool {
+        self.insurance == 0 && self.hands.len() == 1 && self.hands[0].can_double()
+    }
+
+    pub fn take_insurance(&mut self) {
+        self.insurance = self.bet / 2;
+    }
+
+    pub fn can_split(&self) -> bool {
+        self.hands.len() < MAX_HANDS && self.player().can_split()
+    }
+
+    pub fn set_timeout(&mut self) {
+        self.timeout = Some(AtomicU8::new(45));
+    }
+
+    pub fn decrement_timeout(&self) {
+        use std::sync::atomic::Ordering;
+
+        if let Some(timeout) = self.timeout.as_ref() {
+            timeout.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        self.timeout
+            .as_ref()
+            .map(|timeout| timeout.load(Ordering::Relaxed) == 0)
+            .unwrap_or(false)
+    }
+
+    pub fn clear_timeout(&mut self) {
+        self.timeout = None;
+    }
+
+    pub fn clear_hands(&mut self) {
+        self.hands.truncate(1);
+        self.current = 0;
+        self.insurance = 0;
+        self.timeout = None;
+        self.timed_out = false;
+
+        let bet = self.bet;
+        let player = self.player_mut();
+        player.hand.clear();
+        player.state = State::None;
+        player.split = false;
+        player.bet = bet;
+    }
+
+    fn next_hand(&mut self) {
+        if let Some(index) = self.hands.iter().position(|hand| hand.state == State::None) {
+            self.current = index;
+        }
+    }
+
+    pub fn hit(&mut self, deck: &mut impl Deck) {
+        let card = deck.draw();
+
+        let player = self.player_mut();
+        player.hand.push(card);
+        player.check_hand();
+
+        self.next_hand();
+    }
+
+    pub fn stand(&mut self) {
+        self.player_mut().state = State::Stand;
+        self.next_hand();
+    }
+
+    pub fn stand_all(&mut self) {
+        for hand in self.hands.iter_mut() {
+            if hand.state == State::None {
+                hand.state = State::Stand;
+            }
+        }
+    }
+
+    // doubles the hand bet, takes one card and stands
+    pub fn double_down(&mut self, deck: &mut impl Deck) {
+        let card = deck.draw();
+
+        let player = self.player_mut();
+        player.bet *= 2;
+        player.hand.push(card);
+
+        if player.hand_value() > 21 {
+            player.state = State::Bust;
+        } else {
+            player.state = State::Stand;
+        }
+
+        self.next_hand();
+    }
+
+    // splits a pair into two hands with the same bet
+    pub fn split(&mut self, deck: &mut impl Deck) {
+        let index = self.current;
+
+        let mut second = self.hands[index].clone();
+        second.hand = vec![self.hands[index].hand.pop().unwrap()];
+        second.split = true;
+        self.hands[index].split = true;
+
+        self.hands.insert(index + 1, second);
+
+        // split aces only receive one card each
+        let aces = self.hands[index].hand[0].is_ace();
+
+        for hand in self.hands[index..=index + 1].iter_mut() {
+            hand.hand.push(deck.draw());
+            hand.check_hand();
+
+            if aces && hand.state == State::None {
+                hand.state = State::Stand;
+            }
+        }
+
+        self.next_hand();
+    }
+}
@@ -0,0 +1,57 @@
+use super::Deck;
+use crate::cards::poker::Card;
+use rand::{seq::SliceRandom, Rng};
+
+pub const MAX_DECKS: usize = 8;
+
+pub struct Shoe {
+    pub cards: Vec<Card>,
+    pub decks: usize,
+    cut: usize,
+}
+
+impl Shoe {
+    pub fn new(decks: usize) -> Self {
+        let mut shoe = Self {
+            cards: Vec::new(),
+            decks: decks.clamp(1, MAX_DECKS),
+            cut: 0,
+        };
+
+        shoe.shuffle();
+        shoe
+    }
+
+    // refills the shoe and places the cut card between 60% and 80% of it
+    pub fn shuffle(&mut self) {
+        let mut rng = rand::thread_rng();
+
+        self.cards = (0..self.decks)
+            .flat_map(|_| Card::standart_deck())
+            .collect();
+        self.cards.shuffle(&mut rng);
+
+        let total = self.cards.len();
+        self.cut = total - rng.gen_range(total * 60 / 100..=total * 80 / 100);
+    }
+
+    // the shoe is reshuffled between rounds once the cut card comes out
+    pub fn reached_cut(&self) -> bool {
+        self.cards.len() <= self.cut
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+}
+
+impl Deck for Shoe {
+    // an empty shoe mid round is reshuffled instead of running dry
+    fn draw(&mut self) -> Card {
+        if self.cards.is_empty() {
+            self.shuffle();
+        }
+
+        self.cards.pop().unwrap()
+    }
+}
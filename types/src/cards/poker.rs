@@ -153,6 +153,35 @@ impl Card {
         }
     }
 
+    // rank and suit symbol, e.g. A♠
+    pub fn short_name(&self) -> String {
+        let rank = match *self {
+            Self::Two(_) => "2",
+            Self::Three(_) => "3",
+            Self::Four(_) => "4",
+            Self::Five(_) => "5",
+            Self::Six(_) => "6",
+            Self::Seven(_) => "7",
+            Self::Eight(_) => "8",
+            Self::Nine(_) => "9",
+            Self::Ten(_) => "10",
+            Self::Jack(_) => "J",
+            Self::Queen(_) => "Q",
+            Self::King(_) => "K",
+            Self::Ace(_) => "A",
+            Self::Joker(_) => return String::from("🃏"),
+        };
+
+        let suit = match self.suit() {
+            Suit::Clubs => "♣",
+            Suit::Diamonds => "♦",
+            Suit::Hearts => "♥",
+            Suit::Spades => "♠",
+        };
+
+        format!("{}{}", rank, suit)
+    }
+
     pub fn suit(&self) -> Suit {
         match *self {
             Self::Joker(s)
@@ -0,0 +1,103 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusKind {
+    // loses power life at the end of every round
+    Poison,
+    // skips its next turns
+    Stun,
+    // deals power percent less damage
+    Weaken,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Status {
+    pub kind: StatusKind,
+    pub turns: u32,
+    pub power: i32,
+}
+
+// a status a fighter can leave on whoever it hits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Affliction {
+    pub status: Status,
+    // percent of the hits that apply it
+    pub chance: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Fighter {
+    pub name: String,
+    pub life: i32,
+    pub max_life: i32,
+    pub damage: i32,
+    pub defense: i32,
+    // percent of the attacks that land before reaction and precision
+    pub accuracy: i32,
+    // goes first and dodges more
+    pub reaction: i32,
+    // lands more hits and critical ones
+    pub precision: i32,
+    pub affliction: Option<Affliction>,
+    pub statuses: Vec<Status>,
+    pub guarding: bool,
+}
+
+impl Fighter {
+    pub fn new(name: &str, max_life: i32, damage: i32, defense: i32, accuracy: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            life: max_life,
+            max_life,
+            damage,
+            defense,
+            accuracy,
+            reaction: 1,
+            precision: 1,
+            affliction: None,
+            statuses: Vec::new(),
+            guarding: false,
+        }
+    }
+
+    pub fn with_reflexes(mut self, reaction: i32, precision: i32) -> Self {
+        self.reaction = reaction;
+        self.precision = precision;
+        self
+    }
+
+    pub fn with_affliction(mut self, affliction: Affliction) -> Self {
+        self.affliction = Some(affliction);
+        self
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.life > 0
+    }
+
+    pub fn status(&self, kind: StatusKind) -> Option<&Status> {
+        self.statuses.iter().find(|status| status.kind == kind)
+    }
+
+    // the same status only refreshes its turns and keeps the strongest power,
+    // a status without turns does nothing
+    pub fn afflict(&mut self, status: Status) {
+        if status.turns == 0 {
+            return;
+        }
+
+        match self
+            .statuses
+            .iter_mut()
+            .find(|current| current.kind == status.kind)
+        {
+            Some(current) => {
+                current.turns = current.turns.max(status.turns);
+                current.power = current.power.max(status.power);
+            }
+            None => self.statuses.push(status),
+        }
+    }
+
+    pub fn hurt(&mut self, damage: i32) {
+        self.life = (self.life - damage).max(0);
+    }
+}
@@ -0,0 +1,35 @@
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LootEntry {
+    pub item: i32,
+    // percent
+    pub chance: u32,
+    pub min: u32,
+    pub max: u32,
+}
+
+// chance_bonus and amount_bonus are percents over the base of every entry
+pub fn roll_loot<R: Rng>(
+    rng: &mut R,
+    table: &[LootEntry],
+    chance_bonus: i32,
+    amount_bonus: i32,
+) -> Vec<(i32, u32)> {
+    table
+        .iter()
+        .filter_map(|entry| {
+            let chance = (entry.chance as i64 * (100 + chance_bonus.max(-100)) as i64 / 100)
+                .clamp(0, 100) as u32;
+
+            if rng.gen_range(0..100) >= chance {
+                return None;
+            }
+
+            let amount = rng.gen_range(entry.min..=entry.max.max(entry.min));
+            let amount = (amount as i64 * (100 + amount_bonus.max(0)) as i64 / 100) as u32;
+
+            Some((entry.item, amount.max(1)))
+        })
+        .collect()
+}
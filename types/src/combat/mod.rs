@@ -0,0 +1,474 @@
+pub mod fighter;
+pub use fighter::{Affliction, Fighter, Status, StatusKind};
+
+pub mod loot;
+pub use loot::{roll_loot, LootEntry};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+pub const MIN_HIT: i32 = 5;
+pub const MAX_HIT: i32 = 95;
+const CRITICAL_DAMAGE: i32 = 150;
+const MAX_CRITICAL: i32 = 50;
+// heavy attacks hit harder but miss more
+const HEAVY_DAMAGE: i32 = 160;
+const HEAVY_MISS: i32 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Player,
+    Monster,
+}
+
+impl Side {
+    pub fn rival(&self) -> Side {
+        match self {
+            Side::Player => Side::Monster,
+            Side::Monster => Side::Player,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Attack,
+    Heavy,
+    Defend,
+    Flee,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Fighting,
+    Won,
+    Lost,
+    Fled,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Hit {
+        by: Side,
+        damage: i32,
+        critical: bool,
+    },
+    Miss {
+        by: Side,
+    },
+    Guard {
+        by: Side,
+    },
+    Afflicted {
+        target: Side,
+        kind: StatusKind,
+    },
+    Tick {
+        target: Side,
+        kind: StatusKind,
+        damage: i32,
+    },
+    Stunned {
+        target: Side,
+    },
+    Fled,
+    FleeFailed,
+}
+
+// percent of the attacks of attacker that land on defender
+pub fn hit_chance(attacker: &Fighter, defender: &Fighter) -> i32 {
+    (attacker.accuracy + attacker.precision - defender.reaction / 2).clamp(MIN_HIT, MAX_HIT)
+}
+
+// damage of a hit before criticals, percent scales the damage of the attacker
+pub fn hit_damage(attacker: &Fighter, defender: &Fighter, percent: i32) -> i32 {
+    let mut damage = attacker.damage * percent / 100;
+
+    if let Some(weaken) = attacker.status(StatusKind::Weaken) {
+        damage = damage * (100 - weaken.power).max(0) / 100;
+    }
+
+    let damage = (damage - defender.defense).max(1);
+
+    match defender.guarding {
+        true => (damage / 2).max(1),
+        false => damage,
+    }
+}
+
+// a player against a monster, every roll comes from the seed so the same
+// seed and actions always end the same way
+pub struct Combat {
+    pub player: Fighter,
+    pub monster: Fighter,
+    pub round: u32,
+    pub state: State,
+    rng: StdRng,
+}
+
+impl Combat {
+    pub fn new(player: Fighter, monster: Fighter, seed: u64) -> Self {
+        Self {
+            player,
+            monster,
+            round: 0,
+            state: State::Fighting,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    // loot is rolled with the same generator once the fight is over
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    pub fn fighter(&self, side: Side) -> &Fighter {
+        match side {
+            Side::Player => &self.player,
+            Side::Monster => &self.monster,
+        }
+    }
+
+    // the monster always attacks, the order comes from reaction
+    pub fn round(&mut self, action: Action) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        if self.state != State::Fighting {
+            return events;
+        }
+
+        self.round += 1;
+        self.player.guarding = false;
+        self.monster.guarding = false;
+
+        match action {
+            Action::Flee => {
+                let chance =
+                    (50 + (self.player.reaction - self.monster.reaction) * 5).clamp(10, 90);
+
+                if self.rng.gen_range(0..100) < chance {
+                    self.state = State::Fled;
+                    events.push(Event::Fled);
+                    return events;
+                }
+
+                events.push(Event::FleeFailed);
+                self.act(Side::Monster, Action::Attack, &mut events);
+            }
+            _ => {
+                // guarding covers the whole round, even against a faster monster
+                if action == Action::Defend {
+                    self.player.guarding = true;
+                    events.push(Event::Guard { by: Side::Player });
+                }
+
+                let player = self.player.reaction + self.rng.gen_range(0..10);
+                let monster = self.monster.reaction + self.rng.gen_range(0..10);
+
+                let order = match player >= monster {
+                    true => [(Side::Player, action), (Side::Monster, Action::Attack)],
+                    false => [(Side::Monster, Action::Attack), (Side::Player, action)],
+                };
+
+                for (side, action) in order {
+                    if self.state != State::Fighting {
+                        break;
+                    }
+
+                    if action != Action::Defend {
+                        self.act(side, action, &mut events);
+                    }
+                }
+            }
+        }
+
+        if self.state == State::Fighting {
+            self.tick(&mut events);
+        }
+
+        events
+    }
+
+    fn act(&mut self, side: Side, action: Action, events: &mut Vec<Event>) {
+        let Combat {
+            player,
+            monster,
+            rng,
+            ..
+        } = self;
+
+        let (attacker, defender) = match side {
+            Side::Player => (player, monster),
+            Side::Monster => (monster, player),
+        };
+
+        // a stun is spent by losing the turn
+        if let Some(stun) = attacker
+            .statuses
+            .iter_mut()
+            .find(|status| status.kind == StatusKind::Stun)
+        {
+            stun.turns = stun.turns.saturating_sub(1);
+            attacker.statuses.retain(|status| status.turns > 0);
+            events.push(Event::Stunned { target: side });
+            return;
+        }
+
+        let (percent, miss) = match action {
+            Action::Heavy => (HEAVY_DAMAGE, HEAVY_MISS),
+            _ => (100, 0),
+        };
+
+        let chance = (hit_chance(attacker, defender) - miss).max(MIN_HIT);
+
+        if rng.gen_range(0..100) >= chance {
+            events.push(Event::Miss { by: side });
+            return;
+        }
+
+        let critical = rng.gen_range(0..100) < attacker.precision.clamp(0, MAX_CRITICAL);
+        let mut damage = hit_damage(attacker, defender, percent);
+
+        if critical {
+            damage = damage * CRITICAL_DAMAGE / 100;
+        }
+
+        defender.hurt(damage);
+        events.push(Event::Hit {
+            by: side,
+            damage,
+            critical,
+        });
+
+        if let Some(affliction) = attacker
+            .affliction
+            .filter(|affliction| affliction.status.turns > 0)
+        {
+            if defender.is_alive() && rng.gen_range(0..100) < affliction.chance {
+                defender.afflict(affliction.status);
+                events.push(Event::Afflicted {
+                    target: side.rival(),
+                    kind: affliction.status.kind,
+                });
+            }
+        }
+
+        if !defender.is_alive() {
+            self.state = match side {
+                Side::Player => State::Won,
+                Side::Monster => State::Lost,
+            };
+        }
+    }
+
+    // poison hurts and every status but stun wears off at the end of a round
+    fn tick(&mut self, events: &mut Vec<Event>) {
+        for side in [Side::Player, Side::Monster] {
+            let fighter = match side {
+                Side::Player => &mut self.player,
+                Side::Monster => &mut self.monster,
+            };
+
+            let mut poison = 0;
+
+            for status in fighter.statuses.iter_mut() {
+                if status.kind == StatusKind::Stun {
+                    continue;
+                }
+
+                if status.kind == StatusKind::Poison {
+                    poison += status.power;
+                    events.push(Event::Tick {
+                        target: side,
+                        kind: status.kind,
+                        damage: status.power,
+                    });
+                }
+
+                status.turns = status.turns.saturating_sub(1);
+            }
+
+            fighter.statuses.retain(|status| status.turns > 0);
+            fighter.hurt(poison);
+        }
+
+        if !self.player.is_alive() {
+            self.state = State::Lost;
+        } else if !self.monster.is_alive() {
+            self.state = State::Won;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn knight() -> Fighter {
+        Fighter::new("knight", 100, 12, 2, 80).with_reflexes(5, 5)
+    }
+
+    fn slime() -> Fighter {
+        Fighter::new("slime", 40, 6, 0, 60)
+    }
+
+    fn play(seed: u64) -> (State, Vec<Event>) {
+        let mut combat = Combat::new(knight(), slime(), seed);
+        let mut events = Vec::new();
+
+        while combat.state == State::Fighting && combat.round < 50 {
+            events.extend(combat.round(Action::Attack));
+        }
+
+        (combat.state, events)
+    }
+
+    #[test]
+    fn same_seed_same_fight() {
+        for seed in 0..20 {
+            assert_eq!(play(seed), play(seed));
+        }
+    }
+
+    #[test]
+    fn hit_chance_is_clamped() {
+        let blind = Fighter::new("blind", 10, 1, 0, -50);
+        let sharp = Fighter::new("sharp", 10, 1, 0, 200);
+
+        assert_eq!(hit_chance(&blind, &slime()), MIN_HIT);
+        assert_eq!(hit_chance(&sharp, &slime()), MAX_HIT);
+        assert_eq!(hit_chance(&knight(), &slime()), 85);
+    }
+
+    #[test]
+    fn defense_and_guard_lower_damage() {
+        let knight = knight();
+        let mut slime = slime();
+
+        assert_eq!(hit_damage(&slime, &knight, 100), 4);
+        assert_eq!(hit_damage(&knight, &slime, HEAVY_DAMAGE), 19);
+
+        // never less than one
+        let mut wall = knight.clone();
+        wall.defense = 100;
+        wall.guarding = true;
+        assert_eq!(hit_damage(&slime, &wall, 100), 1);
+
+        slime.afflict(Status {
+            kind: StatusKind::Weaken,
+            turns: 2,
+            power: 50,
+        });
+        assert_eq!(hit_damage(&slime, &knight, 100), 1);
+    }
+
+    #[test]
+    fn poison_ticks_and_wears_off() {
+        let mut combat = Combat::new(knight(), slime(), 7);
+        combat.monster.afflict(Status {
+            kind: StatusKind::Poison,
+            turns: 2,
+            power: 5,
+        });
+
+        let mut ticks = 0;
+
+        for _ in 0..3 {
+            let events = combat.round(Action::Defend);
+            ticks += events
+                .iter()
+                .filter(|event| {
+                    matches!(
+                        event,
+                        Event::Tick {
+                            target: Side::Monster,
+                            ..
+                        }
+                    )
+                })
+                .count();
+        }
+
+        assert_eq!(ticks, 2);
+        assert_eq!(combat.monster.life, 30);
+        assert!(combat.monster.statuses.is_empty());
+    }
+
+    #[test]
+    fn stun_skips_the_turn() {
+        let mut combat = Combat::new(knight(), slime(), 3);
+        combat.player.afflict(Status {
+            kind: StatusKind::Stun,
+            turns: 1,
+            power: 0,
+        });
+
+        let events = combat.round(Action::Attack);
+
+        assert!(events.contains(&Event::Stunned {
+            target: Side::Player
+        }));
+        assert!(!events.iter().any(|event| matches!(
+            event,
+            Event::Hit {
+                by: Side::Player,
+                ..
+            }
+        )));
+        assert!(combat.player.status(StatusKind::Stun).is_none());
+    }
+
+    #[test]
+    fn statuses_without_turns_are_ignored() {
+        let mut combat = Combat::new(knight(), slime(), 5);
+        combat.player.afflict(Status {
+            kind: StatusKind::Stun,
+            turns: 0,
+            power: 0,
+        });
+
+        assert!(combat.player.statuses.is_empty());
+
+        // a monster whose status has no turns never leaves it
+        let spider = slime().with_affliction(Affliction {
+            status: Status {
+                kind: StatusKind::Poison,
+                turns: 0,
+                power: 5,
+            },
+            chance: 100,
+        });
+        let mut combat = Combat::new(knight(), spider, 5);
+
+        for _ in 0..5 {
+            let events = combat.round(Action::Defend);
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, Event::Afflicted { .. })));
+        }
+
+        assert!(combat.player.statuses.is_empty());
+    }
+
+    #[test]
+    fn loot_follows_the_table() {
+        let table = [
+            LootEntry {
+                item: 1,
+                chance: 100,
+                min: 2,
+                max: 2,
+            },
+            LootEntry {
+                item: 2,
+                chance: 0,
+                min: 1,
+                max: 1,
+            },
+        ];
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        assert_eq!(roll_loot(&mut rng, &table, 0, 0), vec![(1, 2)]);
+        assert_eq!(roll_loot(&mut rng, &table, 50, 50), vec![(1, 3)]);
+    }
+}
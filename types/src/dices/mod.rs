@@ -1,6 +1,15 @@
 mod player;
 pub use player::Player;
 
+use crate::Error;
+use poise::serenity_prelude::UserId;
+
+pub const MAX_PLAYERS: usize = 8;
+
+// winnings on top of the bet, an exact number is much harder to hit
+pub const PARITY_MULTIPLIER: i64 = 1;
+pub const NUMBER_MULTIPLIER: i64 = 5;
+
 #[derive(poise::ChoiceParameter, Clone, Hash, Eq, PartialEq, Debug)]
 pub enum Selection {
     Pair,
@@ -16,27 +25,62 @@ pub enum DiceResult {
     Lose,
 }
 
+impl DiceResult {
+    pub fn multiplier(&self) -> i64 {
+        match self {
+            DiceResult::NumWin => NUMBER_MULTIPLIER,
+            DiceResult::ChoiceWin => PARITY_MULTIPLIER,
+            _ => -1,
+        }
+    }
+
+    pub fn is_win(&self) -> bool {
+        matches!(self, DiceResult::NumWin | DiceResult::ChoiceWin)
+    }
+}
+
 pub struct Dices {
-    pub player: Player,
+    pub players: Vec<Player>,
     pub dices: Vec<i32>,
 }
 
 impl Dices {
     pub fn new(player: Player) -> Self {
         Self {
-            player,
+            players: vec![player],
             dices: Vec::new(),
         }
     }
 
+    pub fn add_player(&mut self, player: Player) -> Result<(), Error> {
+        if self.players.len() == MAX_PLAYERS {
+            return Err("Game is full".into());
+        }
+
+        if self.get_player(player.id).is_some() {
+            return Err("Already joined".into());
+        }
+
+        self.players.push(player);
+        Ok(())
+    }
+
+    pub fn get_player(&self, id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
     pub fn roll_dices(&mut self) {
         self.dices = vec![self.roll_dice(), self.roll_dice()];
     }
 
-    pub fn player_result(&self) -> DiceResult {
-        let number = self.dices.iter().sum::<i32>();
+    pub fn total(&self) -> i32 {
+        self.dices.iter().sum::<i32>()
+    }
+
+    pub fn player_result(&self, player: &Player) -> DiceResult {
+        let number = self.total();
 
-        if let Some(choice) = &self.player.selection {
+        if let Some(choice) = &player.selection {
             if *choice == self.check_pair(number) {
                 return DiceResult::ChoiceWin;
             }
@@ -44,7 +88,7 @@ impl Dices {
             return DiceResult::ChoiceLose;
         }
 
-        if let Some(num) = self.player.number {
+        if let Some(num) = player.number {
             if num == number {
                 return DiceResult::NumWin;
             }
@@ -55,6 +99,19 @@ impl Dices {
         DiceResult::Lose
     }
 
+    // net result of every player against the shared roll
+    pub fn payouts(&self) -> Vec<(UserId, i64)> {
+        self.players
+            .iter()
+            .map(|player| {
+                (
+                    player.id,
+                    player.bet * self.player_result(player).multiplier(),
+                )
+            })
+            .collect()
+    }
+
     pub fn check_pair(&self, number: i32) -> Selection {
         match number % 2 == 0 {
             true => Selection::Pair,
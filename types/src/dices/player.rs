@@ -6,13 +6,13 @@ use poise::serenity_prelude::{User, UserId};
 pub struct Player {
     pub id: UserId,
     pub name: String,
-    pub bet: i32,
+    pub bet: i64,
     pub selection: Option<Selection>,
     pub number: Option<i32>,
 }
 
 impl Player {
-    pub fn new(user: &User, bet: i32) -> Self {
+    pub fn new(user: &User, bet: i64) -> Self {
         let name = user.display_name().to_title_case();
 
         Self {
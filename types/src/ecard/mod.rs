@@ -0,0 +1,396 @@
+pub mod player;
+pub use player::Player;
+
+use crate::Error;
+use poise::serenity_prelude::{User, UserId};
+
+pub const ROUNDS: u32 = 12;
+pub const SWAP_EVERY: u32 = 3;
+
+// winning with the slave side is worth more
+pub const EMPEROR_POINTS: u32 = 1;
+pub const SLAVE_POINTS: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Card {
+    Emperor,
+    Citizen,
+    Slave,
+}
+
+impl Card {
+    // emperor beats citizen, citizen beats slave, slave beats emperor
+    pub fn beats(&self, other: &Card) -> bool {
+        matches!(
+            (self, other),
+            (Card::Emperor, Card::Citizen)
+                | (Card::Citizen, Card::Slave)
+                | (Card::Slave, Card::Emperor)
+        )
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Card::Emperor => String::from("emperor"),
+            Card::Citizen => String::from("citizen"),
+            Card::Slave => String::from("slave"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Emperor,
+    Slave,
+}
+
+impl Side {
+    pub fn special(&self) -> Card {
+        match self {
+            Side::Emperor => Card::Emperor,
+            Side::Slave => Card::Slave,
+        }
+    }
+
+    pub fn swap(&self) -> Self {
+        match self {
+            Side::Emperor => Side::Slave,
+            Side::Slave => Side::Emperor,
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            Side::Emperor => EMPEROR_POINTS,
+            Side::Slave => SLAVE_POINTS,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Side::Emperor => String::from("emperor-side"),
+            Side::Slave => String::from("slave-side"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Waiting,
+    Selecting,
+    Finished,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlayResult {
+    // two citizens, both are discarded and the round goes on
+    Draw,
+    RoundWin { winner: UserId, side: Side },
+}
+
+#[derive(Clone, Debug)]
+pub struct Play {
+    pub cards: Vec<(UserId, Card)>,
+    pub result: PlayResult,
+    pub swapped: bool,
+}
+
+pub struct ECard {
+    pub players: Vec<Player>,
+    pub round: u32,
+    pub state: State,
+}
+
+impl ECard {
+    pub fn new(user: &User) -> Self {
+        Self {
+            players: vec![Player::new(user, Side::Emperor)],
+            round: 1,
+            state: State::Waiting,
+        }
+    }
+
+    pub fn add_player(&mut self, user: &User) -> Result<(), Error> {
+        if self.players.len() == 2 {
+            return Err("Game is full".into());
+        }
+
+        self.players.push(Player::new(user, Side::Slave));
+        Ok(())
+    }
+
+    pub fn start(&mut self) -> Result<(), Error> {
+        if self.players.len() != 2 {
+            return Err("Not enough players".into());
+        }
+
+        for player in self.players.iter_mut() {
+            player.deal();
+        }
+
+        self.state = State::Selecting;
+        Ok(())
+    }
+
+    pub fn get_player(&self, id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn get_mut_player(&mut self, id: UserId) -> Option<&mut Player> {
+        self.players.iter_mut().find(|player| player.id == id)
+    }
+
+    pub fn get_side(&self, side: Side) -> &Player {
+        self.players
+            .iter()
+            .find(|player| player.side == side)
+            .unwrap()
+    }
+
+    pub fn select(&mut self, id: UserId, index: usize) -> Result<(), Error> {
+        if self.state != State::Selecting {
+            return Err("Cannot select a card now".into());
+        }
+
+        self.get_mut_player(id)
+            .ok_or("Not a player")?
+            .select_card(index)
+    }
+
+    pub fn all_selected(&self) -> bool {
+        self.players.iter().all(|player| player.has_selected())
+    }
+
+    // both selected cards are revealed at the same time
+    pub fn resolve(&mut self) -> Result<Play, Error> {
+        if self.state != State::Selecting || !self.all_selected() {
+            return Err("Players have not selected".into());
+        }
+
+        let cards = self
+            .players
+            .iter_mut()
+            .map(|player| (player.id, player.side, player.play_selected().unwrap()))
+            .collect::<Vec<(UserId, Side, Card)>>();
+
+        let (first, second) = (cards[0], cards[1]);
+
+        let winner = if first.2.beats(&second.2) {
+            Some(first)
+        } else if second.2.beats(&first.2) {
+            Some(second)
+        } else {
+            None
+        };
+
+        let cards = cards
+            .iter()
+            .map(|(id, _, card)| (*id, *card))
+            .collect::<Vec<(UserId, Card)>>();
+
+        let (id, side, _) = match winner {
+            Some(winner) => winner,
+            None => {
+                return Ok(Play {
+                    cards,
+                    result: PlayResult::Draw,
+                    swapped: false,
+                })
+            }
+        };
+
+        if let Some(player) = self.get_mut_player(id) {
+            player.points += side.points();
+        }
+
+        let swapped = self.next_round();
+
+        Ok(Play {
+            cards,
+            result: PlayResult::RoundWin { winner: id, side },
+            swapped,
+        })
+    }
+
+    // deals new hands, returns true when sides were swapped
+    fn next_round(&mut self) -> bool {
+        if self.round == ROUNDS {
+            self.state = State::Finished;
+            return false;
+        }
+
+        let swap = self.round.is_multiple_of(SWAP_EVERY);
+        self.round += 1;
+
+        for player in self.players.iter_mut() {
+            if swap {
+                player.side = player.side.swap();
+            }
+
+            player.deal();
+        }
+
+        swap
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Finished
+    }
+
+    // none on a draw
+    pub fn winner(&self) -> Option<&Player> {
+        let (first, second) = (&self.players[0], &self.players[1]);
+
+        match first.points.cmp(&second.points) {
+            std::cmp::Ordering::Greater => Some(first),
+            std::cmp::Ordering::Less => Some(second),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    pub fn rival(&self, id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id != id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64, name: &str) -> User {
+        let mut user = User::default();
+        user.id = UserId::new(id);
+        user.name = name.to_string();
+        user
+    }
+
+    fn game() -> ECard {
+        let mut ecard = ECard::new(&user(1, "tonegawa"));
+        ecard.add_player(&user(2, "kaiji")).unwrap();
+        ecard.start().unwrap();
+        ecard
+    }
+
+    fn play(ecard: &mut ECard, emperor: Card, slave: Card) -> Play {
+        let emperor_id = ecard.get_side(Side::Emperor).id;
+        let slave_id = ecard.get_side(Side::Slave).id;
+
+        let emperor_index = ecard
+            .get_side(Side::Emperor)
+            .hand
+            .iter()
+            .position(|c| *c == emperor);
+        let slave_index = ecard
+            .get_side(Side::Slave)
+            .hand
+            .iter()
+            .position(|c| *c == slave);
+
+        ecard.select(emperor_id, emperor_index.unwrap()).unwrap();
+        ecard.select(slave_id, slave_index.unwrap()).unwrap();
+        ecard.resolve().unwrap()
+    }
+
+    #[test]
+    fn card_matchups() {
+        assert!(Card::Emperor.beats(&Card::Citizen));
+        assert!(Card::Citizen.beats(&Card::Slave));
+        assert!(Card::Slave.beats(&Card::Emperor));
+        assert!(!Card::Citizen.beats(&Card::Citizen));
+        assert!(!Card::Citizen.beats(&Card::Emperor));
+    }
+
+    #[test]
+    fn deals_special_and_four_citizens() {
+        let ecard = game();
+
+        let emperor = ecard.get_side(Side::Emperor);
+        let slave = ecard.get_side(Side::Slave);
+
+        assert_eq!(emperor.hand.len(), 5);
+        assert_eq!(
+            emperor.hand.iter().filter(|c| **c == Card::Emperor).count(),
+            1
+        );
+        assert_eq!(slave.hand.iter().filter(|c| **c == Card::Slave).count(), 1);
+        assert_eq!(
+            slave.hand.iter().filter(|c| **c == Card::Citizen).count(),
+            4
+        );
+    }
+
+    #[test]
+    fn game_is_full_with_two_players() {
+        let mut ecard = game();
+        assert!(ecard.add_player(&user(3, "endo")).is_err());
+    }
+
+    #[test]
+    fn cannot_select_twice_or_resolve_early() {
+        let mut ecard = game();
+        let id = ecard.players[0].id;
+
+        ecard.select(id, 0).unwrap();
+        assert!(ecard.select(id, 1).is_err());
+        assert!(ecard.resolve().is_err());
+        assert!(ecard.select(UserId::new(99), 0).is_err());
+    }
+
+    #[test]
+    fn citizens_draw_and_are_discarded() {
+        let mut ecard = game();
+        let play = play(&mut ecard, Card::Citizen, Card::Citizen);
+
+        assert_eq!(play.result, PlayResult::Draw);
+        assert_eq!(ecard.round, 1);
+        assert!(ecard.players.iter().all(|player| player.hand.len() == 4));
+    }
+
+    #[test]
+    fn slave_win_scores_more() {
+        let mut ecard = game();
+        let slave_id = ecard.get_side(Side::Slave).id;
+
+        let play = play(&mut ecard, Card::Emperor, Card::Slave);
+
+        assert_eq!(
+            play.result,
+            PlayResult::RoundWin {
+                winner: slave_id,
+                side: Side::Slave
+            }
+        );
+        assert_eq!(ecard.get_player(slave_id).unwrap().points, SLAVE_POINTS);
+        assert_eq!(ecard.round, 2);
+    }
+
+    #[test]
+    fn sides_swap_every_few_rounds() {
+        let mut ecard = game();
+        let host = ecard.players[0].id;
+
+        for round in 1..=SWAP_EVERY {
+            let play = play(&mut ecard, Card::Emperor, Card::Citizen);
+            assert_eq!(play.swapped, round == SWAP_EVERY);
+        }
+
+        assert_eq!(ecard.get_player(host).unwrap().side, Side::Slave);
+        assert_eq!(
+            ecard.get_player(host).unwrap().points,
+            SWAP_EVERY * EMPEROR_POINTS
+        );
+    }
+
+    #[test]
+    fn finishes_after_last_round() {
+        let mut ecard = game();
+
+        for _ in 0..ROUNDS {
+            play(&mut ecard, Card::Citizen, Card::Slave);
+        }
+
+        assert!(ecard.is_finished());
+        assert!(ecard.select(ecard.players[0].id, 0).is_err());
+        assert!(ecard.winner().is_none());
+    }
+}
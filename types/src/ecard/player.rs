@@ -0,0 +1,73 @@
+use super::{Card, Side};
+use crate::Error;
+use inflector::Inflector;
+use poise::serenity_prelude::{MessageId, User, UserId};
+
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub id: UserId,
+    pub name: String,
+    pub side: Side,
+    pub hand: Vec<Card>,
+    pub selected: Option<usize>,
+    pub points: u32,
+    pub ephemeral: Option<MessageId>,
+}
+
+impl Player {
+    pub fn new(user: &User, side: Side) -> Self {
+        let name = user.display_name().to_title_case();
+
+        Self {
+            id: user.id,
+            name,
+            side,
+            hand: Vec::new(),
+            selected: None,
+            points: 0,
+            ephemeral: None,
+        }
+    }
+
+    // special card first, then the four citizens
+    pub fn deal(&mut self) {
+        self.hand = vec![
+            self.side.special(),
+            Card::Citizen,
+            Card::Citizen,
+            Card::Citizen,
+            Card::Citizen,
+        ];
+        self.selected = None;
+    }
+
+    pub fn select_card(&mut self, index: usize) -> Result<(), Error> {
+        if self.selected.is_some() {
+            return Err("Card already selected".into());
+        }
+
+        if index >= self.hand.len() {
+            return Err("Invalid card".into());
+        }
+
+        self.selected = Some(index);
+        Ok(())
+    }
+
+    pub fn has_selected(&self) -> bool {
+        self.selected.is_some()
+    }
+
+    // removes the selected card from the hand
+    pub fn play_selected(&mut self) -> Option<Card> {
+        self.selected.take().map(|index| self.hand.remove(index))
+    }
+
+    pub fn set_ephemeral(&mut self, message: MessageId) {
+        self.ephemeral = Some(message);
+    }
+
+    pub fn take_ephemeral(&mut self) -> Option<MessageId> {
+        self.ephemeral.take()
+    }
+}
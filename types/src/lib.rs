@@ -6,9 +6,6 @@ pub mod oldmaid;
 
 pub mod evaluate;
 
-use std::collections::HashMap;
-use tokio::sync::Mutex;
-
 pub use evaluate::{EvaluatePoker, HandType};
 
 pub mod airpoker;
@@ -19,14 +16,21 @@ pub mod rb;
 pub mod nim_type_zero;
 pub use nim_type_zero::Nim;
 
-#[derive(Default)]
-pub struct Rooms {
-    pub nim: Mutex<HashMap<u64, Nim>>,
-    pub airpoker: Mutex<HashMap<u64, AirPoker>>,
-}
+pub mod rooms;
+pub use rooms::{RoomKind, Rooms};
 
 pub mod contradiction;
 
 pub mod dices;
 
+pub mod ecard;
+
+pub mod rr;
+
+pub mod roulette;
+
+pub mod trade;
+
+pub mod combat;
+
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
@@ -6,6 +6,8 @@ use crate::cards::poker::Card;
 pub use player::Player;
 use poise::serenity_prelude::{User, UserId};
 
+pub const MAX_PLAYERS: usize = 4;
+
 pub struct Oldmaid {
     pub players: Vec<Player>,
     pub turn_timeout: Option<Duration>,
@@ -23,10 +25,14 @@ impl Oldmaid {
     }
 
     pub fn add_player(&mut self, user: &User) -> Result<(), Error> {
-        if self.players.len() == 2 {
+        if self.players.len() == MAX_PLAYERS {
             return Err("Game is full".into());
         }
 
+        if self.players.iter().any(|player| player.id == user.id) {
+            return Err("Already joined".into());
+        }
+
         self.players.push(Player::new(user));
         Ok(())
     }
@@ -59,23 +65,36 @@ impl Oldmaid {
         self.players.iter().find(|p| p.id == id).unwrap()
     }
 
+    // cards are dealt one by one around the table
     pub fn deal_cards(&mut self) {
-        let mut deck = Card::black_deck();
-
-        let fp = self.players.first_mut().unwrap();
-        fp.hand.extend(deck.drain(0..13));
+        let deck = Card::black_deck();
+        let players = self.players.len();
 
-        let sp = self.players.last_mut().unwrap();
-        sp.hand.extend(deck.drain(0..));
+        for (index, card) in deck.into_iter().enumerate() {
+            self.players[index % players].hand.push(card);
+        }
     }
 
+    // players without cards are out and get skipped
     pub fn next_turn(&mut self) {
-        let player = self.players.remove(0);
-        self.players.push(player);
+        loop {
+            let player = self.players.remove(0);
+            self.players.push(player);
+
+            if !self.get_actual().hand.is_empty() || self.is_over() {
+                break;
+            }
+        }
     }
 
+    // the actual player draws from the previous player still holding cards
     pub fn get_mut_rival(&mut self) -> &mut Player {
-        self.players.last_mut().unwrap()
+        self.players
+            .iter_mut()
+            .skip(1)
+            .rev()
+            .find(|player| !player.hand.is_empty())
+            .unwrap()
     }
 
     pub fn get_actual(&self) -> &Player {
@@ -83,7 +102,44 @@ impl Oldmaid {
     }
 
     pub fn get_rival(&self) -> &Player {
-        self.players.last().unwrap()
+        self.players
+            .iter()
+            .skip(1)
+            .rev()
+            .find(|player| !player.hand.is_empty())
+            .unwrap()
+    }
+
+    pub fn players_in_game(&self) -> usize {
+        self.players
+            .iter()
+            .filter(|player| !player.hand.is_empty())
+            .count()
+    }
+
+    // only the one holding the joker is left
+    pub fn is_over(&self) -> bool {
+        self.players_in_game() <= 1
+    }
+
+    // moves the confirmed card from the rival to the actual player
+    pub fn draw_confirmed(&mut self) -> Option<Card> {
+        let index = self.players.first_mut()?.confirmed_card_index.take()?;
+
+        let rival = self.get_mut_rival();
+
+        if index >= rival.hand.len() {
+            return None;
+        }
+
+        let card = rival.take_card(index);
+        rival.shuffle_hand();
+
+        let actual = self.players.first_mut().unwrap();
+        actual.hand.push(card.clone());
+        actual.discard_pairs();
+
+        Some(card)
     }
 
     pub fn cards_in_game(&self) -> usize {
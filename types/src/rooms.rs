@@ -0,0 +1,338 @@
+use crate::{AirPoker, Error, Nim};
+use poise::serenity_prelude::{MessageId, UserId};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::Mutex;
+
+#[derive(poise::ChoiceParameter, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoomKind {
+    Nim,
+    AirPoker,
+}
+
+impl fmt::Display for RoomKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomKind::Nim => write!(f, "nim"),
+            RoomKind::AirPoker => write!(f, "airpoker"),
+        }
+    }
+}
+
+// what the registry knows about a running game without locking its state
+#[derive(Clone, Debug)]
+pub struct RoomInfo {
+    pub kind: RoomKind,
+    pub channel: u64,
+    pub guild: Option<u64>,
+    // id of the invocation, every custom_id of the game starts with it
+    pub id: u64,
+    pub host: UserId,
+    pub players: Vec<UserId>,
+    // unix seconds
+    pub opened: u64,
+}
+
+// the command running the game keeps its state locked for the whole run
+pub struct Room<T> {
+    pub info: RoomInfo,
+    pub game: Arc<Mutex<T>>,
+    aborted: Arc<AtomicBool>,
+}
+
+// handle kept by the command running the game
+#[derive(Clone)]
+pub struct RoomHandle<T> {
+    pub game: Arc<Mutex<T>>,
+    aborted: Arc<AtomicBool>,
+}
+
+impl<T> RoomHandle<T> {
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::Relaxed)
+    }
+}
+
+// one room per channel for every kind of game, each command still collects
+// the interactions of its own room
+pub struct RoomMap<T> {
+    kind: RoomKind,
+    rooms: Mutex<HashMap<u64, Room<T>>>,
+}
+
+impl<T> RoomMap<T> {
+    pub fn new(kind: RoomKind) -> Self {
+        Self {
+            kind,
+            rooms: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn open(
+        &self,
+        channel: u64,
+        guild: Option<u64>,
+        id: u64,
+        host: UserId,
+        game: T,
+    ) -> Result<RoomHandle<T>, Error> {
+        let mut rooms = self.rooms.lock().await;
+
+        if rooms.contains_key(&channel) {
+            return Err("Room already open".into());
+        }
+
+        let opened = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        let room = Room {
+            info: RoomInfo {
+                kind: self.kind,
+                channel,
+                guild,
+                id,
+                host,
+                players: vec![host],
+                opened,
+            },
+            game: Arc::new(Mutex::new(game)),
+            aborted: Arc::new(AtomicBool::new(false)),
+        };
+
+        let handle = RoomHandle {
+            game: room.game.clone(),
+            aborted: room.aborted.clone(),
+        };
+
+        rooms.insert(channel, room);
+        Ok(handle)
+    }
+
+    pub async fn join(&self, channel: u64, id: UserId) {
+        if let Some(room) = self.rooms.lock().await.get_mut(&channel) {
+            if !room.info.players.contains(&id) {
+                room.info.players.push(id);
+            }
+        }
+    }
+
+    // only the invocation that opened the room can close it
+    pub async fn close(&self, channel: u64, id: u64) {
+        let mut rooms = self.rooms.lock().await;
+
+        if rooms.get(&channel).is_some_and(|room| room.info.id == id) {
+            rooms.remove(&channel);
+        }
+    }
+
+    pub async fn abort(&self, channel: u64) -> Option<RoomInfo> {
+        let room = self.rooms.lock().await.remove(&channel)?;
+        room.aborted.store(true, Ordering::Relaxed);
+
+        Some(room.info)
+    }
+
+    pub async fn get(&self, channel: u64) -> Option<RoomInfo> {
+        self.rooms
+            .lock()
+            .await
+            .get(&channel)
+            .map(|room| room.info.clone())
+    }
+
+    pub async fn list(&self) -> Vec<RoomInfo> {
+        self.rooms
+            .lock()
+            .await
+            .values()
+            .map(|room| room.info.clone())
+            .collect()
+    }
+
+    pub async fn find(&self, id: u64) -> Option<RoomInfo> {
+        self.rooms
+            .lock()
+            .await
+            .values()
+            .find(|room| room.info.id == id)
+            .map(|room| room.info.clone())
+    }
+}
+
+// running games, so a channel can't host two games of a kind and buttons of
+// games lost in a restart get an answer
+pub struct Rooms {
+    pub nim: RoomMap<Nim>,
+    pub airpoker: RoomMap<AirPoker>,
+    // unix seconds when the registry was created
+    started: u64,
+}
+
+impl Default for Rooms {
+    fn default() -> Self {
+        Self {
+            nim: RoomMap::new(RoomKind::Nim),
+            airpoker: RoomMap::new(RoomKind::AirPoker),
+            started: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+// custom ids are "{id}_{action}", the id being the invocation snowflake
+pub fn parse_custom_id(custom_id: &str) -> Option<(u64, &str)> {
+    let (id, action) = custom_id.split_once('_')?;
+    let id = id.parse::<u64>().ok().filter(|id| *id != 0)?;
+
+    Some((id, action))
+}
+
+pub enum Route {
+    // a command is still collecting the interactions of this room
+    Room,
+    // the game was started before the last restart and its state is gone
+    Stale,
+    // not a game interaction or one that is handled by its own collector
+    Unknown,
+}
+
+impl Rooms {
+    pub async fn list(&self) -> Vec<RoomInfo> {
+        let mut rooms = self.nim.list().await;
+        rooms.extend(self.airpoker.list().await);
+        rooms.sort_by_key(|room| room.opened);
+
+        rooms
+    }
+
+    pub async fn abort(&self, kind: RoomKind, channel: u64) -> Option<RoomInfo> {
+        match kind {
+            RoomKind::Nim => self.nim.abort(channel).await,
+            RoomKind::AirPoker => self.airpoker.abort(channel).await,
+        }
+    }
+
+    pub async fn find(&self, id: u64) -> Option<RoomInfo> {
+        match self.nim.find(id).await {
+            Some(room) => Some(room),
+            None => self.airpoker.find(id).await,
+        }
+    }
+
+    pub async fn route(&self, custom_id: &str) -> Route {
+        let id = match parse_custom_id(custom_id) {
+            Some((id, _)) => id,
+            None => return Route::Unknown,
+        };
+
+        if self.find(id).await.is_some() {
+            return Route::Room;
+        }
+
+        // ids are snowflakes, anything created before the start can't have a collector
+        let created = MessageId::new(id).created_at().unix_timestamp();
+
+        match created < self.started as i64 {
+            true => Route::Stale,
+            false => Route::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airpoker::Player;
+    use poise::serenity_prelude::User;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn one_room_per_channel() {
+        block_on(async {
+            let rooms: RoomMap<u8> = RoomMap::new(RoomKind::Nim);
+            let host = UserId::new(1);
+
+            assert!(rooms.open(10, None, 100, host, 0).await.is_ok());
+            assert!(rooms.open(10, None, 101, host, 0).await.is_err());
+            assert!(rooms.open(11, None, 102, host, 0).await.is_ok());
+        });
+    }
+
+    #[test]
+    fn close_only_from_owner() {
+        block_on(async {
+            let rooms: RoomMap<u8> = RoomMap::new(RoomKind::AirPoker);
+            let host = UserId::new(1);
+
+            rooms.open(10, None, 100, host, 0).await.unwrap();
+
+            rooms.close(10, 101).await;
+            assert!(rooms.get(10).await.is_some());
+
+            rooms.close(10, 100).await;
+            assert!(rooms.get(10).await.is_none());
+        });
+    }
+
+    #[test]
+    fn abort_flags_handle() {
+        block_on(async {
+            let rooms: RoomMap<u8> = RoomMap::new(RoomKind::Nim);
+            let handle = rooms.open(10, None, 100, UserId::new(1), 0).await.unwrap();
+            rooms.join(10, UserId::new(2)).await;
+
+            let info = rooms.abort(10).await.unwrap();
+
+            assert!(handle.is_aborted());
+            assert_eq!(info.players, vec![UserId::new(1), UserId::new(2)]);
+            assert!(rooms.list().await.is_empty());
+        });
+    }
+
+    #[test]
+    fn routes_by_custom_id() {
+        block_on(async {
+            let rooms = Rooms::default();
+            // a snowflake from 2015, long before any restart
+            let old = 41771983423143937;
+
+            let game = AirPoker::new(Player::new(User::default()), Player::new(User::default()));
+
+            rooms
+                .airpoker
+                .open(10, None, old, UserId::new(1), game)
+                .await
+                .unwrap();
+
+            assert!(matches!(
+                rooms.route(&format!("{}_call", old)).await,
+                Route::Room
+            ));
+
+            rooms.airpoker.close(10, old).await;
+
+            assert!(matches!(
+                rooms.route(&format!("{}_call", old)).await,
+                Route::Stale
+            ));
+            assert!(matches!(rooms.route("bet").await, Route::Unknown));
+        });
+    }
+}
@@ -0,0 +1,414 @@
+pub mod player;
+pub use player::{Chip, Player};
+
+use crate::Error;
+use poise::serenity_prelude::{User, UserId};
+use rand::Rng;
+
+pub const MAX_CHIPS: usize = 10;
+
+pub const RED: [u8; 18] = [
+    1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Green,
+    Red,
+    Black,
+}
+
+impl Color {
+    pub fn of(number: u8) -> Self {
+        match number {
+            0 => Color::Green,
+            n if RED.contains(&n) => Color::Red,
+            _ => Color::Black,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Color::Green => String::from("green"),
+            Color::Red => String::from("red"),
+            Color::Black => String::from("black"),
+        }
+    }
+}
+
+// inside bets take the first number of the row or corner they cover
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bet {
+    Straight(u8),
+    Split(u8, u8),
+    Street(u8),
+    Corner(u8),
+    SixLine(u8),
+    Dozen(u8),
+    Column(u8),
+    Red,
+    Black,
+    Odd,
+    Even,
+    Low,
+    High,
+}
+
+impl Bet {
+    // numbers covered by the bet, errors when it isn't on the layout
+    pub fn numbers(&self) -> Result<Vec<u8>, Error> {
+        let numbers = match *self {
+            Bet::Straight(n) if n <= 36 => vec![n],
+            Bet::Split(a, b) => {
+                let (a, b) = (a.min(b), a.max(b));
+
+                let adjacent = match a {
+                    0 => (1..=3).contains(&b),
+                    _ => b <= 36 && (b == a + 3 || (b == a + 1 && a % 3 != 0)),
+                };
+
+                if !adjacent {
+                    return Err("Invalid split".into());
+                }
+
+                vec![a, b]
+            }
+            Bet::Street(n) if (1..=34).contains(&n) && n % 3 == 1 => (n..n + 3).collect(),
+            Bet::Corner(n) if (1..=32).contains(&n) && n % 3 != 0 => vec![n, n + 1, n + 3, n + 4],
+            Bet::SixLine(n) if (1..=31).contains(&n) && n % 3 == 1 => (n..n + 6).collect(),
+            Bet::Dozen(d) if (1..=3).contains(&d) => ((d - 1) * 12 + 1..=d * 12).collect(),
+            Bet::Column(c) if (1..=3).contains(&c) => (1..=36).filter(|n| n % 3 == c % 3).collect(),
+            Bet::Red => RED.to_vec(),
+            Bet::Black => (1..=36).filter(|n| !RED.contains(n)).collect(),
+            Bet::Odd => (1..=36).filter(|n| n % 2 == 1).collect(),
+            Bet::Even => (1..=36).filter(|n| n % 2 == 0).collect(),
+            Bet::Low => (1..=18).collect(),
+            Bet::High => (19..=36).collect(),
+            _ => return Err("Invalid bet".into()),
+        };
+
+        Ok(numbers)
+    }
+
+    // odds paid on top of the stake
+    pub fn payout(&self) -> i64 {
+        match self {
+            Bet::Straight(_) => 35,
+            Bet::Split(..) => 17,
+            Bet::Street(_) => 11,
+            Bet::Corner(_) => 8,
+            Bet::SixLine(_) => 5,
+            Bet::Dozen(_) | Bet::Column(_) => 2,
+            _ => 1,
+        }
+    }
+
+    pub fn wins(&self, number: u8) -> bool {
+        self.numbers()
+            .map(|numbers| numbers.contains(&number))
+            .unwrap_or(false)
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Bet::Straight(n) => n.to_string(),
+            Bet::Split(a, b) => format!("split {}-{}", a, b),
+            Bet::Street(n) => format!("street {}", n),
+            Bet::Corner(n) => format!("corner {}", n),
+            Bet::SixLine(n) => format!("sixline {}", n),
+            Bet::Dozen(n) => format!("dozen {}", n),
+            Bet::Column(n) => format!("column {}", n),
+            Bet::Red => String::from("red"),
+            Bet::Black => String::from("black"),
+            Bet::Odd => String::from("odd"),
+            Bet::Even => String::from("even"),
+            Bet::Low => String::from("1-18"),
+            Bet::High => String::from("19-36"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Betting,
+    Spun,
+}
+
+pub struct Roulette {
+    pub players: Vec<Player>,
+    pub state: State,
+    pub number: Option<u8>,
+}
+
+impl Default for Roulette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Roulette {
+    pub fn new() -> Self {
+        Self {
+            players: Vec::new(),
+            state: State::Betting,
+            number: None,
+        }
+    }
+
+    pub fn place(&mut self, user: &User, bet: Bet, amount: i64) -> Result<(), Error> {
+        if self.state != State::Betting {
+            return Err("Bets are closed".into());
+        }
+
+        if amount <= 0 {
+            return Err("Invalid amount".into());
+        }
+
+        bet.numbers()?;
+
+        if self.get_player(user.id).is_none() {
+            self.players.push(Player::new(user));
+        }
+
+        let player = self.get_mut_player(user.id).unwrap();
+
+        if player.chips.len() == MAX_CHIPS {
+            return Err("Too many chips".into());
+        }
+
+        player.chips.push(Chip { bet, amount });
+        Ok(())
+    }
+
+    pub fn get_player(&self, id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn get_mut_player(&mut self, id: UserId) -> Option<&mut Player> {
+        self.players.iter_mut().find(|player| player.id == id)
+    }
+
+    pub fn spin(&mut self) -> u8 {
+        let number = rand::thread_rng().gen_range(0..=36);
+        self.land(number);
+        number
+    }
+
+    // closes the bets on the given pocket
+    pub fn land(&mut self, number: u8) {
+        self.number = Some(number);
+        self.state = State::Spun;
+    }
+
+    // net result for every player once the ball has landed
+    pub fn payouts(&self) -> Vec<(UserId, i64)> {
+        let number = match self.number {
+            Some(number) => number,
+            None => return Vec::new(),
+        };
+
+        self.players
+            .iter()
+            .map(|player| (player.id, player.settle(number)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64, name: &str) -> User {
+        let mut user = User::default();
+        user.id = UserId::new(id);
+        user.name = name.to_string();
+        user
+    }
+
+    fn all_bets() -> Vec<Bet> {
+        let mut bets = Vec::new();
+
+        for n in 0..=36 {
+            bets.push(Bet::Straight(n));
+        }
+
+        for a in 0..=36 {
+            for b in a + 1..=36 {
+                if Bet::Split(a, b).numbers().is_ok() {
+                    bets.push(Bet::Split(a, b));
+                }
+            }
+        }
+
+        for n in 1..=36 {
+            for bet in [Bet::Street(n), Bet::Corner(n), Bet::SixLine(n)] {
+                if bet.numbers().is_ok() {
+                    bets.push(bet);
+                }
+            }
+        }
+
+        for n in 1..=3 {
+            bets.push(Bet::Dozen(n));
+            bets.push(Bet::Column(n));
+        }
+
+        bets.extend([
+            Bet::Red,
+            Bet::Black,
+            Bet::Odd,
+            Bet::Even,
+            Bet::Low,
+            Bet::High,
+        ]);
+        bets
+    }
+
+    #[test]
+    fn layout_has_every_bet() {
+        let bets = all_bets();
+        let count = |f: fn(&Bet) -> bool| bets.iter().filter(|bet| f(bet)).count();
+
+        assert_eq!(count(|bet| matches!(bet, Bet::Straight(_))), 37);
+        // 24 horizontal, 33 vertical and 3 with the zero
+        assert_eq!(count(|bet| matches!(bet, Bet::Split(..))), 60);
+        assert_eq!(count(|bet| matches!(bet, Bet::Street(_))), 12);
+        assert_eq!(count(|bet| matches!(bet, Bet::Corner(_))), 22);
+        assert_eq!(count(|bet| matches!(bet, Bet::SixLine(_))), 11);
+    }
+
+    #[test]
+    fn payouts_match_covered_numbers() {
+        // every bet pays 36 / covered - 1, the zero is the house edge
+        for bet in all_bets() {
+            let covered = bet.numbers().unwrap().len() as i64;
+            assert_eq!(bet.payout(), 36 / covered - 1, "{:?}", bet);
+        }
+    }
+
+    #[test]
+    fn expected_loss_is_one_in_thirty_seven() {
+        for bet in all_bets() {
+            let total = (0..=36)
+                .map(|number| match bet.wins(number) {
+                    true => bet.payout(),
+                    false => -1,
+                })
+                .sum::<i64>();
+
+            assert_eq!(total, -1, "{:?}", bet);
+        }
+    }
+
+    #[test]
+    fn zero_loses_every_outside_bet() {
+        let outside = [
+            Bet::Red,
+            Bet::Black,
+            Bet::Odd,
+            Bet::Even,
+            Bet::Low,
+            Bet::High,
+            Bet::Dozen(1),
+            Bet::Column(1),
+        ];
+
+        for bet in outside {
+            assert!(!bet.wins(0), "{:?}", bet);
+        }
+
+        assert!(Bet::Straight(0).wins(0));
+        assert!(Bet::Split(0, 2).wins(0));
+    }
+
+    #[test]
+    fn outside_bets_split_the_wheel() {
+        for number in 1..=36 {
+            assert_ne!(Bet::Red.wins(number), Bet::Black.wins(number));
+            assert_ne!(Bet::Odd.wins(number), Bet::Even.wins(number));
+            assert_ne!(Bet::Low.wins(number), Bet::High.wins(number));
+
+            let dozens = (1..=3).filter(|d| Bet::Dozen(*d).wins(number)).count();
+            let columns = (1..=3).filter(|c| Bet::Column(*c).wins(number)).count();
+
+            assert_eq!(dozens, 1);
+            assert_eq!(columns, 1);
+        }
+
+        assert_eq!(Color::of(0), Color::Green);
+        assert_eq!(Color::of(1), Color::Red);
+        assert_eq!(Color::of(2), Color::Black);
+    }
+
+    #[test]
+    fn inside_bets_cover_the_right_numbers() {
+        assert_eq!(Bet::Split(18, 17).numbers().unwrap(), vec![17, 18]);
+        assert_eq!(Bet::Street(34).numbers().unwrap(), vec![34, 35, 36]);
+        assert_eq!(Bet::Corner(32).numbers().unwrap(), vec![32, 33, 35, 36]);
+        assert_eq!(
+            Bet::SixLine(31).numbers().unwrap(),
+            vec![31, 32, 33, 34, 35, 36]
+        );
+        assert_eq!(Bet::Dozen(3).numbers().unwrap().first(), Some(&25));
+        assert_eq!(Bet::Column(3).numbers().unwrap().last(), Some(&36));
+    }
+
+    #[test]
+    fn rejects_bets_off_the_layout() {
+        let invalid = [
+            Bet::Straight(37),
+            Bet::Split(3, 4),
+            Bet::Split(1, 5),
+            Bet::Split(0, 4),
+            Bet::Split(5, 5),
+            Bet::Street(2),
+            Bet::Street(37),
+            Bet::Corner(3),
+            Bet::Corner(34),
+            Bet::SixLine(34),
+            Bet::Dozen(0),
+            Bet::Column(4),
+        ];
+
+        for bet in invalid {
+            assert!(bet.numbers().is_err(), "{:?}", bet);
+            assert!(!bet.wins(1));
+        }
+    }
+
+    #[test]
+    fn settles_several_chips_per_player() {
+        let mut roulette = Roulette::new();
+        let (first, second) = (user(1, "ichijo"), user(2, "kaiji"));
+
+        roulette.place(&first, Bet::Straight(17), 100).unwrap();
+        roulette.place(&first, Bet::Red, 200).unwrap();
+        roulette.place(&second, Bet::Black, 300).unwrap();
+        roulette.place(&second, Bet::Corner(13), 50).unwrap();
+
+        roulette.land(17);
+
+        let payouts = roulette.payouts();
+
+        // 17 is black
+        assert_eq!(payouts[0], (first.id, 100 * 35 - 200));
+        assert_eq!(payouts[1], (second.id, 300 + 50 * 8));
+        assert!(roulette.place(&first, Bet::Odd, 100).is_err());
+    }
+
+    #[test]
+    fn limits_chips_and_amounts() {
+        let mut roulette = Roulette::new();
+        let player = user(1, "ichijo");
+
+        assert!(roulette.place(&player, Bet::Odd, 0).is_err());
+        assert!(roulette.place(&player, Bet::Split(1, 9), 100).is_err());
+
+        for _ in 0..MAX_CHIPS {
+            roulette.place(&player, Bet::Odd, 100).unwrap();
+        }
+
+        assert!(roulette.place(&player, Bet::Even, 100).is_err());
+        assert_eq!(roulette.get_player(player.id).unwrap().total_bet(), 1000);
+    }
+}
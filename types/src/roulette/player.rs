@@ -0,0 +1,43 @@
+use super::Bet;
+use inflector::Inflector;
+use poise::serenity_prelude::{User, UserId};
+
+#[derive(Clone, Debug)]
+pub struct Chip {
+    pub bet: Bet,
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub id: UserId,
+    pub name: String,
+    pub chips: Vec<Chip>,
+}
+
+impl Player {
+    pub fn new(user: &User) -> Self {
+        let name = user.display_name().to_title_case();
+
+        Self {
+            id: user.id,
+            name,
+            chips: Vec::new(),
+        }
+    }
+
+    pub fn total_bet(&self) -> i64 {
+        self.chips.iter().map(|chip| chip.amount).sum()
+    }
+
+    // winning chips pay their odds and keep the stake, the rest are lost
+    pub fn settle(&self, number: u8) -> i64 {
+        self.chips
+            .iter()
+            .map(|chip| match chip.bet.wins(number) {
+                true => chip.amount * chip.bet.payout(),
+                false => -chip.amount,
+            })
+            .sum()
+    }
+}
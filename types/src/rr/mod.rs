@@ -0,0 +1,203 @@
+pub mod player;
+pub use player::Player;
+
+pub mod revolver;
+pub use revolver::Revolver;
+
+use crate::Error;
+use poise::serenity_prelude::{User, UserId};
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 6;
+
+#[derive(poise::ChoiceParameter, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    // the cylinder is spun before every pull
+    Spin,
+    // the cylinder is spun once and keeps turning
+    NoSpin,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Waiting,
+    Playing,
+    Finished,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shot {
+    pub id: UserId,
+    pub fired: bool,
+}
+
+pub struct RussianRoulette {
+    pub players: Vec<Player>,
+    pub revolver: Revolver,
+    pub rule: Rule,
+    pub bet: i64,
+    pub turn: usize,
+    pub state: State,
+}
+
+impl RussianRoulette {
+    pub fn new(
+        user: &User,
+        bet: i64,
+        chambers: usize,
+        bullets: usize,
+        rule: Rule,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            players: vec![Player::new(user)],
+            revolver: Revolver::new(chambers, bullets)?,
+            rule,
+            bet,
+            turn: 0,
+            state: State::Waiting,
+        })
+    }
+
+    pub fn add_player(&mut self, user: &User) -> Result<(), Error> {
+        if self.players.len() == MAX_PLAYERS {
+            return Err("Game is full".into());
+        }
+
+        if self.get_player(user.id).is_some() {
+            return Err("Already joined".into());
+        }
+
+        self.players.push(Player::new(user));
+        Ok(())
+    }
+
+    // at least one player has to survive every bullet
+    pub fn start(&mut self) -> Result<(), Error> {
+        if self.players.len() < MIN_PLAYERS {
+            return Err("Not enough players".into());
+        }
+
+        if self.revolver.bullets() >= self.players.len() {
+            return Err("Too many bullets".into());
+        }
+
+        self.revolver.spin();
+        self.state = State::Playing;
+        Ok(())
+    }
+
+    pub fn get_player(&self, id: UserId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.turn]
+    }
+
+    pub fn pull_trigger(&mut self) -> Result<Shot, Error> {
+        if self.state != State::Playing {
+            return Err("Game is not running".into());
+        }
+
+        if self.rule == Rule::Spin {
+            self.revolver.spin();
+        }
+
+        let fired = self.revolver.fire();
+        let player = &mut self.players[self.turn];
+
+        if fired {
+            player.kill();
+        }
+
+        let shot = Shot {
+            id: player.id,
+            fired,
+        };
+
+        if self.revolver.bullets() == 0 || self.survivors().len() == 1 {
+            self.state = State::Finished;
+        } else {
+            self.next_turn();
+        }
+
+        Ok(shot)
+    }
+
+    fn next_turn(&mut self) {
+        loop {
+            self.turn = (self.turn + 1) % self.players.len();
+
+            if self.players[self.turn].alive {
+                break;
+            }
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Finished
+    }
+
+    pub fn survivors(&self) -> Vec<&Player> {
+        self.players.iter().filter(|player| player.alive).collect()
+    }
+
+    pub fn eliminated(&self) -> Vec<&Player> {
+        self.players.iter().filter(|player| !player.alive).collect()
+    }
+
+    pub fn pot(&self) -> i64 {
+        self.bet * self.players.len() as i64
+    }
+
+    // the dead lose their bet and survivors split it evenly, the first
+    // survivors take what can't be split
+    pub fn payouts(&self) -> Vec<(UserId, i64)> {
+        let survivors = self.survivors().len().max(1) as i64;
+        let lost = self.bet * self.eliminated().len() as i64;
+        let mut remainder = lost % survivors;
+
+        self.players
+            .iter()
+            .map(|player| match player.alive {
+                true => {
+                    let extra = (remainder > 0) as i64;
+                    remainder -= extra;
+
+                    (player.id, lost / survivors + extra)
+                }
+                false => (player.id, -self.bet),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64) -> User {
+        let mut user = User::default();
+        user.id = UserId::new(id);
+        user
+    }
+
+    #[test]
+    fn payouts_add_up_to_zero() {
+        let mut rr = RussianRoulette::new(&user(1), 100, 6, 1, Rule::Spin).unwrap();
+
+        for id in 2..=4 {
+            rr.add_player(&user(id)).unwrap();
+        }
+
+        rr.players[3].kill();
+
+        let payouts = rr.payouts();
+
+        assert_eq!(
+            payouts.iter().map(|(_, net)| *net).collect::<Vec<i64>>(),
+            vec![34, 33, 33, -100]
+        );
+        assert_eq!(payouts.iter().map(|(_, net)| net).sum::<i64>(), 0);
+    }
+}
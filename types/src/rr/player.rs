@@ -0,0 +1,25 @@
+use inflector::Inflector;
+use poise::serenity_prelude::{User, UserId};
+
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub id: UserId,
+    pub name: String,
+    pub alive: bool,
+}
+
+impl Player {
+    pub fn new(user: &User) -> Self {
+        let name = user.display_name().to_title_case();
+
+        Self {
+            id: user.id,
+            name,
+            alive: true,
+        }
+    }
+
+    pub fn kill(&mut self) {
+        self.alive = false;
+    }
+}
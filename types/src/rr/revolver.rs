@@ -0,0 +1,61 @@
+use crate::Error;
+use rand::Rng;
+
+pub const MIN_CHAMBERS: usize = 2;
+pub const MAX_CHAMBERS: usize = 12;
+
+pub struct Revolver {
+    pub chambers: Vec<bool>,
+    position: usize,
+}
+
+impl Revolver {
+    pub fn new(chambers: usize, bullets: usize) -> Result<Self, Error> {
+        if !(MIN_CHAMBERS..=MAX_CHAMBERS).contains(&chambers) {
+            return Err("Invalid chamber count".into());
+        }
+
+        if bullets == 0 || bullets >= chambers {
+            return Err("Invalid bullet count".into());
+        }
+
+        let mut revolver = Self {
+            chambers: vec![false; chambers],
+            position: 0,
+        };
+
+        revolver.load(bullets);
+        Ok(revolver)
+    }
+
+    // bullets go in consecutive chambers, then the cylinder is spun
+    pub fn load(&mut self, bullets: usize) {
+        for (index, chamber) in self.chambers.iter_mut().enumerate() {
+            *chamber = index < bullets;
+        }
+
+        self.spin();
+    }
+
+    pub fn spin(&mut self) {
+        self.position = rand::thread_rng().gen_range(0..self.chambers.len());
+    }
+
+    // a fired bullet leaves its chamber empty
+    pub fn fire(&mut self) -> bool {
+        let fired = self.chambers[self.position];
+
+        self.chambers[self.position] = false;
+        self.position = (self.position + 1) % self.chambers.len();
+
+        fired
+    }
+
+    pub fn bullets(&self) -> usize {
+        self.chambers.iter().filter(|chamber| **chamber).count()
+    }
+
+    pub fn size(&self) -> usize {
+        self.chambers.len()
+    }
+}
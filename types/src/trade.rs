@@ -0,0 +1,189 @@
+use crate::Error;
+use poise::serenity_prelude::{Mentionable, User, UserId};
+
+// different items a side can put on the table
+pub const MAX_ITEMS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OfferItem {
+    pub id: i32,
+    pub name: String,
+    pub amount: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Side {
+    pub id: UserId,
+    pub name: String,
+    pub bios: i64,
+    pub items: Vec<OfferItem>,
+    pub confirmed: bool,
+}
+
+impl Side {
+    pub fn new(user: &User) -> Self {
+        Self {
+            id: user.id,
+            name: user.mention().to_string(),
+            bios: 0,
+            items: Vec::new(),
+            confirmed: false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bios == 0 && self.items.is_empty()
+    }
+}
+
+// both members put bios and items on the table, the swap only happens once
+// both confirmed the same offers
+pub struct Trade {
+    pub sides: [Side; 2],
+}
+
+impl Trade {
+    pub fn new(author: &User, user: &User) -> Self {
+        Self {
+            sides: [Side::new(author), Side::new(user)],
+        }
+    }
+
+    pub fn get_side(&self, id: UserId) -> Result<&Side, Error> {
+        self.sides
+            .iter()
+            .find(|side| side.id == id)
+            .ok_or("member not in trade".into())
+    }
+
+    fn get_mut_side(&mut self, id: UserId) -> Result<&mut Side, Error> {
+        self.sides
+            .iter_mut()
+            .find(|side| side.id == id)
+            .ok_or("member not in trade".into())
+    }
+
+    pub fn get_rival(&self, id: UserId) -> Result<&Side, Error> {
+        self.sides
+            .iter()
+            .find(|side| side.id != id)
+            .ok_or("member not in trade".into())
+    }
+
+    // any change to an offer has to be confirmed again by both
+    fn unconfirm(&mut self) {
+        for side in self.sides.iter_mut() {
+            side.confirmed = false;
+        }
+    }
+
+    pub fn set_bios(&mut self, id: UserId, bios: i64) -> Result<(), Error> {
+        if bios < 0 {
+            return Err("invalid amount".into());
+        }
+
+        self.get_mut_side(id)?.bios = bios;
+        self.unconfirm();
+
+        Ok(())
+    }
+
+    // an amount of zero takes the item off the offer
+    pub fn set_item(&mut self, id: UserId, item: OfferItem) -> Result<(), Error> {
+        if item.amount < 0 {
+            return Err("invalid amount".into());
+        }
+
+        let side = self.get_mut_side(id)?;
+
+        match side.items.iter().position(|offered| offered.id == item.id) {
+            Some(index) if item.amount == 0 => {
+                side.items.remove(index);
+            }
+            Some(index) => side.items[index].amount = item.amount,
+            None if item.amount == 0 => return Ok(()),
+            None => {
+                if side.items.len() >= MAX_ITEMS {
+                    return Err("too many items".into());
+                }
+
+                side.items.push(item);
+            }
+        }
+
+        self.unconfirm();
+
+        Ok(())
+    }
+
+    // true once both members confirmed
+    pub fn confirm(&mut self, id: UserId) -> Result<bool, Error> {
+        if self.is_empty() {
+            return Err("empty trade".into());
+        }
+
+        self.get_mut_side(id)?.confirmed = true;
+
+        Ok(self.is_confirmed())
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.sides.iter().all(|side| side.confirmed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sides.iter().all(|side| side.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64) -> User {
+        let mut user = User::default();
+        user.id = UserId::new(id);
+        user
+    }
+
+    fn coffee(amount: i32) -> OfferItem {
+        OfferItem {
+            id: 1,
+            name: String::from("coffee"),
+            amount,
+        }
+    }
+
+    #[test]
+    fn changes_reset_confirmations() {
+        let (a, b) = (user(1), user(2));
+        let mut trade = Trade::new(&a, &b);
+
+        assert!(trade.confirm(a.id).is_err());
+
+        trade.set_bios(a.id, 500).unwrap();
+        assert!(!trade.confirm(a.id).unwrap());
+
+        trade.set_item(b.id, coffee(2)).unwrap();
+        assert!(!trade.get_side(a.id).unwrap().confirmed);
+
+        assert!(!trade.confirm(a.id).unwrap());
+        assert!(trade.confirm(b.id).unwrap());
+    }
+
+    #[test]
+    fn items_are_replaced_and_removed() {
+        let (a, b) = (user(1), user(2));
+        let mut trade = Trade::new(&a, &b);
+
+        trade.set_item(a.id, coffee(2)).unwrap();
+        trade.set_item(a.id, coffee(5)).unwrap();
+        assert_eq!(trade.get_side(a.id).unwrap().items, vec![coffee(5)]);
+
+        trade.set_item(a.id, coffee(0)).unwrap();
+        assert!(trade.get_side(a.id).unwrap().is_empty());
+
+        assert!(trade.set_item(user(3).id, coffee(1)).is_err());
+        assert!(trade.set_bios(b.id, -1).is_err());
+    }
+}